@@ -402,7 +402,7 @@ describe('Contract-Backend Consistency Tests', () => {
         'batch_burn',
         'get_burn_count',
         'admin_burn',
-        'set_metadata',
+        'set_token_metadata',
         'mint_tokens',
       ];
 
@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client, admin)
+}
+
+fn spec(env: &Env) -> TokenCreationParams {
+    token_params(env, "GRD")
+}
+
+/// Address the next token (index 0) would be deployed to
+fn next_token_address(env: &Env, contract_id: &Address) -> Address {
+    env.as_contract(contract_id, || token_creation::derive_token_address(env, 0))
+}
+
+#[test]
+fn test_token_address_colliding_with_treasury_is_rejected() {
+    let (env, contract_id, client, _admin) = setup();
+    let collision = next_token_address(&env, &contract_id);
+    env.as_contract(&contract_id, || storage::set_treasury(&env, &collision));
+
+    let result = client.try_create_token(
+        &Address::generate(&env),
+        &spec(&env),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_token_address_colliding_with_creator_is_rejected() {
+    let (env, contract_id, client, _admin) = setup();
+    let collision = next_token_address(&env, &contract_id);
+
+    let result = client.try_create_token(
+        &collision,
+        &spec(&env),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_fee_withdrawal_to_self_is_rejected() {
+    let (env, contract_id, client, admin) = setup();
+    client.initialize_treasury_policy(&admin, &None, &false);
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset.address()).mint(&creator, &BASE_FEE);
+    client.batch_create_tokens(&creator, &vec![&env, spec(&env)], &BASE_FEE, &None);
+
+    let result = client.try_withdraw_fees(&admin, &contract_id, &BASE_FEE);
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    assert_eq!(client.get_fee_balance(&fee_asset.address()), BASE_FEE);
+}
+
+#[test]
+fn test_rescue_to_self_is_rejected() {
+    let (env, contract_id, client, admin) = setup();
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    StellarAssetClient::new(&env, &asset.address()).mint(&contract_id, &1_000);
+
+    let result = client.try_rescue_funds(&admin, &asset.address(), &contract_id, &1_000);
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_transfer_admin_to_factory_is_rejected() {
+    let (_env, contract_id, client, admin) = setup();
+
+    let result = client.try_transfer_admin(&admin, &contract_id);
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    assert_eq!(client.get_state().admin, admin);
+}
+
+#[test]
+fn test_transfer_admin_to_token_is_rejected() {
+    let (env, _contract_id, client, admin) = setup();
+    let token = client.create_token(
+        &Address::generate(&env),
+        &spec(&env),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    let result = client.try_transfer_admin(&admin, &token);
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    assert_eq!(client.get_state().admin, admin);
+}
+
+#[test]
+fn test_transfer_admin_to_external_address() {
+    let (env, _contract_id, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+
+    client.transfer_admin(&admin, &new_admin);
+
+    assert_eq!(client.get_state().admin, new_admin);
+}
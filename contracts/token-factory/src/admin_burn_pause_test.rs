@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    holder: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = token_params(&env, "PAUS");
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    let holder = Address::generate(&env);
+    client.transfer_with_memo(&token, &creator, &holder, &10_000, &Bytes::new(&env));
+    client.set_clawback(&token, &creator, &true);
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        admin,
+        holder,
+        token,
+    }
+}
+
+fn balance(s: &Setup) -> i128 {
+    s.env.as_contract(&s.contract_id, || {
+        storage::get_balance(&s.env, 0, &s.holder)
+    })
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let s = setup();
+    assert!(!s.client.is_admin_burn_during_pause());
+}
+
+#[test]
+fn test_exemption_lets_admin_burn_through_pause() {
+    let s = setup();
+    s.client.pause(&s.admin);
+
+    // Regular burns and transfers are blocked
+    assert_eq!(
+        s.client.try_burn(&s.holder, &0, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    let recipient = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_transfer_with_memo(&s.token, &s.holder, &recipient, &100, &Bytes::new(&s.env)),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    // So are admin burns until the exemption is enabled
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &0, &s.holder, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    s.client.set_admin_burn_during_pause(&s.admin, &true);
+    s.client.admin_burn(&s.admin, &0, &s.holder, &100);
+    assert_eq!(balance(&s), 9_900);
+
+    // Regular burns stay blocked with the exemption on
+    assert_eq!(
+        s.client.try_burn(&s.holder, &0, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    s.client.set_admin_burn_during_pause(&s.admin, &false);
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &0, &s.holder, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(balance(&s), 9_900);
+}
+
+#[test]
+fn test_unpaused_admin_burn_unaffected() {
+    let s = setup();
+    s.client.admin_burn(&s.admin, &0, &s.holder, &100);
+    assert_eq!(balance(&s), 9_900);
+}
+
+#[test]
+fn test_non_admin_cannot_toggle() {
+    let s = setup();
+    let attacker = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_set_admin_burn_during_pause(&attacker, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+}
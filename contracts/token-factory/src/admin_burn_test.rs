@@ -22,6 +22,16 @@ fn setup_mock_token(env: &Env, client: &TokenFactoryClient, creator: &Address) -
         clawback_enabled: true,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
     
@@ -172,6 +182,16 @@ fn test_admin_burn_clawback_disabled() {
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
     storage::set_token_info_by_address(&env, &token_address, &token_info);
@@ -254,6 +274,16 @@ fn test_set_clawback_enable() {
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
     storage::set_token_info_by_address(&env, &token_address, &token_info);
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+#[test]
+fn test_defaults() {
+    let (_env, client, _admin) = setup();
+
+    let config = client.get_admin_config();
+    assert_eq!(config.base_fee, BASE_FEE);
+    assert_eq!(config.metadata_fee, 30_000_000);
+    assert_eq!(config.fee_burn_bps, 0);
+    assert_eq!(config.rounding_mode, types::RoundingMode::Floor);
+    assert!(config.admin_fee_exempt);
+    assert_eq!(config.max_uri_length, storage::DEFAULT_MAX_URI_LENGTH);
+    assert_eq!(config.min_decimals, 0);
+    assert_eq!(config.max_raw_supply, storage::DEFAULT_MAX_RAW_SUPPLY);
+    assert_eq!(config.global_supply_cap, None);
+    assert_eq!(config.window_create_cap, 0);
+    assert_eq!(config.min_creator_balance, 0);
+    assert_eq!(config.action_quorum, 1);
+    assert_eq!(config.clawback_cooldown, 0);
+    assert!(!config.unique_symbols);
+    assert!(!config.normalize_symbols);
+    assert!(!config.admin_burn_during_pause);
+    assert!(config.clawback_non_burnable);
+    assert!(!config.allowlist_enabled);
+    assert!(!config.paused);
+    assert_eq!(config.pause_expiry, None);
+    assert!(!config.creation_disabled);
+}
+
+#[test]
+fn test_bundles_every_setting() {
+    let (env, client, admin) = setup();
+
+    client.update_fees(&admin, &Some(90_000_000), &Some(10_000_000));
+    client.set_fee_burn_bps(&admin, &2_500);
+    client.set_rounding_mode(&admin, &types::RoundingMode::Ceil);
+    client.set_admin_fee_exempt(&admin, &false);
+    client.set_max_uri_length(&admin, &128);
+    client.set_min_decimals(&admin, &6);
+    client.set_global_supply_cap(&admin, &Some(1_000_000_000));
+    client.set_window_create_cap(&admin, &5);
+    client.set_min_creator_balance(&admin, &Address::generate(&env), &50_000_000);
+    client.set_action_quorum(&admin, &3);
+    client.set_clawback_cooldown(&admin, &3_600);
+    client.set_admin_burn_during_pause(&admin, &true);
+    client.set_clawback_non_burnable(&admin, &false);
+    client.set_unique_symbols(&admin, &true);
+    client.set_normalize_symbols(&admin, &true);
+    client.initialize_treasury_policy(&admin, &None, &true);
+    client.pause_with_timeout(&admin, &500);
+
+    let config = client.get_admin_config();
+    assert_eq!(config.base_fee, 90_000_000);
+    assert_eq!(config.metadata_fee, 10_000_000);
+    assert_eq!(config.fee_burn_bps, 2_500);
+    assert_eq!(config.rounding_mode, types::RoundingMode::Ceil);
+    assert!(!config.admin_fee_exempt);
+    assert_eq!(config.max_uri_length, 128);
+    assert_eq!(config.min_decimals, 6);
+    assert_eq!(config.global_supply_cap, Some(1_000_000_000));
+    assert_eq!(config.window_create_cap, 5);
+    assert_eq!(config.min_creator_balance, 50_000_000);
+    assert_eq!(config.action_quorum, 3);
+    assert_eq!(config.clawback_cooldown, 3_600);
+    assert!(config.admin_burn_during_pause);
+    assert!(!config.clawback_non_burnable);
+    assert!(config.unique_symbols);
+    assert!(config.normalize_symbols);
+    assert!(config.allowlist_enabled);
+    assert!(config.paused);
+    assert_eq!(config.pause_expiry, Some(500));
+    assert!(!config.creation_disabled);
+}
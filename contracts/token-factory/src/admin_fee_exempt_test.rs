@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, String};
+
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    user: Address,
+    fee_token: TokenClient<'static>,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let user = Address::generate(&env);
+    let minter = StellarAssetClient::new(&env, &fee_asset.address());
+    minter.mint(&admin, &STARTING_BALANCE);
+    minter.mint(&user, &STARTING_BALANCE);
+
+    Setup {
+        fee_token: TokenClient::new(&env, &fee_asset.address()),
+        env,
+        client,
+        admin,
+        user,
+    }
+}
+
+fn spec(s: &Setup, symbol: &str) -> TokenCreationParams {
+    token_params(&s.env, symbol)
+}
+
+fn create(s: &Setup, creator: &Address, symbol: &str, fee: i128) -> Result<Address, Error> {
+    s.client
+        .try_create_token(creator, &spec(s, symbol), &fee, &TokenKind::Fungible, &None)
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_admin_creates_for_free_by_default() {
+    let s = setup();
+    assert!(s.client.is_admin_fee_exempt());
+
+    create(&s, &s.admin, "OFFL", 0).unwrap();
+
+    assert_eq!(s.fee_token.balance(&s.admin), STARTING_BALANCE);
+}
+
+#[test]
+fn test_regular_user_pays_full_fee() {
+    let s = setup();
+
+    assert_eq!(create(&s, &s.user, "USER", 0), Err(Error::InsufficientFee));
+
+    create(&s, &s.user, "USER", BASE_FEE).unwrap();
+    assert_eq!(s.fee_token.balance(&s.user), STARTING_BALANCE - BASE_FEE);
+}
+
+#[test]
+fn test_admin_pays_when_exemption_off() {
+    let s = setup();
+    s.client.set_admin_fee_exempt(&s.admin, &false);
+    assert!(!s.client.is_admin_fee_exempt());
+
+    assert_eq!(create(&s, &s.admin, "OFFL", 0), Err(Error::InsufficientFee));
+
+    create(&s, &s.admin, "OFFL", BASE_FEE).unwrap();
+    assert_eq!(s.fee_token.balance(&s.admin), STARTING_BALANCE - BASE_FEE);
+}
+
+#[test]
+fn test_fee_exempt_role() {
+    let s = setup();
+    s.client.set_fee_exempt(&s.admin, &s.user, &true);
+    assert!(s.client.is_fee_exempt(&s.user));
+
+    create(&s, &s.user, "ROLE", 0).unwrap();
+    assert_eq!(s.fee_token.balance(&s.user), STARTING_BALANCE);
+
+    s.client.set_fee_exempt(&s.admin, &s.user, &false);
+    assert!(!s.client.is_fee_exempt(&s.user));
+    assert_eq!(create(&s, &s.user, "ROLE2", 0), Err(Error::InsufficientFee));
+}
+
+#[test]
+fn test_exemption_covers_batch_creation() {
+    let s = setup();
+    let tokens = vec![&s.env, spec(&s, "BAT1"), spec(&s, "BAT2")];
+
+    assert_eq!(
+        s.client
+            .try_batch_create_tokens(&s.user, &tokens, &0, &None),
+        Err(Ok(Error::InsufficientFee))
+    );
+
+    s.client.set_fee_exempt(&s.admin, &s.user, &true);
+    s.client.batch_create_tokens(&s.user, &tokens, &0, &None);
+
+    assert_eq!(s.client.get_token_count(&true), 2);
+    assert_eq!(s.fee_token.balance(&s.user), STARTING_BALANCE);
+}
+
+#[test]
+fn test_exemption_covers_escrowed_creation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_begin_create(&s.user, &spec(&s, "ESC"), &0, &None),
+        Err(Ok(Error::InsufficientFee))
+    );
+
+    s.client.set_fee_exempt(&s.admin, &s.user, &true);
+    let escrow_id = s.client.begin_create(&s.user, &spec(&s, "ESC"), &0, &None);
+    s.client.finalize_create(&s.user, &escrow_id);
+
+    assert_eq!(s.client.get_token_count(&true), 1);
+    assert_eq!(s.fee_token.balance(&s.user), STARTING_BALANCE);
+}
+
+#[test]
+fn test_only_admin_configures_exemptions() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_set_admin_fee_exempt(&s.user, &false),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_set_fee_exempt(&s.user, &s.user, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(s.client.is_admin_fee_exempt());
+    assert!(!s.client.is_fee_exempt(&s.user));
+}
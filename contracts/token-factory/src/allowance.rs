@@ -0,0 +1,107 @@
+//! Spender allowances on factory-managed balances
+//!
+//! A holder can approve a spender to act on part of their balance of a
+//! token (currently used by `burn_from`). Allowances are tracked per
+//! (token, holder, spender) and decrease as the spender uses them. Each
+//! holder's spenders are listed so `revoke_all_approvals` can clear them.
+
+use crate::storage;
+use crate::types::Error;
+use soroban_sdk::{symbol_short, Address, Env};
+
+/// Set the allowance `spender` has over `from`'s balance
+///
+/// Overwrites any previous allowance; approving zero revokes it.
+///
+/// # Returns
+/// * `Ok(())` - Allowance stored
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::InvalidAmount)` - Amount is negative
+pub fn approve(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    from.require_auth();
+
+    if amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+
+    storage::set_allowance(env, token_address, from, spender, amount);
+    emit_approve_event(env, token_address, from, spender, amount);
+
+    Ok(())
+}
+
+/// Revoke every allowance `owner` has granted on a token
+///
+/// Works while the contract is paused, so holders can always cut off
+/// spenders. Each revoked allowance emits an approve event with amount 0.
+///
+/// # Returns
+/// * `Ok(u32)` - Number of allowances revoked
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+pub fn revoke_all_approvals(env: &Env, token_address: &Address, owner: &Address) -> Result<u32, Error> {
+    owner.require_auth();
+
+    storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+
+    let spenders = storage::get_owner_spenders(env, token_address, owner);
+    for spender in spenders.iter() {
+        storage::set_allowance(env, token_address, owner, &spender, 0);
+        emit_approve_event(env, token_address, owner, &spender, 0);
+    }
+
+    Ok(spenders.len())
+}
+
+/// Consume `amount` of `spender`'s allowance over `from`'s balance
+pub fn spend_allowance(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let allowance = storage::get_allowance(env, token_address, from, spender);
+    if allowance < amount {
+        return Err(Error::InsufficientAllowance);
+    }
+
+    let remaining = allowance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
+    storage::set_allowance(env, token_address, from, spender, remaining);
+    Ok(())
+}
+
+/// Emit approve event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: apprv_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "apprv_v1"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - from: Address - The holder granting the allowance
+/// - spender: Address - The approved spender
+/// - amount: i128 - The new allowance
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+fn emit_approve_event(env: &Env, token_address: &Address, from: &Address, spender: &Address, amount: i128) {
+    crate::events::publish(
+        env,
+        (symbol_short!("apprv_v1"), token_address.clone()),
+        (from.clone(), spender.clone(), amount),
+    );
+}
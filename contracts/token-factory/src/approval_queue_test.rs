@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+use types::{AdminAction, Role};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, [Address; 3]) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let approvers = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for approver in approvers.iter() {
+        client.set_approver(&admin, approver, &true);
+    }
+    client.set_action_quorum(&admin, &2);
+
+    (env, client, admin, approvers)
+}
+
+#[test]
+fn test_fee_change_executes_after_quorum() {
+    let (_env, client, _admin, [a, b, _]) = setup();
+
+    let id = client.propose_action(&a, &AdminAction::UpdateFees(Some(90_000_000), None));
+    client.approve_action(&b, &id);
+    client.execute_action(&id);
+
+    let state = client.get_state();
+    assert_eq!(state.base_fee, 90_000_000);
+    assert_eq!(state.metadata_fee, 30_000_000);
+    assert!(client.get_action(&id).unwrap().executed);
+}
+
+#[test]
+fn test_execute_below_quorum_fails() {
+    let (_env, client, _admin, [a, _, _]) = setup();
+
+    let id = client.propose_action(&a, &AdminAction::UpdateFees(Some(90_000_000), None));
+
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::QuorumNotMet)));
+    assert_eq!(client.get_state().base_fee, BASE_FEE);
+}
+
+#[test]
+fn test_revoked_approver_no_longer_counts() {
+    let (_env, client, admin, [a, b, _]) = setup();
+
+    let id = client.propose_action(&a, &AdminAction::SetPaused(true));
+    client.approve_action(&b, &id);
+    client.set_approver(&admin, &b, &false);
+
+    assert_eq!(client.try_execute_action(&id), Err(Ok(Error::QuorumNotMet)));
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_action_executes_once() {
+    let (env, client, _admin, [a, b, c]) = setup();
+
+    let treasury = Address::generate(&env);
+    let id = client.propose_action(&a, &AdminAction::SetTreasury(treasury.clone()));
+    client.approve_action(&b, &id);
+    client.execute_action(&id);
+    assert_eq!(client.get_state().treasury, treasury);
+
+    assert_eq!(
+        client.try_execute_action(&id),
+        Err(Ok(Error::ChangeAlreadyExecuted))
+    );
+    assert_eq!(
+        client.try_approve_action(&c, &id),
+        Err(Ok(Error::ChangeAlreadyExecuted))
+    );
+}
+
+#[test]
+fn test_approval_rules() {
+    let (env, client, admin, [a, b, _]) = setup();
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_propose_action(&outsider, &AdminAction::SetPaused(true)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_propose_action(&a, &AdminAction::UpdateFees(Some(-1), None)),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_approve_action(&a, &7),
+        Err(Ok(Error::ProposalNotFound))
+    );
+
+    let id = client.propose_action(&a, &AdminAction::SetPaused(true));
+    assert_eq!(
+        client.try_approve_action(&a, &id),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_approve_action(&outsider, &id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The role can also be granted through batch_update_admin
+    client.batch_update_admin(
+        &admin,
+        &None,
+        &None,
+        &None,
+        &None,
+        &Some((outsider.clone(), Role::Approver, true)),
+    );
+    client.approve_action(&outsider, &id);
+    client.approve_action(&b, &id);
+    assert_eq!(client.get_action(&id).unwrap().approvals.len(), 3);
+}
+
+#[test]
+fn test_quorum_admin_only_and_nonzero() {
+    let (env, client, admin, _) = setup();
+
+    assert_eq!(
+        client.try_set_action_quorum(&Address::generate(&env), &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_action_quorum(&admin, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(client.get_action_quorum(), 2);
+}
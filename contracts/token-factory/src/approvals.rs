@@ -0,0 +1,175 @@
+//! Admin action approval queue
+//!
+//! Lets a group of approvers share control of sensitive admin actions
+//! instead of relying on the single admin key. An approver proposes an
+//! `AdminAction`, other approvers sign off on it, and anyone can execute
+//! it once the approvals reach the configured quorum (1 by default). The
+//! proposal counts as the proposer's approval.
+//!
+//! Approvals are counted at execution time against the current approver
+//! set, so revoking an approver also withdraws their pending approvals.
+//! Each request executes at most once.
+
+use crate::events;
+use crate::fees;
+use crate::storage;
+use crate::types::{ActionRequest, AdminAction, Error};
+use crate::validation;
+use soroban_sdk::{vec, Address, Env};
+
+/// Queue an admin action and record the proposer's approval
+///
+/// # Returns
+/// * `Ok(id)` - ID of the queued request
+/// * `Err(Error::Unauthorized)` - Proposer is not an approver
+/// * `Err(Error::InvalidParameters)` - The action would be rejected when
+///   executed
+pub fn propose_action(env: &Env, proposer: &Address, action: AdminAction) -> Result<u64, Error> {
+    proposer.require_auth();
+
+    if !storage::is_approver(env, proposer) {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_action(env, &action)?;
+
+    let id = storage::next_action_id(env);
+    storage::set_action_request(
+        env,
+        &ActionRequest {
+            id,
+            proposer: proposer.clone(),
+            action: action.clone(),
+            approvals: vec![env, proposer.clone()],
+            executed: false,
+        },
+    );
+
+    events::emit_action_proposed(env, id, proposer, &action);
+    Ok(id)
+}
+
+/// Sign off on a queued admin action
+///
+/// # Returns
+/// * `Ok(())` - Approval recorded
+/// * `Err(Error::Unauthorized)` - Approver does not hold the approver role
+/// * `Err(Error::ProposalNotFound)` - No request with this ID
+/// * `Err(Error::ChangeAlreadyExecuted)` - The request already ran
+/// * `Err(Error::AlreadyVoted)` - Approver already signed off
+pub fn approve_action(env: &Env, approver: &Address, id: u64) -> Result<(), Error> {
+    approver.require_auth();
+
+    if !storage::is_approver(env, approver) {
+        return Err(Error::Unauthorized);
+    }
+
+    let mut request = storage::get_action_request(env, id).ok_or(Error::ProposalNotFound)?;
+    if request.executed {
+        return Err(Error::ChangeAlreadyExecuted);
+    }
+    if request.approvals.contains(approver) {
+        return Err(Error::AlreadyVoted);
+    }
+
+    request.approvals.push_back(approver.clone());
+    storage::set_action_request(env, &request);
+
+    events::emit_action_approved(env, id, approver, request.approvals.len());
+    Ok(())
+}
+
+/// Run a queued admin action once it has enough approvals
+///
+/// # Returns
+/// * `Ok(())` - Action applied
+/// * `Err(Error::ProposalNotFound)` - No request with this ID
+/// * `Err(Error::ChangeAlreadyExecuted)` - The request already ran
+/// * `Err(Error::QuorumNotMet)` - Fewer current approvers signed off than
+///   the quorum requires
+/// * `Err(Error::InvalidParameters)` - The action is no longer valid
+pub fn execute_action(env: &Env, id: u64) -> Result<(), Error> {
+    let mut request = storage::get_action_request(env, id).ok_or(Error::ProposalNotFound)?;
+    if request.executed {
+        return Err(Error::ChangeAlreadyExecuted);
+    }
+
+    let approvals = count_approvals(env, &request);
+    if approvals < storage::get_action_quorum(env) {
+        return Err(Error::QuorumNotMet);
+    }
+
+    // Revalidate: the factory may have changed since the proposal
+    validate_action(env, &request.action)?;
+    apply_action(env, &request)?;
+
+    request.executed = true;
+    storage::set_action_request(env, &request);
+
+    events::emit_action_executed(env, id, approvals);
+    Ok(())
+}
+
+/// Count approvals from accounts that still hold the approver role
+fn count_approvals(env: &Env, request: &ActionRequest) -> u32 {
+    request
+        .approvals
+        .iter()
+        .filter(|approver| storage::is_approver(env, approver))
+        .count() as u32
+}
+
+fn validate_action(env: &Env, action: &AdminAction) -> Result<(), Error> {
+    match action {
+        AdminAction::UpdateFees(base_fee, metadata_fee) => {
+            if base_fee.is_none() && metadata_fee.is_none() {
+                return Err(Error::InvalidParameters);
+            }
+            fees::validate_fee_amounts(*base_fee, *metadata_fee)
+        }
+        AdminAction::SetPaused(_) => Ok(()),
+        AdminAction::SetTreasury(treasury) => {
+            // Contract-controlled addresses could never withdraw the fees
+            if *treasury == env.current_contract_address()
+                || storage::get_token_info_by_address(env, treasury).is_some()
+            {
+                return Err(Error::InvalidParameters);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn apply_action(env: &Env, request: &ActionRequest) -> Result<(), Error> {
+    match &request.action {
+        AdminAction::UpdateFees(base_fee, metadata_fee) => {
+            if let Some(fee) = base_fee {
+                storage::set_base_fee(env, *fee);
+            }
+            if let Some(fee) = metadata_fee {
+                storage::set_metadata_fee(env, *fee);
+            }
+            validation::validate_fees(env)?;
+            fees::record_fee_change(env);
+
+            events::emit_fees_updated(
+                env,
+                storage::get_base_fee(env),
+                storage::get_metadata_fee(env),
+            );
+        }
+        AdminAction::SetPaused(paused) => {
+            storage::set_paused(env, *paused);
+            if *paused {
+                events::emit_pause(env, &request.proposer);
+            } else {
+                events::emit_unpause(env, &request.proposer);
+            }
+        }
+        AdminAction::SetTreasury(treasury) => {
+            storage::set_treasury(env, treasury);
+            events::emit_treasury_updated(env, treasury);
+        }
+    }
+    Ok(())
+}
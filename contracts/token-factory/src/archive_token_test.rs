@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (
+    Env,
+    TokenFactoryClient<'static>,
+    Address,
+    soroban_sdk::Vec<Address>,
+) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let mut tokens = soroban_sdk::Vec::new(&env);
+    for symbol in ["AAA", "BBB", "CCC"] {
+        let spec = token_params(&env, symbol);
+        tokens.push_back(client.create_token(
+            &creator,
+            &spec,
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        ));
+    }
+
+    (env, client, creator, tokens)
+}
+
+fn listed_addresses(env: &Env, page: &types::PaginatedTokens) -> soroban_sdk::Vec<Address> {
+    let mut out = soroban_sdk::Vec::new(env);
+    for info in page.tokens.iter() {
+        out.push_back(info.address);
+    }
+    out
+}
+
+#[test]
+fn test_archived_token_hidden_by_default() {
+    let (env, client, creator, tokens) = setup();
+    let middle = tokens.get(1).unwrap();
+
+    client.archive_token(&middle, &creator);
+
+    let page = client.list_tokens(&None, &None, &false);
+    assert_eq!(
+        listed_addresses(&env, &page),
+        soroban_sdk::vec![&env, tokens.get(0).unwrap(), tokens.get(2).unwrap()]
+    );
+    assert!(!page.has_more);
+    assert_eq!(client.get_token_count(&false), 2);
+}
+
+#[test]
+fn test_archived_token_listed_when_requested() {
+    let (env, client, creator, tokens) = setup();
+    client.archive_token(&tokens.get(1).unwrap(), &creator);
+
+    let page = client.list_tokens(&None, &None, &true);
+
+    assert_eq!(listed_addresses(&env, &page), tokens);
+    assert_eq!(client.get_token_count(&true), 3);
+}
+
+#[test]
+fn test_archiving_keeps_indices() {
+    let (_env, client, creator, tokens) = setup();
+    client.archive_token(&tokens.get(1).unwrap(), &creator);
+
+    assert!(client.is_token_archived(&1));
+    assert!(!client.is_token_archived(&2));
+    for (index, address) in tokens.iter().enumerate() {
+        assert_eq!(client.get_token_info(&(index as u32)).address, address);
+    }
+}
+
+#[test]
+fn test_list_tokens_pages_past_archived() {
+    let (env, client, creator, tokens) = setup();
+    client.archive_token(&tokens.get(0).unwrap(), &creator);
+
+    let first = client.list_tokens(&None, &Some(1), &false);
+    assert_eq!(
+        listed_addresses(&env, &first),
+        soroban_sdk::vec![&env, tokens.get(1).unwrap()]
+    );
+    assert!(first.has_more);
+
+    let second = client.list_tokens(&Some(first.cursor.next_index), &Some(1), &false);
+    assert_eq!(
+        listed_addresses(&env, &second),
+        soroban_sdk::vec![&env, tokens.get(2).unwrap()]
+    );
+    assert!(!second.has_more);
+}
+
+#[test]
+fn test_archive_twice_rejected() {
+    let (_env, client, creator, tokens) = setup();
+    let token = tokens.get(1).unwrap();
+    client.archive_token(&token, &creator);
+
+    let result = client.try_archive_token(&token, &creator);
+
+    assert_eq!(result, Err(Ok(Error::TokenArchived)));
+    assert_eq!(client.get_token_count(&false), 2);
+}
+
+#[test]
+fn test_archive_requires_creator() {
+    let (env, client, _creator, tokens) = setup();
+
+    let result = client.try_archive_token(&tokens.get(1).unwrap(), &Address::generate(&env));
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert!(!client.is_token_archived(&1));
+}
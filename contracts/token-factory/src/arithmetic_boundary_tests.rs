@@ -117,6 +117,16 @@ fn test_mint_max_supply_overflow_protection() {
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
     
@@ -158,6 +168,16 @@ fn test_burn_underflow_protection() {
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
     
@@ -287,6 +307,16 @@ fn test_batch_burn_total_overflow_protection() {
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
     storage::set_token_info(&env, 0, &token_info);
@@ -479,6 +509,16 @@ fn test_arithmetic_fuzz_mint_with_random_supplies() {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         
         };
         storage::set_token_info(&env, 0, &token_info);
@@ -274,6 +274,8 @@ proptest! {
             &Some(base_fee),
             &Some(metadata_fee),
             &Some(paused),
+            &None,
+            &None,
         );
         
         prop_assert!(result.is_err(),
@@ -794,6 +796,8 @@ mod privilege_escalation_tests {
                 &Some(base_fee),
                 &None,
                 &Some(true),
+                &None,
+                &None,
             );
             
             prop_assert!(result.is_err(),
@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use crate::test_helpers::{token_params, AuthMode, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{vec, Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    token: Address,
+}
+
+/// Factory with one token, paused, with authorization switched to strict.
+///
+/// The contract is paused so that a function reading storage before its
+/// auth check would return `ContractPaused` instead of trapping.
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = token_params(&env, "AUTH");
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+    client.pause(&admin);
+
+    AuthMode::Strict.apply(&env);
+
+    Setup {
+        env,
+        client,
+        admin,
+        creator,
+        token,
+    }
+}
+
+/// Assert the call trapped on authorization, not on a contract error,
+/// and left no trace
+fn assert_auth_trap(s: &Setup, call: impl FnOnce()) {
+    let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(call))
+        .expect_err("call must trap without a signature");
+    let message = payload
+        .downcast_ref::<std::string::String>()
+        .map(|m| m.as_str())
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or_default();
+    assert!(
+        message.contains("Error(Auth"),
+        "expected an auth trap, got: {message}"
+    );
+    assert_eq!(s.env.events().all().len(), 0);
+}
+
+#[test]
+fn test_creator_functions_check_auth_first() {
+    let s = setup();
+
+    assert_auth_trap(&s, || {
+        s.client.set_clawback(&s.token, &s.creator, &true);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client
+            .bulk_set_clawback(&s.creator, &vec![&s.env, s.token.clone()], &true);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.mint(&s.creator, &0, &s.creator, &1);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.finalize_minting(&s.token, &s.creator);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.archive_token(&s.token, &s.creator);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.wrap_as_sac(&s.token, &s.creator);
+    });
+
+    let info = s.client.get_token_info_by_address(&s.token);
+    assert!(!info.clawback_enabled);
+    assert!(!s.client.is_minting_finalized(&s.token));
+    assert!(!s.client.is_token_archived(&0));
+}
+
+#[test]
+fn test_admin_functions_check_auth_first() {
+    let s = setup();
+
+    assert_auth_trap(&s, || {
+        s.client.unpause(&s.admin);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.update_fees(&s.admin, &Some(1), &None);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.set_fee_burn_bps(&s.admin, &1_000);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client
+            .transfer_admin(&s.admin, &Address::generate(&s.env));
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.emergency_shutdown(&s.admin);
+    });
+
+    assert_auth_trap(&s, || {
+        s.client.admin_burn(&s.admin, &0, &s.creator, &1);
+    });
+
+    let state = s.client.get_state();
+    assert!(state.paused);
+    assert_eq!(state.admin, s.admin);
+    assert_eq!(state.base_fee, BASE_FEE);
+    assert!(!s.client.is_creation_disabled());
+}
+
+#[test]
+fn test_mock_all_restores_authorization() {
+    let s = setup();
+
+    AuthMode::MockAll.apply(&s.env);
+
+    assert!(s.client.unpause(&s.admin));
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, vec, xdr, Address, Env, IntoVal, String, TryFromVal, Val};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let params = token_params(&env, "BTCH");
+    client.batch_create_tokens(
+        &Address::generate(&env),
+        &vec![&env, params],
+        &BASE_FEE,
+        &None,
+    );
+
+    (env, contract_id, client, admin)
+}
+
+fn holders(
+    env: &Env,
+    contract_id: &Address,
+    count: u32,
+    balance: i128,
+) -> soroban_sdk::Vec<Address> {
+    let mut out = soroban_sdk::Vec::new(env);
+    for _ in 0..count {
+        let holder = Address::generate(env);
+        env.as_contract(contract_id, || {
+            storage::set_balance(env, 0, &holder, balance)
+        });
+        out.push_back(holder);
+    }
+    out
+}
+
+/// Find the (entry_index, reason) payload of the last `bch_fl_v1` event.
+///
+/// The contract events of a reverted call are dropped from the ledger but
+/// are still reported by the host (flagged as a failed call), which is what
+/// off-chain tooling sees in the transaction diagnostics.
+fn last_entry_failure(env: &Env) -> Option<(u32, u32)> {
+    let name: Val = symbol_short!("bch_fl_v1").into_val(env);
+    let mut found = None;
+    for event in env.host().get_events().unwrap().0.iter() {
+        let xdr::ContractEventBody::V0(body) = &event.event.body;
+        let Some(topic) = body.topics.first() else {
+            continue;
+        };
+        let topic = Val::try_from_val(env, topic).unwrap();
+        if topic.shallow_eq(&name) {
+            let data = Val::try_from_val(env, &body.data).unwrap();
+            let (_version, entry_index, reason, _seq) =
+                <(u32, u32, u32, u64)>::try_from_val(env, &data).unwrap();
+            found = Some((entry_index, reason));
+        }
+    }
+    found
+}
+
+#[test]
+fn test_diagnostic_event_pinpoints_insufficient_balance_entry() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 5, 100);
+
+    let mut burns = vec![&env];
+    for (i, holder) in holders.iter().enumerate() {
+        let amount = if i == 3 { 101 } else { 10 };
+        burns.push_back((holder, amount));
+    }
+
+    let result = client.try_batch_burn(&admin, &0, &burns);
+
+    assert_eq!(result, Err(Ok(Error::BatchEntryFailed)));
+    assert_eq!(
+        last_entry_failure(&env),
+        Some((3, Error::InsufficientBalance.0))
+    );
+
+    // Nothing was burned
+    for holder in holders.iter() {
+        assert_eq!(
+            env.as_contract(&contract_id, || storage::get_balance(&env, 0, &holder)),
+            100
+        );
+    }
+}
+
+#[test]
+fn test_diagnostic_event_reports_invalid_amount() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 2, 100);
+
+    let burns = vec![
+        &env,
+        (holders.get(0).unwrap(), 10_i128),
+        (holders.get(1).unwrap(), 0_i128),
+    ];
+
+    let result = client.try_batch_burn(&admin, &0, &burns);
+
+    assert_eq!(result, Err(Ok(Error::BatchEntryFailed)));
+    assert_eq!(
+        last_entry_failure(&env),
+        Some((1, Error::InvalidParameters.0))
+    );
+}
+
+#[test]
+fn test_valid_batch_emits_no_diagnostic() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 3, 100);
+
+    let mut burns = vec![&env];
+    for holder in holders.iter() {
+        burns.push_back((holder, 50_i128));
+    }
+
+    client.batch_burn(&admin, &0, &burns);
+
+    assert_eq!(last_entry_failure(&env), None);
+}
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let params = token_params(&env, "LNT");
+    client.batch_create_tokens(
+        &Address::generate(&env),
+        &vec![&env, params],
+        &BASE_FEE,
+        &None,
+    );
+
+    (env, contract_id, client, admin)
+}
+
+fn holders(env: &Env, contract_id: &Address, count: u32) -> soroban_sdk::Vec<Address> {
+    let mut out = soroban_sdk::Vec::new(env);
+    for _ in 0..count {
+        let holder = Address::generate(env);
+        env.as_contract(contract_id, || storage::set_balance(env, 0, &holder, 100));
+        out.push_back(holder);
+    }
+    out
+}
+
+fn balance(env: &Env, contract_id: &Address, holder: &Address) -> i128 {
+    env.as_contract(contract_id, || storage::get_balance(env, 0, holder))
+}
+
+/// Entry 1 asks for more than the holder's balance
+fn burns_with_bad_entry(
+    env: &Env,
+    holders: &soroban_sdk::Vec<Address>,
+) -> soroban_sdk::Vec<(Address, i128)> {
+    vec![
+        env,
+        (holders.get(0).unwrap(), 10_i128),
+        (holders.get(1).unwrap(), 101_i128),
+        (holders.get(2).unwrap(), 30_i128),
+    ]
+}
+
+#[test]
+fn test_lenient_burns_valid_entries_and_reports_failure() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 3);
+    let supply_before = client.get_token_info(&0).total_supply;
+
+    let results = client.batch_burn_lenient(&admin, &0, &burns_with_bad_entry(&env, &holders));
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            (holders.get(0).unwrap(), true),
+            (holders.get(1).unwrap(), false),
+            (holders.get(2).unwrap(), true),
+        ]
+    );
+    assert_eq!(balance(&env, &contract_id, &holders.get(0).unwrap()), 90);
+    assert_eq!(balance(&env, &contract_id, &holders.get(1).unwrap()), 100);
+    assert_eq!(balance(&env, &contract_id, &holders.get(2).unwrap()), 70);
+
+    let info = client.get_token_info(&0);
+    assert_eq!(info.total_supply, supply_before - 40);
+    assert_eq!(info.total_burned, 40);
+    assert_eq!(info.burn_count, 2);
+}
+
+#[test]
+fn test_strict_variant_reverts_same_batch() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 3);
+
+    let result = client.try_batch_burn(&admin, &0, &burns_with_bad_entry(&env, &holders));
+
+    assert_eq!(result, Err(Ok(Error::BatchEntryFailed)));
+    for holder in holders.iter() {
+        assert_eq!(balance(&env, &contract_id, &holder), 100);
+    }
+    assert_eq!(client.get_token_info(&0).total_burned, 0);
+}
+
+#[test]
+fn test_lenient_checks_duplicates_against_running_balance() {
+    let (env, contract_id, client, admin) = setup();
+    let holder = holders(&env, &contract_id, 1).get(0).unwrap();
+
+    let results = client.batch_burn_lenient(
+        &admin,
+        &0,
+        &vec![
+            &env,
+            (holder.clone(), 60_i128),
+            (holder.clone(), 60_i128),
+            (holder.clone(), 40_i128),
+        ],
+    );
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            (holder.clone(), true),
+            (holder.clone(), false),
+            (holder.clone(), true)
+        ]
+    );
+    assert_eq!(balance(&env, &contract_id, &holder), 0);
+}
+
+#[test]
+fn test_lenient_all_failed_changes_nothing() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 2);
+
+    let results = client.batch_burn_lenient(
+        &admin,
+        &0,
+        &vec![
+            &env,
+            (holders.get(0).unwrap(), 0_i128),
+            (holders.get(1).unwrap(), 500_i128),
+        ],
+    );
+
+    assert_eq!(results.iter().filter(|(_, ok)| *ok).count(), 0);
+    assert_eq!(client.get_token_info(&0).burn_count, 0);
+}
+
+#[test]
+fn test_lenient_batch_level_errors_still_fail() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 1);
+    let burns = vec![&env, (holders.get(0).unwrap(), 10_i128)];
+
+    assert_eq!(
+        client.try_batch_burn_lenient(&Address::generate(&env), &0, &burns),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_batch_burn_lenient(&admin, &0, &vec![&env]),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_batch_burn_lenient(&admin, &7, &burns),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
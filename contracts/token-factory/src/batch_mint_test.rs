@@ -46,6 +46,16 @@ fn setup_token(
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
 
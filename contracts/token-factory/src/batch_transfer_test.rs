@@ -0,0 +1,154 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+const SUPPLY: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    contract_id: Address,
+    creator: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let spec = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&env, "PAY")
+    };
+    let token = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        creator,
+        token,
+    }
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, 0, holder))
+}
+
+#[test]
+fn test_pays_every_recipient() {
+    let s = setup();
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    let c = Address::generate(&s.env);
+
+    let total = s.client.batch_transfer(
+        &s.token,
+        &s.creator,
+        &vec![&s.env, (a.clone(), 100), (b.clone(), 200), (c.clone(), 300)],
+    );
+
+    assert_eq!(total, 600);
+    assert_eq!(balance(&s, &a), 100);
+    assert_eq!(balance(&s, &b), 200);
+    assert_eq!(balance(&s, &c), 300);
+    assert_eq!(balance(&s, &s.creator), SUPPLY - 600);
+    assert_eq!(s.client.get_holder_count(&0), 4);
+}
+
+#[test]
+fn test_insufficient_total_moves_nothing() {
+    let s = setup();
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+
+    let result = s.client.try_batch_transfer(
+        &s.token,
+        &s.creator,
+        &vec![&s.env, (a.clone(), SUPPLY), (b.clone(), 1)],
+    );
+
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(balance(&s, &a), 0);
+    assert_eq!(balance(&s, &b), 0);
+    assert_eq!(balance(&s, &s.creator), SUPPLY);
+}
+
+#[test]
+fn test_invalid_entry_aborts_batch() {
+    let s = setup();
+    let a = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_batch_transfer(
+            &s.token,
+            &s.creator,
+            &vec![&s.env, (a.clone(), 10), (a.clone(), 0)],
+        ),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_batch_transfer(
+            &s.token,
+            &s.creator,
+            &vec![&s.env, (a.clone(), 10), (s.creator.clone(), 10)],
+        ),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(balance(&s, &a), 0);
+}
+
+#[test]
+fn test_batch_size_bounds() {
+    let s = setup();
+    let a = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client
+            .try_batch_transfer(&s.token, &s.creator, &Vec::new(&s.env)),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    let mut transfers = Vec::new(&s.env);
+    for _ in 0..101 {
+        transfers.push_back((a.clone(), 1));
+    }
+    assert_eq!(
+        s.client
+            .try_batch_transfer(&s.token, &s.creator, &transfers),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
+
+#[test]
+fn test_respects_holder_cap() {
+    let s = setup();
+    s.client.set_max_holders(&s.token, &s.creator, &Some(2));
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_batch_transfer(
+            &s.token,
+            &s.creator,
+            &vec![&s.env, (a.clone(), 10), (b.clone(), 10)],
+        ),
+        Err(Ok(Error::HolderLimitReached))
+    );
+
+    s.client.batch_transfer(
+        &s.token,
+        &s.creator,
+        &vec![&s.env, (a.clone(), 10), (a, 10)],
+    );
+    assert_eq!(s.client.get_holder_count(&0), 2);
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+use types::Role;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+#[test]
+fn test_updates_fees_pause_treasury_and_role_together() {
+    let (env, client, admin) = setup();
+    let new_treasury = Address::generate(&env);
+    let account = Address::generate(&env);
+
+    client.batch_update_admin(
+        &admin,
+        &Some(100_000_000),
+        &Some(40_000_000),
+        &Some(true),
+        &Some(new_treasury.clone()),
+        &Some((account.clone(), Role::FeeExempt, true)),
+    );
+
+    let state = client.get_state();
+    assert_eq!(state.base_fee, 100_000_000);
+    assert_eq!(state.metadata_fee, 40_000_000);
+    assert!(state.paused);
+    assert_eq!(state.treasury, new_treasury);
+    assert!(client.is_fee_exempt(&account));
+}
+
+#[test]
+fn test_revokes_role() {
+    let (env, client, admin) = setup();
+    let account = Address::generate(&env);
+    client.set_fee_exempt(&admin, &account, &true);
+
+    client.batch_update_admin(
+        &admin,
+        &None,
+        &None,
+        &None,
+        &None,
+        &Some((account.clone(), Role::FeeExempt, false)),
+    );
+
+    assert!(!client.is_fee_exempt(&account));
+}
+
+#[test]
+fn test_invalid_fee_aborts_whole_batch() {
+    let (env, client, admin) = setup();
+    let before = client.get_state();
+    let new_treasury = Address::generate(&env);
+    let account = Address::generate(&env);
+
+    let result = client.try_batch_update_admin(
+        &admin,
+        &Some(100_000_000),
+        &Some(-1),
+        &Some(true),
+        &Some(new_treasury),
+        &Some((account.clone(), Role::FeeExempt, true)),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    assert_eq!(client.get_state(), before);
+    assert!(!client.is_fee_exempt(&account));
+}
+
+#[test]
+fn test_rejects_factory_as_treasury() {
+    let (_env, client, admin) = setup();
+    let before = client.get_state();
+
+    let result = client.try_batch_update_admin(
+        &admin,
+        &Some(100_000_000),
+        &None,
+        &None,
+        &Some(client.address.clone()),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    assert_eq!(client.get_state(), before);
+}
+
+#[test]
+fn test_non_admin_rejected() {
+    let (env, client, _admin) = setup();
+    let attacker = Address::generate(&env);
+
+    let result = client.try_batch_update_admin(
+        &attacker,
+        &None,
+        &None,
+        &None,
+        &Some(attacker.clone()),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
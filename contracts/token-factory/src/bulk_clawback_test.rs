@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client)
+}
+
+fn register_token(
+    env: &Env,
+    contract_id: &Address,
+    client: &TokenFactoryClient,
+    creator: &Address,
+) -> Address {
+    let spec = TokenCreationParams {
+        initial_supply: 1_000_000_0000000,
+        ..token_params(env, "TEST")
+    };
+    let token_address = client.create_token(creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    // Start with clawback enabled on both records
+    env.as_contract(contract_id, || {
+        let index = storage::get_token_index(env, &token_address).unwrap();
+        let mut info = storage::get_token_info(env, index).unwrap();
+        info.clawback_enabled = true;
+        storage::set_token_info(env, index, &info);
+        storage::set_token_info_by_address(env, &token_address, &info);
+    });
+    token_address
+}
+
+#[test]
+fn test_bulk_disable_clawback_across_creator_tokens() {
+    let (env, contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let t1 = register_token(&env, &contract_id, &client, &creator);
+    let t2 = register_token(&env, &contract_id, &client, &creator);
+    let t3 = register_token(&env, &contract_id, &client, &creator);
+
+    client.bulk_set_clawback(
+        &creator,
+        &vec![&env, t1.clone(), t2.clone(), t3.clone()],
+        &false,
+    );
+
+    for token in [t1, t2, t3] {
+        assert!(!client.get_token_info_by_address(&token).clawback_enabled);
+    }
+}
+
+#[test]
+fn test_bulk_set_clawback_updates_index_records() {
+    let (env, contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    register_token(&env, &contract_id, &client, &creator);
+    let t2 = register_token(&env, &contract_id, &client, &creator);
+
+    client.bulk_set_clawback(&creator, &vec![&env, t2.clone()], &false);
+
+    assert!(client.get_token_info(&0).clawback_enabled);
+    let info = client.get_token_info(&1);
+    assert_eq!(info.address, t2);
+    assert!(!info.clawback_enabled);
+}
+
+#[test]
+fn test_bulk_set_clawback_foreign_token_aborts_batch() {
+    let (env, contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let other = Address::generate(&env);
+    let t1 = register_token(&env, &contract_id, &client, &creator);
+    let t2 = register_token(&env, &contract_id, &client, &creator);
+    let foreign = register_token(&env, &contract_id, &client, &other);
+
+    let result = client.try_bulk_set_clawback(
+        &creator,
+        &vec![&env, t1.clone(), foreign.clone(), t2.clone()],
+        &false,
+    );
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    for token in [t1, t2, foreign] {
+        assert!(client.get_token_info_by_address(&token).clawback_enabled);
+    }
+}
+
+#[test]
+fn test_bulk_set_clawback_unknown_token_aborts_batch() {
+    let (env, contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let t1 = register_token(&env, &contract_id, &client, &creator);
+
+    let result = client.try_bulk_set_clawback(
+        &creator,
+        &vec![&env, t1.clone(), Address::generate(&env)],
+        &false,
+    );
+
+    assert_eq!(result, Err(Ok(Error::TokenNotFound)));
+    assert!(client.get_token_info_by_address(&t1).clawback_enabled);
+}
+
+#[test]
+fn test_bulk_set_clawback_rejects_empty_batch() {
+    let (env, _contract_id, client) = setup();
+    let creator = Address::generate(&env);
+
+    let result = client.try_bulk_set_clawback(&creator, &Vec::new(&env), &false);
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_bulk_set_clawback_rejects_oversized_batch() {
+    let (env, contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let token = register_token(&env, &contract_id, &client, &creator);
+
+    let mut tokens = Vec::new(&env);
+    for _ in 0..=MAX_BULK_CLAWBACK {
+        tokens.push_back(token.clone());
+    }
+
+    let result = client.try_bulk_set_clawback(&creator, &tokens, &false);
+
+    assert_eq!(result, Err(Ok(Error::BatchTooLarge)));
+}
@@ -1,30 +1,12 @@
 use crate::storage;
-use crate::types::Error;
+use crate::types::{ClawbackMode, Error, TokenInfo, TokenStats};
 use soroban_sdk::{symbol_short, Address, Env};
 
 const MAX_BATCH_BURN: u32 = 100;
 
 pub fn burn(env: &Env, caller: Address, token_index: u32, amount: i128) -> Result<(), Error> {
     caller.require_auth();
-    validate_amount(amount)?;
-
-    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
-
-    // Token-level pause check
-    if storage::is_token_paused(env, token_index) {
-        return Err(Error::TokenPaused);
-    }
-
-    let balance = storage::get_balance(env, token_index, &caller);
-    if balance < amount {
-        return Err(Error::InsufficientBalance);
-    }
-
-    let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
-    let new_supply = info
-        .total_supply
-        .checked_sub(amount)
-        .ok_or(Error::ArithmeticError)?;
+    let (mut info, new_balance, new_supply) = check_burn(env, token_index, &caller, amount)?;
 
     storage::set_balance(env, token_index, &caller, new_balance);
     info.total_supply = new_supply;
@@ -41,11 +23,81 @@ pub fn burn(env: &Env, caller: Address, token_index: u32, amount: i128) -> Resul
     // 8. Emit event — after state is fully committed
     storage::increment_burn_count(env, token_index);
     storage::add_total_burned(env, token_index, amount);
+    crate::mint::retire_global_supply(env, amount);
+    storage::add_global_burn(env, amount, 1);
 
-    emit_burn_event(env, token_index, &caller, amount, new_supply);
+    if !info.events_muted {
+        emit_burn_event(env, token_index, &caller, amount, new_supply);
+    }
     Ok(())
 }
 
+/// Preview a holder's burn without changing any state
+///
+/// Runs the same checks as `burn` and fails with the same errors; on
+/// success returns the token stats the burn would leave behind.
+pub fn simulate_burn(
+    env: &Env,
+    token_address: &Address,
+    holder: &Address,
+    amount: i128,
+) -> Result<TokenStats, Error> {
+    let token_index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let (_, _, new_supply) = check_burn(env, token_index, holder, amount)?;
+
+    Ok(TokenStats {
+        current_supply: new_supply,
+        total_burned: storage::get_total_burned(env, token_index)
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticError)?,
+        burn_count: storage::get_burn_count(env, token_index).saturating_add(1),
+        is_paused: false,
+        clawback_enabled: false,
+        freeze_enabled: false,
+    })
+}
+
+/// Validate a holder's burn, returning the token info with the new
+/// balance and supply
+fn check_burn(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    amount: i128,
+) -> Result<(TokenInfo, i128, i128), Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    validate_amount(amount)?;
+    validate_address(env, holder)?;
+
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if !info.burnable {
+        return Err(Error::BurnDisabled);
+    }
+
+    // Token-level pause check
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    crate::token_creation::ensure_token_unlocked(env, &info.address)?;
+
+    let balance = storage::get_balance(env, token_index, holder);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
+    crate::token_creation::ensure_min_balance(&info, new_balance)?;
+    let new_supply = info
+        .total_supply
+        .checked_sub(amount)
+        .ok_or(Error::ArithmeticError)?;
+
+    Ok((info, new_balance, new_supply))
+}
+
 pub fn admin_burn(
     env: &Env,
     admin: Address,
@@ -60,15 +112,30 @@ pub fn admin_burn(
         return Err(Error::Unauthorized);
     }
 
+    // Emergency clawbacks can be allowed through a contract-wide pause
+    if storage::is_paused(env) && !storage::is_admin_burn_during_pause(env) {
+        return Err(Error::ContractPaused);
+    }
+
     validate_amount(amount)?;
-    validate_address(&holder)?;
+    validate_address(env, &holder)?;
 
     let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if !info.clawback_enabled {
+        return Err(Error::ClawbackDisabled);
+    }
+    if !info.burnable && !storage::is_clawback_non_burnable_allowed(env) {
+        return Err(Error::BurnDisabled);
+    }
 
     // Token-level pause check
     if storage::is_token_paused(env, token_index) {
         return Err(Error::TokenPaused);
     }
+    crate::token_creation::ensure_token_unlocked(env, &info.address)?;
+
+    let now = env.ledger().timestamp();
+    check_clawback_cooldown(env, &info.address, &holder, now)?;
 
     let balance = storage::get_balance(env, token_index, &holder);
     if balance < amount {
@@ -76,6 +143,31 @@ pub fn admin_burn(
     }
 
     let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
+
+    // Redirect mode moves the tokens instead of burning them
+    if let ClawbackMode::Redirect(destination) = info.clawback_mode.clone() {
+        // The destination receives tokens like any transfer recipient
+        if storage::is_address_frozen(env, &info.address, &destination) {
+            return Err(Error::AddressFrozen);
+        }
+        crate::token_creation::ensure_gate_holder(env, &info, &destination)?;
+
+        let destination_balance = storage::get_balance(env, token_index, &destination);
+        let new_holders = u32::from(destination_balance == 0 && new_balance != 0);
+        crate::mint::ensure_holder_capacity(env, &info, token_index, new_holders)?;
+        let destination_balance = destination_balance
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticError)?;
+        crate::token_creation::ensure_min_balance(&info, destination_balance)?;
+
+        storage::set_balance(env, token_index, &holder, new_balance);
+        storage::set_balance(env, token_index, &destination, destination_balance);
+        storage::set_last_clawback(env, &info.address, &holder, now);
+
+        emit_clawback_redirected_event(env, token_index, &admin, &holder, &destination, amount);
+        return Ok(());
+    }
+
     let new_supply = info
         .total_supply
         .checked_sub(amount)
@@ -96,6 +188,10 @@ pub fn admin_burn(
     // 8. Emit event with both admin and holder for auditability
     storage::increment_burn_count(env, token_index);
     storage::add_total_burned(env, token_index, amount);
+    crate::mint::retire_global_supply(env, amount);
+    storage::add_global_burn(env, amount, 1);
+
+    storage::set_last_clawback(env, &info.address, &holder, now);
 
     emit_admin_burn_event(env, token_index, &admin, &holder, amount, new_supply);
     Ok(())
@@ -107,10 +203,94 @@ pub fn batch_burn(
     token_index: u32,
     burns: soroban_sdk::Vec<(Address, i128)>,
 ) -> Result<(), Error> {
+    let info = check_batch_burn(env, &admin, token_index, &burns)?;
+
+    // Pre-validation pass (all-or-nothing guarantee)
+    let mut total_burn: i128 = 0;
+    for i in 0..burns.len() {
+        let (ref holder, amount) = burns.get(i).unwrap();
+        total_burn = validate_batch_entry(env, token_index, holder, amount, total_burn)
+            .map_err(|reason| {
+                emit_batch_entry_failed_event(env, token_index, i, reason);
+                Error::BatchEntryFailed
+            })?;
+    }
+
+    if info.total_supply < total_burn {
+        return Err(Error::InsufficientBalance);
+    }
+
+    // Mutation pass
+    for i in 0..burns.len() {
+        let (ref holder, amount) = burns.get(i).unwrap();
+        let balance = storage::get_balance(env, token_index, holder);
+        let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
+        storage::set_balance(env, token_index, holder, new_balance);
+    }
+
+    record_batch_burn(env, token_index, &admin, info, burns.len(), total_burn)
+}
+
+/// Best-effort batch burn: each entry succeeds or fails on its own
+///
+/// Runs the same batch-level checks as `batch_burn`, which still fail the
+/// whole call. Entries are then validated and burned one at a time, so a
+/// holder listed twice is checked against its balance after the first
+/// burn. A failed entry emits `bch_fl_v1` and is skipped.
+///
+/// Returns `(holder, burned)` for every entry, in order.
+pub fn batch_burn_lenient(
+    env: &Env,
+    admin: Address,
+    token_index: u32,
+    burns: soroban_sdk::Vec<(Address, i128)>,
+) -> Result<soroban_sdk::Vec<(Address, bool)>, Error> {
+    let info = check_batch_burn(env, &admin, token_index, &burns)?;
+
+    let mut results = soroban_sdk::Vec::new(env);
+    let mut total_burn: i128 = 0;
+    let mut burned: u32 = 0;
+    for i in 0..burns.len() {
+        let (holder, amount) = burns.get(i).unwrap();
+        let outcome = validate_batch_entry(env, token_index, &holder, amount, total_burn)
+            .and_then(|total| {
+                if info.total_supply < total {
+                    return Err(Error::InsufficientBalance);
+                }
+                Ok(total)
+            });
+        match outcome {
+            Ok(total) => {
+                let balance = storage::get_balance(env, token_index, &holder);
+                storage::set_balance(env, token_index, &holder, balance - amount);
+                total_burn = total;
+                burned += 1;
+                results.push_back((holder, true));
+            }
+            Err(reason) => {
+                emit_batch_entry_failed_event(env, token_index, i, reason);
+                results.push_back((holder, false));
+            }
+        }
+    }
+
+    if burned > 0 {
+        record_batch_burn(env, token_index, &admin, info, burned, total_burn)?;
+    }
+    Ok(results)
+}
+
+/// Batch-level checks shared by the strict and lenient batch burns
+fn check_batch_burn(
+    env: &Env,
+    admin: &Address,
+    token_index: u32,
+    burns: &soroban_sdk::Vec<(Address, i128)>,
+) -> Result<TokenInfo, Error> {
     admin.require_auth();
 
     let current_admin = storage::get_admin(env);
-    if admin != current_admin {
+    if *admin != current_admin {
         return Err(Error::Unauthorized);
     }
 
@@ -121,67 +301,217 @@ pub fn batch_burn(
         return Err(Error::InvalidParameters);
     }
 
-    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if !info.burnable {
+        return Err(Error::BurnDisabled);
+    }
 
     // Token-level pause check
     if storage::is_token_paused(env, token_index) {
         return Err(Error::TokenPaused);
     }
+    crate::token_creation::ensure_token_unlocked(env, &info.address)?;
 
-    // Pre-validation pass (all-or-nothing guarantee)
-    let mut total_burn: i128 = 0;
-    for i in 0..burns.len() {
-        let (ref holder, amount) = burns.get(i).unwrap();
-        validate_amount(amount)?;
-        validate_address(holder)?;
+    Ok(info)
+}
 
-        let balance = storage::get_balance(env, token_index, holder);
-        if balance < amount {
-            return Err(Error::InsufficientBalance);
-        }
-        total_burn = total_burn
-            .checked_add(amount)
-            .ok_or(Error::ArithmeticError)?;
+/// Update supply and burn stats after `count` batch entries burned `total_burn`
+fn record_batch_burn(
+    env: &Env,
+    token_index: u32,
+    admin: &Address,
+    mut info: TokenInfo,
+    count: u32,
+    total_burn: i128,
+) -> Result<(), Error> {
+    let new_supply = info
+        .total_supply
+        .checked_sub(total_burn)
+        .ok_or(Error::ArithmeticError)?;
+    info.total_supply = new_supply;
+    info.total_burned = info
+        .total_burned
+        .checked_add(total_burn)
+        .ok_or(Error::ArithmeticError)?;
+    info.burn_count = info
+        .burn_count
+        .checked_add(count)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_token_info(env, token_index, &info);
+    storage::increment_burn_count(env, token_index)?;
+    storage::add_total_burned(env, token_index, total_burn);
+    crate::mint::retire_global_supply(env, total_burn);
+    storage::add_global_burn(env, total_burn, count);
+
+    emit_batch_burn_event(env, token_index, admin, count, total_burn, new_supply);
+    Ok(())
+}
+
+pub fn burn_from(
+    env: &Env,
+    token_address: Address,
+    spender: Address,
+    from: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
     }
 
-    if info.total_supply < total_burn {
+    spender.require_auth();
+    validate_amount(amount)?;
+    validate_address(env, &from)?;
+
+    let token_index = storage::get_token_index(env, &token_address).ok_or(Error::TokenNotFound)?;
+    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if !info.burnable {
+        return Err(Error::BurnDisabled);
+    }
+
+    // Token-level pause check
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    crate::token_creation::ensure_token_unlocked(env, &info.address)?;
+
+    if storage::is_address_frozen(env, &token_address, &from) {
+        return Err(Error::AddressFrozen);
+    }
+
+    let balance = storage::get_balance(env, token_index, &from);
+    if balance < amount {
         return Err(Error::InsufficientBalance);
     }
 
-    // Mutation pass
-    for i in 0..burns.len() {
-        let (ref holder, amount) = burns.get(i).unwrap();
-        let balance = storage::get_balance(env, token_index, holder);
-        let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
-        storage::set_balance(env, token_index, holder, new_balance);
+    let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
+    crate::token_creation::ensure_min_balance(&info, new_balance)?;
+
+    crate::allowance::spend_allowance(env, &token_address, &from, &spender, amount)?;
+
+    let new_supply = info
+        .total_supply
+        .checked_sub(amount)
+        .ok_or(Error::ArithmeticError)?;
+
+    storage::set_balance(env, token_index, &from, new_balance);
+    info.total_supply = new_supply;
+    info.total_burned = info
+        .total_burned
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    info.burn_count = info
+        .burn_count
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_token_info(env, token_index, &info);
+    storage::set_token_info_by_address(env, &token_address, &info);
+
+    storage::increment_burn_count(env, token_index);
+    storage::add_total_burned(env, token_index, amount);
+    crate::mint::retire_global_supply(env, amount);
+    storage::add_global_burn(env, amount, 1);
+
+    if !info.events_muted {
+        emit_burn_from_event(env, token_index, &spender, &from, amount, new_supply);
+    }
+    Ok(())
+}
+
+/// Burn every listed balance below `threshold` (creator only)
+///
+/// Holders at or above the threshold, or with nothing left, are skipped.
+/// Returns the total amount swept.
+pub fn sweep_dust(
+    env: &Env,
+    token_address: Address,
+    creator: Address,
+    threshold: i128,
+    holders: soroban_sdk::Vec<Address>,
+) -> Result<i128, Error> {
+    creator.require_auth();
+
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if holders.len() > MAX_BATCH_BURN {
+        return Err(Error::BatchTooLarge);
+    }
+    if holders.is_empty() || threshold <= 0 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let token_index = storage::get_token_index(env, &token_address).ok_or(Error::TokenNotFound)?;
+    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if info.creator != creator {
+        return Err(Error::Unauthorized);
+    }
+    if !info.clawback_enabled {
+        return Err(Error::ClawbackDisabled);
+    }
+
+    // Token-level pause check
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    crate::token_creation::ensure_token_unlocked(env, &info.address)?;
+
+    let mut swept: i128 = 0;
+    let mut count: u32 = 0;
+    for holder in holders.iter() {
+        let balance = storage::get_balance(env, token_index, &holder);
+        if balance <= 0 || balance >= threshold {
+            continue;
+        }
+        storage::set_balance(env, token_index, &holder, 0);
+        swept = swept.checked_add(balance).ok_or(Error::ArithmeticError)?;
+        count += 1;
+    }
+
+    if count == 0 {
+        return Ok(0);
     }
 
     let new_supply = info
         .total_supply
-        .checked_sub(total_burn)
+        .checked_sub(swept)
         .ok_or(Error::ArithmeticError)?;
     info.total_supply = new_supply;
     info.total_burned = info
         .total_burned
-        .checked_add(total_burn)
+        .checked_add(swept)
         .ok_or(Error::ArithmeticError)?;
     info.burn_count = info
         .burn_count
-        .checked_add(burns.len())
+        .checked_add(count)
         .ok_or(Error::ArithmeticError)?;
     storage::set_token_info(env, token_index, &info);
+    storage::set_token_info_by_address(env, &token_address, &info);
+
     storage::increment_burn_count(env, token_index)?;
-    storage::add_total_burned(env, token_index, total_burn);
+    storage::add_total_burned(env, token_index, swept);
+    crate::mint::retire_global_supply(env, swept);
+    storage::add_global_burn(env, swept, count);
 
-    emit_batch_burn_event(
-        env,
-        token_index,
-        &admin,
-        burns.len(),
-        total_burn,
-        new_supply,
-    );
-    Ok(())
+    emit_dust_swept_event(env, token_index, &creator, count, swept, new_supply);
+    Ok(swept)
+}
+
+/// Reject a clawback from `holder` inside the configured cooldown
+fn check_clawback_cooldown(
+    env: &Env,
+    token_address: &Address,
+    holder: &Address,
+    now: u64,
+) -> Result<(), Error> {
+    let cooldown = storage::get_clawback_cooldown(env);
+    if cooldown == 0 {
+        return Ok(());
+    }
+    match storage::get_last_clawback(env, token_address, holder) {
+        Some(last) if now < last.saturating_add(cooldown) => Err(Error::ClawbackRateLimited),
+        _ => Ok(()),
+    }
 }
 
 pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
@@ -192,6 +522,24 @@ pub fn get_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
     storage::get_balance(env, token_index, holder)
 }
 
+/// Validate one batch burn entry and return the running burn total
+fn validate_batch_entry(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    amount: i128,
+    total_burn: i128,
+) -> Result<i128, Error> {
+    validate_amount(amount)?;
+    validate_address(env, holder)?;
+
+    let balance = storage::get_balance(env, token_index, holder);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    total_burn.checked_add(amount).ok_or(Error::ArithmeticError)
+}
+
 fn validate_amount(amount: i128) -> Result<(), Error> {
     if amount <= 0 {
         return Err(Error::InvalidParameters);
@@ -199,8 +547,15 @@ fn validate_amount(amount: i128) -> Result<(), Error> {
     Ok(())
 }
 
-fn validate_address(addr: &Address) -> Result<(), Error> {
-    let _ = addr;
+/// Reject the factory's own address as a holder
+///
+/// Balances held by the factory contract itself would be counted against
+/// its own accounting, so burns and transfers involving it are refused.
+/// The treasury is an ordinary external account and is not affected.
+pub(crate) fn validate_address(env: &Env, addr: &Address) -> Result<(), Error> {
+    if *addr == env.current_contract_address() {
+        return Err(Error::InvalidParameters);
+    }
     Ok(())
 }
 
@@ -224,7 +579,8 @@ fn validate_address(addr: &Address) -> Result<(), Error> {
 ///
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 fn emit_burn_event(env: &Env, token_index: u32, caller: &Address, amount: i128, new_supply: i128) {
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("burn_v1"), token_index),
         (caller.clone(), amount, new_supply),
     );
@@ -254,12 +610,41 @@ fn emit_admin_burn_event(
     amount: i128,
     new_supply: i128,
 ) {
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("adm_bn_v1"), token_index),
         (admin.clone(), holder.clone(), amount, new_supply),
     );
 }
 
+/// Emit redirected clawback event
+///
+/// **Event Name**: claw_rdr
+///
+/// **Topics** (indexed):
+/// - Event name: "claw_rdr"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who initiated the clawback
+/// - holder: Address - The address the tokens were taken from
+/// - destination: Address - The redirect address that received them
+/// - amount: i128 - The amount moved
+fn emit_clawback_redirected_event(
+    env: &Env,
+    token_index: u32,
+    admin: &Address,
+    holder: &Address,
+    destination: &Address,
+    amount: i128,
+) {
+    crate::events::publish(
+        env,
+        (symbol_short!("claw_rdr"), token_index),
+        (admin.clone(), holder.clone(), destination.clone(), amount),
+    );
+}
+
 /// Emit batch burn event (v1)
 ///
 /// **Schema Version**: 1
@@ -284,8 +669,96 @@ fn emit_batch_burn_event(
     total_burned: i128,
     new_supply: i128,
 ) {
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("bch_bn_v1"), token_index),
         (admin.clone(), count, total_burned, new_supply),
     );
 }
+
+/// Emit dust sweep event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: dust_swp
+///
+/// **Topics** (indexed):
+/// - Event name: "dust_swp"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who ran the sweep
+/// - count: u32 - The number of balances burned
+/// - total_burned: i128 - The total amount swept
+/// - new_supply: i128 - The new total supply after the sweep
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+fn emit_dust_swept_event(
+    env: &Env,
+    token_index: u32,
+    creator: &Address,
+    count: u32,
+    total_burned: i128,
+    new_supply: i128,
+) {
+    crate::events::publish(
+        env,
+        (symbol_short!("dust_swp"), token_index),
+        (creator.clone(), count, total_burned, new_supply),
+    );
+}
+
+/// Emit burn-from event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: brn_fr_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "brn_fr_v1"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - spender: Address - The approved spender that burned
+/// - from: Address - The holder whose balance was burned
+/// - amount: i128 - The amount burned
+/// - new_supply: i128 - The new total supply after burn
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+fn emit_burn_from_event(
+    env: &Env,
+    token_index: u32,
+    spender: &Address,
+    from: &Address,
+    amount: i128,
+    new_supply: i128,
+) {
+    crate::events::publish(
+        env,
+        (symbol_short!("brn_fr_v1"), token_index),
+        (spender.clone(), from.clone(), amount, new_supply),
+    );
+}
+
+/// Emit batch entry failed diagnostic event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: bch_fl_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "bch_fl_v1"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - entry_index: u32 - Position of the failing entry in the batch
+/// - reason: u32 - Error code the entry failed with
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+///
+/// Emitted immediately before a batch reverts with `BatchEntryFailed` so
+/// off-chain tooling can pinpoint the bad entry.
+fn emit_batch_entry_failed_event(env: &Env, token_index: u32, entry_index: u32, reason: Error) {
+    crate::events::publish(
+        env,
+        (symbol_short!("bch_fl_v1"), token_index),
+        (entry_index, reason.0),
+    );
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+const INITIAL_SUPPLY: i128 = 1_000_000;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let holder = Address::generate(&env);
+    let params = TokenCreationParams {
+        initial_supply: INITIAL_SUPPLY,
+        ..token_params(&env, "BURN")
+    };
+    let token = client
+        .batch_create_tokens(&holder, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    let spender = Address::generate(&env);
+    (env, client, token, holder, spender)
+}
+
+#[test]
+fn test_burn_from_within_allowance() {
+    let (_env, client, token, holder, spender) = setup();
+
+    client.approve(&token, &holder, &spender, &500);
+    client.burn_from(&token, &spender, &holder, &200);
+
+    assert_eq!(client.get_allowance(&token, &holder, &spender), 300);
+    let info = client.get_token_info_by_address(&token);
+    assert_eq!(info.total_supply, INITIAL_SUPPLY - 200);
+    assert_eq!(info.total_burned, 200);
+    assert_eq!(info.burn_count, 1);
+    assert_eq!(client.get_token_info(&0).total_supply, INITIAL_SUPPLY - 200);
+}
+
+#[test]
+fn test_burn_from_exceeding_allowance_is_rejected() {
+    let (_env, client, token, holder, spender) = setup();
+
+    client.approve(&token, &holder, &spender, &100);
+    let result = client.try_burn_from(&token, &spender, &holder, &101);
+
+    assert_eq!(result, Err(Ok(Error::InsufficientAllowance)));
+    assert_eq!(client.get_allowance(&token, &holder, &spender), 100);
+    assert_eq!(
+        client.get_token_info_by_address(&token).total_supply,
+        INITIAL_SUPPLY
+    );
+}
+
+#[test]
+fn test_burn_from_without_approval_is_rejected() {
+    let (_env, client, token, holder, spender) = setup();
+
+    let result = client.try_burn_from(&token, &spender, &holder, &1);
+
+    assert_eq!(result, Err(Ok(Error::InsufficientAllowance)));
+}
+
+#[test]
+fn test_burn_from_exhausts_allowance() {
+    let (_env, client, token, holder, spender) = setup();
+
+    client.approve(&token, &holder, &spender, &250);
+    client.burn_from(&token, &spender, &holder, &250);
+
+    assert_eq!(client.get_allowance(&token, &holder, &spender), 0);
+    assert_eq!(
+        client.try_burn_from(&token, &spender, &holder, &1),
+        Err(Ok(Error::InsufficientAllowance))
+    );
+}
+
+#[test]
+fn test_burn_from_respects_pause() {
+    let (_env, client, token, holder, spender) = setup();
+    let admin = client.get_state().admin;
+
+    client.approve(&token, &holder, &spender, &500);
+
+    client.pause_token(&admin, &0);
+    assert_eq!(
+        client.try_burn_from(&token, &spender, &holder, &100),
+        Err(Ok(Error::TokenPaused))
+    );
+    client.unpause_token(&admin, &0);
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_burn_from(&token, &spender, &holder, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+#[test]
+fn test_approve_rejects_negative_amount() {
+    let (_env, client, token, holder, spender) = setup();
+
+    assert_eq!(
+        client.try_approve(&token, &holder, &spender, &-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
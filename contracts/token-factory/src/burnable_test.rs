@@ -0,0 +1,128 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        admin,
+    }
+}
+
+/// Create a token and hand 10_000 units to a fresh holder
+fn create(s: &Setup, symbol: &str, burnable: Option<bool>) -> (Address, u32, Address) {
+    let creator = Address::generate(&s.env);
+    let params = TokenCreationParams {
+        burnable,
+        ..token_params(&s.env, symbol)
+    };
+    let token = s
+        .client
+        .create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+    let index = s.client.get_next_token_index() - 1;
+
+    let holder = Address::generate(&s.env);
+    s.client
+        .transfer_with_memo(&token, &creator, &holder, &10_000, &Bytes::new(&s.env));
+    s.client.set_clawback(&token, &creator, &true);
+    (token, index, holder)
+}
+
+fn balance(s: &Setup, index: u32, who: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, index, who))
+}
+
+#[test]
+fn test_tokens_are_burnable_by_default() {
+    let s = setup();
+    let (token, index, holder) = create(&s, "DFLT", None);
+
+    assert!(s.client.is_burnable(&token));
+    s.client.burn(&holder, &index, &100);
+    assert_eq!(balance(&s, index, &holder), 9_900);
+}
+
+#[test]
+fn test_non_burnable_token_rejects_holder_burns() {
+    let s = setup();
+    let (token, index, holder) = create(&s, "NOBRN", Some(false));
+    let spender = Address::generate(&s.env);
+    s.client.approve(&token, &holder, &spender, &500);
+
+    assert!(!s.client.is_burnable(&token));
+    assert_eq!(
+        s.client.try_burn(&holder, &index, &100),
+        Err(Ok(Error::BurnDisabled))
+    );
+    assert_eq!(
+        s.client.try_simulate_burn(&token, &holder, &100),
+        Err(Ok(Error::BurnDisabled))
+    );
+    assert_eq!(
+        s.client.try_burn_from(&token, &spender, &holder, &100),
+        Err(Ok(Error::BurnDisabled))
+    );
+    assert_eq!(
+        s.client
+            .try_batch_burn(&s.admin, &index, &vec![&s.env, (holder.clone(), 100)]),
+        Err(Ok(Error::BurnDisabled))
+    );
+    assert_eq!(balance(&s, index, &holder), 10_000);
+}
+
+#[test]
+fn test_burnable_token_accepts_burns() {
+    let s = setup();
+    let (token, index, holder) = create(&s, "BRN", Some(true));
+    let spender = Address::generate(&s.env);
+    s.client.approve(&token, &holder, &spender, &500);
+
+    s.client.burn(&holder, &index, &100);
+    s.client.burn_from(&token, &spender, &holder, &100);
+    assert_eq!(balance(&s, index, &holder), 9_800);
+}
+
+#[test]
+fn test_clawback_of_non_burnable_is_configurable() {
+    let s = setup();
+    let (_token, index, holder) = create(&s, "CLAW", Some(false));
+
+    // Allowed by default
+    s.client.admin_burn(&s.admin, &index, &holder, &100);
+    assert_eq!(balance(&s, index, &holder), 9_900);
+
+    s.client.set_clawback_non_burnable(&s.admin, &false);
+    assert!(!s.client.is_clawback_non_burnable_allowed());
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &index, &holder, &100),
+        Err(Ok(Error::BurnDisabled))
+    );
+    assert_eq!(balance(&s, index, &holder), 9_900);
+}
+
+#[test]
+fn test_unknown_token_is_not_burnable() {
+    let s = setup();
+    assert!(!s.client.is_burnable(&Address::generate(&s.env)));
+}
@@ -251,7 +251,8 @@ fn emit_buyback_step_settled(
     burned: i128,
     delta: i128,
 ) {
-    env.events().publish(
+    crate::events::publish(
+        env,
         (soroban_sdk::symbol_short!("buyback"), campaign_id),
         (spent, bought, burned, delta),
     );
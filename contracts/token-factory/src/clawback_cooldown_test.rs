@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String};
+
+const COOLDOWN: u64 = 3_600;
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    alice: Address,
+    bob: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+    env.ledger().set_timestamp(1_000_000);
+
+    let params = token_params(&env, "CLAW");
+    let creator = Address::generate(&env);
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+    client.set_clawback(&token, &creator, &true);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &alice, 1_000);
+        storage::set_balance(&env, 0, &bob, 1_000);
+    });
+
+    client.set_clawback_cooldown(&admin, &COOLDOWN);
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        admin,
+        alice,
+        bob,
+    }
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, 0, holder))
+}
+
+#[test]
+fn test_second_clawback_within_cooldown_rejected() {
+    let s = setup();
+
+    s.client.admin_burn(&s.admin, &0, &s.alice, &100);
+
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &0, &s.alice, &100),
+        Err(Ok(Error::ClawbackRateLimited))
+    );
+    assert_eq!(balance(&s, &s.alice), 900);
+}
+
+#[test]
+fn test_clawback_allowed_after_cooldown() {
+    let s = setup();
+    s.client.admin_burn(&s.admin, &0, &s.alice, &100);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + COOLDOWN - 1);
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &0, &s.alice, &100),
+        Err(Ok(Error::ClawbackRateLimited))
+    );
+
+    s.env.ledger().set_timestamp(s.env.ledger().timestamp() + 1);
+    s.client.admin_burn(&s.admin, &0, &s.alice, &100);
+    assert_eq!(balance(&s, &s.alice), 800);
+}
+
+#[test]
+fn test_other_holder_unaffected() {
+    let s = setup();
+    s.client.admin_burn(&s.admin, &0, &s.alice, &100);
+
+    s.client.admin_burn(&s.admin, &0, &s.bob, &100);
+
+    assert_eq!(balance(&s, &s.bob), 900);
+}
+
+#[test]
+fn test_zero_cooldown_disables_limit() {
+    let s = setup();
+    s.client.set_clawback_cooldown(&s.admin, &0);
+
+    s.client.admin_burn(&s.admin, &0, &s.alice, &100);
+    s.client.admin_burn(&s.admin, &0, &s.alice, &100);
+
+    assert_eq!(balance(&s, &s.alice), 800);
+}
+
+#[test]
+fn test_set_clawback_cooldown_requires_admin() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_set_clawback_cooldown(&Address::generate(&s.env), &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.client.get_clawback_cooldown(), COOLDOWN);
+}
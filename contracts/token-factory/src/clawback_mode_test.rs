@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, Env, String};
+use types::ClawbackMode;
+
+const SUPPLY: i128 = 1_000_000;
+const HELD: i128 = 10_000;
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    holder: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&env, "RCVR")
+    };
+    let token = client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+    client.set_clawback(&token, &creator, &true);
+
+    let holder = Address::generate(&env);
+    client.transfer_with_memo(&token, &creator, &holder, &HELD, &Bytes::new(&env));
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        admin,
+        creator,
+        holder,
+        token,
+    }
+}
+
+fn balance(s: &Setup, who: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, 0, who))
+}
+
+#[test]
+fn test_redirect_mode_moves_tokens_without_burning() {
+    let s = setup();
+    let recovery = Address::generate(&s.env);
+    s.client.set_clawback_mode(
+        &s.token,
+        &s.creator,
+        &ClawbackMode::Redirect(recovery.clone()),
+    );
+    assert_eq!(
+        s.client.get_token_info(&0).clawback_mode,
+        ClawbackMode::Redirect(recovery.clone())
+    );
+
+    s.client.admin_burn(&s.admin, &0, &s.holder, &4_000);
+
+    assert_eq!(balance(&s, &s.holder), HELD - 4_000);
+    assert_eq!(balance(&s, &recovery), 4_000);
+    let info = s.client.get_token_info(&0);
+    assert_eq!(info.total_supply, SUPPLY);
+    assert_eq!(info.total_burned, 0);
+}
+
+#[test]
+fn test_burn_mode_reduces_supply() {
+    let s = setup();
+    assert_eq!(
+        s.client.get_token_info(&0).clawback_mode,
+        ClawbackMode::Burn
+    );
+
+    s.client.admin_burn(&s.admin, &0, &s.holder, &4_000);
+
+    assert_eq!(balance(&s, &s.holder), HELD - 4_000);
+    let info = s.client.get_token_info(&0);
+    assert_eq!(info.total_supply, SUPPLY - 4_000);
+    assert_eq!(info.total_burned, 4_000);
+}
+
+#[test]
+fn test_only_creator_sets_mode() {
+    let s = setup();
+    let recovery = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client
+            .try_set_clawback_mode(&s.token, &s.holder, &ClawbackMode::Redirect(recovery)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_set_clawback_mode(
+            &s.token,
+            &s.creator,
+            &ClawbackMode::Redirect(s.token.clone())
+        ),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_redirect_destination_must_hold_gate_token() {
+    let s = setup();
+    let recovery = Address::generate(&s.env);
+    let gate_params = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&s.env, "GATE")
+    };
+    let gate = s.client.create_token(
+        &s.creator,
+        &gate_params,
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    s.client
+        .set_token_gate(&s.token, &s.creator, &Some(gate.clone()));
+    s.client.set_clawback_mode(
+        &s.token,
+        &s.creator,
+        &ClawbackMode::Redirect(recovery.clone()),
+    );
+
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &0, &s.holder, &4_000),
+        Err(Ok(Error::GateTokenRequired))
+    );
+    assert_eq!(balance(&s, &s.holder), HELD);
+
+    s.client
+        .transfer_with_memo(&gate, &s.creator, &recovery, &1, &Bytes::new(&s.env));
+    s.client.admin_burn(&s.admin, &0, &s.holder, &4_000);
+    assert_eq!(balance(&s, &recovery), 4_000);
+}
+
+#[test]
+fn test_admin_burn_requires_clawback_enabled() {
+    let s = setup();
+    s.client.set_clawback(&s.token, &s.creator, &false);
+
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &0, &s.holder, &4_000),
+        Err(Ok(Error::ClawbackDisabled))
+    );
+    assert_eq!(balance(&s, &s.holder), HELD);
+
+    // Either toggle re-enables it
+    s.client.bulk_set_clawback(
+        &s.creator,
+        &soroban_sdk::vec![&s.env, s.token.clone()],
+        &true,
+    );
+    s.client.admin_burn(&s.admin, &0, &s.holder, &4_000);
+    assert_eq!(balance(&s, &s.holder), HELD - 4_000);
+}
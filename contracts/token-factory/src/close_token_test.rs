@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, Env, String};
+use types::{DataKey, TokenKey};
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    holder: Address,
+    token: Address,
+    index: u32,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        initial_supply: 1_000,
+        ..token_params(&env, "WDWN")
+    };
+    let token = client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+    let index = client.get_next_token_index() - 1;
+
+    let holder = Address::generate(&env);
+    client.transfer_with_memo(&token, &creator, &holder, &400, &Bytes::new(&env));
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        admin,
+        creator,
+        holder,
+        token,
+        index,
+    }
+}
+
+fn drain(s: &Setup) {
+    s.client.burn(&s.holder, &s.index, &400);
+    s.client.burn(&s.creator, &s.index, &600);
+}
+
+fn has_persistent<K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(s: &Setup, key: &K) -> bool {
+    s.env
+        .as_contract(&s.contract_id, || s.env.storage().persistent().has(key))
+}
+
+#[test]
+fn test_close_drained_token_clears_storage() {
+    let s = setup();
+    let website = String::from_str(&s.env, "website");
+    s.client.set_token_attribute(
+        &s.token,
+        &s.creator,
+        &website,
+        &String::from_str(&s.env, "https://nova.example"),
+    );
+    drain(&s);
+    let live_before = s.client.get_token_count(&false);
+
+    s.client.close_token(&s.admin, &s.token);
+
+    assert!(s.client.is_token_archived(&s.index));
+    assert_eq!(s.client.get_token_count(&false), live_before - 1);
+
+    assert!(!has_persistent(
+        &s,
+        &DataKey::Balance(s.index, s.holder.clone())
+    ));
+    assert!(!has_persistent(
+        &s,
+        &DataKey::Balance(s.index, s.creator.clone())
+    ));
+    assert!(!has_persistent(&s, &DataKey::BurnCount(s.index)));
+    assert!(!has_persistent(&s, &DataKey::TotalBurned(s.index)));
+    assert!(!has_persistent(
+        &s,
+        &TokenKey::TokenAttribute(s.token.clone(), website.clone())
+    ));
+    assert!(!has_persistent(
+        &s,
+        &TokenKey::TokenCreator(s.token.clone())
+    ));
+    assert_eq!(s.client.get_token_attribute(&s.token, &website), None);
+    assert_eq!(s.client.get_burn_count(&s.index), 0);
+}
+
+#[test]
+fn test_closed_token_operations_fail() {
+    let s = setup();
+    drain(&s);
+    s.client.close_token(&s.admin, &s.token);
+
+    assert_eq!(
+        s.client.try_get_token_info(&s.index),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(
+        s.client.try_get_token_info_detailed(&s.index),
+        Err(Ok(Error::TokenArchived))
+    );
+    assert_eq!(
+        s.client.try_get_token_info_by_address(&s.token),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(
+        s.client.try_mint(&s.creator, &s.index, &s.holder, &1),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(
+        s.client
+            .try_transfer_with_memo(&s.token, &s.creator, &s.holder, &1, &Bytes::new(&s.env)),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(
+        s.client.try_close_token(&s.admin, &s.token),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_token_with_supply_cannot_be_closed() {
+    let s = setup();
+    s.client.burn(&s.holder, &s.index, &400);
+
+    assert_eq!(
+        s.client.try_close_token(&s.admin, &s.token),
+        Err(Ok(Error::SupplyNotZero))
+    );
+    assert!(!s.client.is_token_archived(&s.index));
+}
+
+#[test]
+fn test_already_archived_token_counted_once() {
+    let s = setup();
+    drain(&s);
+    s.client.archive_token(&s.token, &s.creator);
+    let live_before = s.client.get_token_count(&false);
+
+    s.client.close_token(&s.admin, &s.token);
+
+    assert_eq!(s.client.get_token_count(&false), live_before);
+}
+
+#[test]
+fn test_non_admin_cannot_close() {
+    let s = setup();
+    drain(&s);
+
+    assert_eq!(
+        s.client.try_close_token(&s.creator, &s.token),
+        Err(Ok(Error::Unauthorized))
+    );
+}
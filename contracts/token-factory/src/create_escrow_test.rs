@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    contract_id: Address,
+    fee_token: TokenClient<'static>,
+    creator: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset.address()).mint(&creator, &1_000_000_000);
+
+    Setup {
+        fee_token: TokenClient::new(&env, &fee_asset.address()),
+        env,
+        client,
+        contract_id,
+        creator,
+    }
+}
+
+fn spec(env: &Env) -> TokenCreationParams {
+    token_params(env, "ESC")
+}
+
+#[test]
+fn test_begin_create_holds_fee() {
+    let s = setup();
+
+    let escrow_id = s
+        .client
+        .begin_create(&s.creator, &spec(&s.env), &BASE_FEE, &None);
+
+    assert_eq!(s.fee_token.balance(&s.contract_id), BASE_FEE);
+    assert_eq!(s.fee_token.balance(&s.creator), 1_000_000_000 - BASE_FEE);
+    let escrow = s.client.get_create_escrow(&escrow_id).unwrap();
+    assert_eq!(escrow.status, types::EscrowStatus::Pending);
+    assert_eq!(escrow.fee, BASE_FEE);
+}
+
+#[test]
+fn test_cancel_then_finalize_flow() {
+    let s = setup();
+
+    // Cancel refunds the fee
+    let cancelled = s
+        .client
+        .begin_create(&s.creator, &spec(&s.env), &BASE_FEE, &None);
+    s.client.cancel_create(&s.creator, &cancelled);
+    assert_eq!(s.fee_token.balance(&s.creator), 1_000_000_000);
+    assert_eq!(s.fee_token.balance(&s.contract_id), 0);
+
+    // Finalize deploys the token and credits the fee to the fee balance
+    let escrow_id = s
+        .client
+        .begin_create(&s.creator, &spec(&s.env), &BASE_FEE, &None);
+    let token = s.client.finalize_create(&s.creator, &escrow_id);
+
+    let info = s.client.get_token_info_by_address(&token);
+    assert_eq!(info.creator, s.creator);
+    assert_eq!(info.symbol, String::from_str(&s.env, "ESC"));
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), BASE_FEE);
+    assert_eq!(s.fee_token.balance(&s.contract_id), BASE_FEE);
+    assert_eq!(
+        s.client.get_create_escrow(&escrow_id).unwrap().status,
+        types::EscrowStatus::Finalized
+    );
+
+    // A cancelled escrow can no longer be finalized
+    let result = s.client.try_finalize_create(&s.creator, &cancelled);
+    assert_eq!(result, Err(Ok(Error::InvalidStateTransition)));
+}
+
+#[test]
+fn test_finalized_escrow_cannot_be_cancelled() {
+    let s = setup();
+
+    let escrow_id = s
+        .client
+        .begin_create(&s.creator, &spec(&s.env), &BASE_FEE, &None);
+    s.client.finalize_create(&s.creator, &escrow_id);
+
+    let result = s.client.try_cancel_create(&s.creator, &escrow_id);
+    assert_eq!(result, Err(Ok(Error::InvalidStateTransition)));
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), BASE_FEE);
+}
+
+#[test]
+fn test_begin_create_rejects_insufficient_fee() {
+    let s = setup();
+
+    let result = s
+        .client
+        .try_begin_create(&s.creator, &spec(&s.env), &(BASE_FEE - 1), &None);
+
+    assert_eq!(result, Err(Ok(Error::InsufficientFee)));
+    assert_eq!(s.fee_token.balance(&s.creator), 1_000_000_000);
+}
+
+#[test]
+fn test_only_escrow_creator_can_finalize_or_cancel() {
+    let s = setup();
+    let other = Address::generate(&s.env);
+
+    let escrow_id = s
+        .client
+        .begin_create(&s.creator, &spec(&s.env), &BASE_FEE, &None);
+
+    assert_eq!(
+        s.client.try_finalize_create(&other, &escrow_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_cancel_create(&other, &escrow_id),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_unknown_escrow() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_finalize_create(&s.creator, &42),
+        Err(Ok(Error::EscrowNotFound))
+    );
+}
+
+#[test]
+fn test_overpayment_stays_with_creator() {
+    let s = setup();
+
+    let escrow_id = s
+        .client
+        .begin_create(&s.creator, &spec(&s.env), &(BASE_FEE * 2), &None);
+
+    assert_eq!(
+        s.client.get_create_escrow(&escrow_id).unwrap().fee,
+        BASE_FEE
+    );
+    assert_eq!(s.fee_token.balance(&s.creator), 1_000_000_000 - BASE_FEE);
+
+    s.client.finalize_create(&s.creator, &escrow_id);
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), BASE_FEE);
+}
+
+#[test]
+fn test_begin_create_checks_global_cap() {
+    let s = setup();
+    let admin = s.client.get_state().admin;
+    s.client.set_global_supply_cap(&admin, &Some(1));
+
+    assert_eq!(
+        s.client
+            .try_begin_create(&s.creator, &spec(&s.env), &BASE_FEE, &None),
+        Err(Ok(Error::GlobalCapExceeded))
+    );
+    assert_eq!(s.fee_token.balance(&s.creator), 1_000_000_000);
+}
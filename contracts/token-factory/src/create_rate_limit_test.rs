@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+    env.ledger().with_mut(|li| li.sequence_number = 1_200);
+
+    (env, client, admin)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, symbol: &str) -> Result<Address, Error> {
+    let creator = Address::generate(env);
+    client
+        .try_create_token(
+            &creator,
+            &token_params(env, symbol),
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_cap_blocks_until_next_window() {
+    let (env, client, admin) = setup();
+    client.set_window_create_cap(&admin, &2);
+    assert_eq!(client.get_window_create_cap(), 2);
+
+    create(&env, &client, "ONE").unwrap();
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    create(&env, &client, "TWO").unwrap();
+    assert_eq!(
+        create(&env, &client, "THREE"),
+        Err(Error::CreateRateLimited)
+    );
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number += token_creation::CREATE_WINDOW_LEDGERS);
+    create(&env, &client, "THREE").unwrap();
+    assert_eq!(client.get_token_count(&true), 3);
+}
+
+#[test]
+fn test_batch_counts_every_token() {
+    let (env, client, admin) = setup();
+    client.set_window_create_cap(&admin, &2);
+    let creator = Address::generate(&env);
+
+    let result = client.try_batch_create_tokens(
+        &creator,
+        &vec![
+            &env,
+            token_params(&env, "A"),
+            token_params(&env, "B"),
+            token_params(&env, "C"),
+        ],
+        &210_000_000,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::CreateRateLimited)));
+    assert_eq!(client.get_token_count(&true), 0);
+}
+
+#[test]
+fn test_no_cap_by_default() {
+    let (env, client, admin) = setup();
+    assert_eq!(client.get_window_create_cap(), 0);
+
+    for symbol in ["A", "B", "C", "D"] {
+        create(&env, &client, symbol).unwrap();
+    }
+
+    assert_eq!(
+        client.try_set_window_create_cap(&Address::generate(&env), &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_window_create_cap(&admin, &0);
+    create(&env, &client, "E").unwrap();
+}
@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, TryFromVal};
+
+const SUPPLY: i128 = 1_000_000;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    (env, client, creator)
+}
+
+fn spec(env: &Env, symbol: &str) -> TokenCreationParams {
+    TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(env, symbol)
+    }
+}
+
+fn event_names(env: &Env) -> std::vec::Vec<Symbol> {
+    env.events()
+        .all()
+        .iter()
+        .filter_map(|(_, topics, _)| Symbol::try_from_val(env, &topics.get(0)?).ok())
+        .collect()
+}
+
+#[test]
+fn test_emits_created_then_renounced() {
+    let (env, client, creator) = setup();
+
+    let token = client.create_token_renounced(&creator, &spec(&env, "FAIR"), &BASE_FEE);
+
+    let names = event_names(&env);
+    let created = names.iter().position(|n| *n == symbol_short!("tok_crt"));
+    let renounced = names.iter().position(|n| *n == symbol_short!("renounced"));
+    assert!(created.is_some() && renounced.is_some());
+    assert!(created < renounced);
+
+    assert!(client.is_token_renounced(&token));
+    assert!(client.is_minting_finalized(&token));
+    assert!(client.is_token_metadata_frozen(&token));
+
+    let info = client.get_token_info(&0);
+    assert_eq!(info.creator, creator);
+    assert_eq!(info.total_supply, SUPPLY);
+    assert!(!info.clawback_enabled);
+}
+
+#[test]
+fn test_creator_powers_are_gone() {
+    let (env, client, creator) = setup();
+    let token = client.create_token_renounced(&creator, &spec(&env, "FAIR"), &BASE_FEE);
+
+    assert_eq!(
+        client.try_set_clawback(&token, &creator, &true),
+        Err(Ok(Error::TokenRenounced))
+    );
+    assert_eq!(
+        client.try_bulk_set_clawback(&creator, &vec![&env, token.clone()], &true),
+        Err(Ok(Error::TokenRenounced))
+    );
+    assert_eq!(
+        client.try_mint(&creator, &0, &creator, &1),
+        Err(Ok(Error::MintingFinalized))
+    );
+    assert_eq!(
+        client.try_set_token_metadata(&creator, &0, &String::from_str(&env, "ipfs://meta")),
+        Err(Ok(Error::TokenRenounced))
+    );
+    assert_eq!(
+        client.try_set_token_attribute(
+            &token,
+            &creator,
+            &String::from_str(&env, "website"),
+            &String::from_str(&env, "https://fair.example"),
+        ),
+        Err(Ok(Error::MetadataFrozen))
+    );
+}
+
+#[test]
+fn test_holder_burns_still_work() {
+    let (env, client, creator) = setup();
+    client.create_token_renounced(&creator, &spec(&env, "FAIR"), &BASE_FEE);
+
+    client.burn(&creator, &0, &1_000);
+
+    assert_eq!(client.get_token_info(&0).total_supply, SUPPLY - 1_000);
+}
+
+#[test]
+fn test_regular_tokens_are_not_renounced() {
+    let (env, client, creator) = setup();
+    let token = client.create_token(
+        &creator,
+        &spec(&env, "NORM"),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert!(!client.is_token_renounced(&token));
+    client.set_clawback(&token, &creator, &true);
+}
+
+#[test]
+fn test_invalid_spec_creates_nothing() {
+    let (env, client, creator) = setup();
+    let mut bad = spec(&env, "BAD");
+    bad.initial_supply = 0;
+
+    assert_eq!(
+        client.try_create_token_renounced(&creator, &bad, &BASE_FEE),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+    assert_eq!(client.get_token_count(&true), 0);
+}
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, symbol: &str, decimals: u32) -> Address {
+    let creator = Address::generate(env);
+    let params = TokenCreationParams {
+        decimals,
+        ..token_params(env, symbol)
+    };
+    client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None)
+}
+
+#[test]
+fn test_resolves_decimals_and_skips_unknown() {
+    let (env, client) = setup();
+    let six = create(&env, &client, "SIX", 6);
+    let seven = create(&env, &client, "SEVEN", 7);
+    let zero = create(&env, &client, "ZERO", 0);
+
+    let map = client.get_decimals_map(&vec![
+        &env,
+        seven.clone(),
+        Address::generate(&env),
+        zero.clone(),
+        six.clone(),
+        Address::generate(&env),
+    ]);
+
+    assert_eq!(map, vec![&env, (seven, 7), (zero, 0), (six, 6)]);
+}
+
+#[test]
+fn test_all_unknown_returns_empty() {
+    let (env, client) = setup();
+    let map = client.get_decimals_map(&vec![&env, Address::generate(&env)]);
+    assert_eq!(map.len(), 0);
+}
+
+#[test]
+fn test_oversized_batch_rejected() {
+    let (env, client) = setup();
+    let mut addresses = Vec::new(&env);
+    for _ in 0..51 {
+        addresses.push_back(Address::generate(&env));
+    }
+
+    assert_eq!(
+        client.try_get_decimals_map(&addresses),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
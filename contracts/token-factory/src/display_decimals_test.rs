@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+fn params(env: &Env, display_decimals: Option<u32>) -> TokenCreationParams {
+    TokenCreationParams {
+        display_decimals,
+        ..token_params(env, "DSP")
+    }
+}
+
+#[test]
+fn test_display_decimals_override() {
+    let (env, client) = setup();
+
+    let token = client.create_token(
+        &Address::generate(&env),
+        &params(&env, Some(2)),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert_eq!(client.get_display_decimals(&token), 2);
+    let info = client.get_token_info_by_address(&token);
+    assert_eq!(info.decimals, 7);
+    assert_eq!(info.display_decimals, Some(2));
+}
+
+#[test]
+fn test_display_decimals_default_to_decimals() {
+    let (env, client) = setup();
+
+    let token = client.create_token(
+        &Address::generate(&env),
+        &params(&env, None),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert_eq!(client.get_display_decimals(&token), 7);
+}
+
+#[test]
+fn test_display_decimals_above_decimals_rejected() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    assert_eq!(
+        client.try_create_token(
+            &creator,
+            &params(&env, Some(8)),
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        ),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+    assert_eq!(
+        client.try_batch_create_tokens(
+            &creator,
+            &vec![&env, params(&env, Some(8))],
+            &BASE_FEE,
+            &None
+        ),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+    assert_eq!(client.get_token_count(&true), 0);
+}
+
+#[test]
+fn test_display_decimals_unknown_token() {
+    let (env, client) = setup();
+
+    assert_eq!(
+        client.try_get_display_decimals(&Address::generate(&env)),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client, admin)
+}
+
+fn spec(env: &Env) -> TokenCreationParams {
+    token_params(env, "SHUT")
+}
+
+#[test]
+fn test_shutdown_rejects_all_creation_paths() {
+    let (env, _contract_id, client, admin) = setup();
+    let creator = Address::generate(&env);
+
+    client.emergency_shutdown(&admin);
+
+    assert!(client.is_creation_disabled());
+    assert_eq!(
+        client.try_create_token(
+            &creator,
+            &spec(&env),
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None
+        ),
+        Err(Ok(Error::CreationDisabled))
+    );
+    assert_eq!(
+        client.try_batch_create_tokens(&creator, &vec![&env, spec(&env)], &BASE_FEE, &None),
+        Err(Ok(Error::CreationDisabled))
+    );
+    assert_eq!(
+        client.try_begin_create(&creator, &spec(&env), &BASE_FEE, &None),
+        Err(Ok(Error::CreationDisabled))
+    );
+}
+
+#[test]
+fn test_existing_tokens_remain_operable() {
+    let (env, contract_id, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, spec(&env)], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+    client.set_clawback(&token, &creator, &true);
+    let holder = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &holder, 1_000)
+    });
+
+    client.emergency_shutdown(&admin);
+
+    client.burn(&holder, &0, &400);
+    client.admin_burn(&admin, &0, &holder, &100);
+    assert_eq!(client.get_burn_count(&0), 2);
+    assert_eq!(
+        env.as_contract(&contract_id, || storage::get_balance(&env, 0, &holder)),
+        500
+    );
+
+    // Admin operations keep working
+    client.update_fees(&admin, &Some(1), &None);
+    assert_eq!(client.get_state().base_fee, 1);
+}
+
+#[test]
+fn test_pending_escrow_can_only_be_cancelled() {
+    let (env, _contract_id, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let escrow_id = client.begin_create(&creator, &spec(&env), &BASE_FEE, &None);
+
+    client.emergency_shutdown(&admin);
+
+    assert_eq!(
+        client.try_finalize_create(&creator, &escrow_id),
+        Err(Ok(Error::CreationDisabled))
+    );
+    client.cancel_create(&creator, &escrow_id);
+}
+
+#[test]
+fn test_shutdown_is_irreversible() {
+    let (env, _contract_id, client, admin) = setup();
+    client.emergency_shutdown(&admin);
+
+    assert_eq!(
+        client.try_emergency_shutdown(&admin),
+        Err(Ok(Error::CreationDisabled))
+    );
+
+    // Neither unpausing nor a new admin re-enables creation
+    client.pause(&admin);
+    client.unpause(&admin);
+    let new_admin = Address::generate(&env);
+    client.transfer_admin(&admin, &new_admin);
+
+    assert!(client.is_creation_disabled());
+    assert_eq!(
+        client.try_create_token(
+            &Address::generate(&env),
+            &spec(&env),
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None
+        ),
+        Err(Ok(Error::CreationDisabled))
+    );
+}
+
+#[test]
+fn test_shutdown_requires_admin() {
+    let (env, _contract_id, client, _admin) = setup();
+
+    let result = client.try_emergency_shutdown(&Address::generate(&env));
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert!(!client.is_creation_disabled());
+}
@@ -0,0 +1,148 @@
+//! Refundable token creation escrow
+//!
+//! A creator can open a token creation by paying the deployment fee into
+//! escrow, then either finalize it (the token is created and the fee goes
+//! to the treasury) or cancel it before finalizing (the fee is refunded).
+
+use crate::events;
+use crate::fees;
+use crate::storage;
+use crate::token_creation;
+use crate::types::{CreateEscrow, Error, EscrowStatus, TokenCreationParams, TokenKind};
+use soroban_sdk::{Address, Bytes, Env};
+
+/// Open a creation escrow holding the deployment fee
+///
+/// Only the fee the creator owes is held, even when `fee` is larger.
+/// A promo `waiver_code` is redeemed when the escrow opens; cancelling
+/// the escrow does not give the use back.
+///
+/// # Returns
+/// * `Ok(u64)` - The new escrow id
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::CreationDisabled)` - Creation was shut down
+/// * `Err(Error::InvalidTokenParams)` - Token parameters are invalid
+/// * `Err(Error::SymbolReserved)` - Symbol is on the reserved blocklist
+/// * `Err(Error::SymbolTaken)` - Symbol registered while unique symbols
+///   are enforced
+/// * `Err(Error::GlobalCapExceeded)` - Initial supply would exceed the
+///   global supply cap
+/// * `Err(Error::InsufficientFee)` - Fee is below the creation fee the
+///   creator owes (none when exempt or waived)
+pub fn begin_create(
+    env: &Env,
+    creator: &Address,
+    params: TokenCreationParams,
+    fee: i128,
+    waiver_code: Option<Bytes>,
+) -> Result<u64, Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    token_creation::ensure_creation_enabled(env)?;
+
+    creator.require_auth();
+    token_creation::ensure_creator_balance(env, creator)?;
+
+    token_creation::validate_creation_spec(env, &params, TokenKind::Fungible)?;
+
+    let (base, metadata) =
+        token_creation::creation_fee_due(env, creator, &params, waiver_code.as_ref())?;
+    let required_fee = if params.metadata_uri.is_some() {
+        base.checked_add(metadata).ok_or(Error::ArithmeticError)?
+    } else {
+        base
+    };
+    if fee < required_fee {
+        return Err(Error::InsufficientFee);
+    }
+
+    // Only the fee due is held; any excess stays with the creator
+    fees::hold(env, creator, required_fee)?;
+
+    let escrow_id = storage::increment_create_escrow_count(env)?;
+    let escrow = CreateEscrow {
+        id: escrow_id,
+        creator: creator.clone(),
+        params,
+        fee: required_fee,
+        status: EscrowStatus::Pending,
+        created_at: env.ledger().timestamp(),
+    };
+    storage::set_create_escrow(env, &escrow);
+
+    events::emit_escrow_opened(env, escrow_id, creator, required_fee);
+
+    Ok(escrow_id)
+}
+
+/// Create the escrowed token and forward the fee to the treasury
+///
+/// # Returns
+/// * `Ok(Address)` - The created token address
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::CreationDisabled)` - Creation was shut down
+/// * `Err(Error::EscrowNotFound)` - Escrow id does not exist
+/// * `Err(Error::Unauthorized)` - Caller did not open the escrow
+/// * `Err(Error::InvalidStateTransition)` - Escrow was already finalized or cancelled
+pub fn finalize_create(env: &Env, creator: &Address, escrow_id: u64) -> Result<Address, Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    token_creation::ensure_creation_enabled(env)?;
+
+    creator.require_auth();
+
+    let mut escrow = load_pending(env, creator, escrow_id)?;
+
+    let token_index = storage::increment_token_count(env)? - 1;
+    let token_address = token_creation::create_token_internal(
+        env,
+        creator,
+        &escrow.params,
+        token_index,
+        TokenKind::Fungible,
+    )?;
+
+    fees::release(env, escrow.fee)?;
+
+    escrow.status = EscrowStatus::Finalized;
+    storage::set_create_escrow(env, &escrow);
+
+    events::emit_escrow_finalized(env, escrow_id, &token_address, escrow.fee);
+
+    Ok(token_address)
+}
+
+/// Cancel a pending escrow and refund the fee to the creator
+///
+/// # Returns
+/// * `Ok(())` - Escrow cancelled and fee refunded
+/// * `Err(Error::EscrowNotFound)` - Escrow id does not exist
+/// * `Err(Error::Unauthorized)` - Caller did not open the escrow
+/// * `Err(Error::InvalidStateTransition)` - Escrow was already finalized or cancelled
+pub fn cancel_create(env: &Env, creator: &Address, escrow_id: u64) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut escrow = load_pending(env, creator, escrow_id)?;
+
+    escrow.status = EscrowStatus::Cancelled;
+    storage::set_create_escrow(env, &escrow);
+
+    fees::refund(env, creator, escrow.fee)?;
+
+    events::emit_escrow_cancelled(env, escrow_id, creator, escrow.fee);
+
+    Ok(())
+}
+
+fn load_pending(env: &Env, creator: &Address, escrow_id: u64) -> Result<CreateEscrow, Error> {
+    let escrow = storage::get_create_escrow(env, escrow_id).ok_or(Error::EscrowNotFound)?;
+    if escrow.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    if escrow.status != EscrowStatus::Pending {
+        return Err(Error::InvalidStateTransition);
+    }
+    Ok(escrow)
+}
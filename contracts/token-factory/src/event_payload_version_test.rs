@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, Bytes, Env, String, TryFromVal, Val};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client, admin)
+}
+
+fn params(env: &Env) -> TokenCreationParams {
+    token_params(env, "VERS")
+}
+
+/// Payloads of every event from the last invocation, as generic vectors.
+fn payloads(env: &Env) -> soroban_sdk::Vec<soroban_sdk::Vec<Val>> {
+    let mut out = soroban_sdk::Vec::new(env);
+    for (_, _, data) in env.events().all().iter() {
+        out.push_back(soroban_sdk::Vec::<Val>::try_from_val(env, &data).unwrap());
+    }
+    out
+}
+
+fn version_of(env: &Env, payload: &soroban_sdk::Vec<Val>) -> u32 {
+    u32::try_from_val(env, &payload.get(0).unwrap()).unwrap()
+}
+
+#[test]
+fn test_reported_payload_version() {
+    let (_env, _contract_id, client, _admin) = setup();
+
+    assert_eq!(client.get_event_payload_version(), 1);
+    assert_eq!(
+        client.get_event_payload_version(),
+        events::EVENT_PAYLOAD_VERSION
+    );
+}
+
+#[test]
+fn test_every_event_payload_starts_with_version() {
+    let (env, contract_id, client, _admin) = setup();
+    let version = client.get_event_payload_version();
+
+    let creator = Address::generate(&env);
+    client.batch_create_tokens(&creator, &vec![&env, params(&env)], &BASE_FEE, &None);
+    let created = payloads(&env);
+    assert!(!created.is_empty());
+    for payload in created.iter() {
+        assert_eq!(version_of(&env, &payload), version);
+    }
+
+    let token = client.get_token_info(&0).address;
+    let holder = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &holder, 1_000)
+    });
+    let recipient = Address::generate(&env);
+    let memo = Bytes::from_slice(&env, b"versioned");
+    client.transfer_with_memo(&token, &holder, &recipient, &250, &memo);
+    let transferred = payloads(&env);
+    assert!(!transferred.is_empty());
+    for payload in transferred.iter() {
+        assert_eq!(version_of(&env, &payload), version);
+    }
+
+    client.set_token_attribute(
+        &token,
+        &creator,
+        &String::from_str(&env, "website"),
+        &String::from_str(&env, "https://nova.example"),
+    );
+    let updated = payloads(&env);
+    assert!(!updated.is_empty());
+    for payload in updated.iter() {
+        assert_eq!(version_of(&env, &payload), version);
+    }
+}
+
+#[test]
+fn test_fields_follow_version_and_precede_seq() {
+    let (env, contract_id, client, _admin) = setup();
+    client.batch_create_tokens(
+        &Address::generate(&env),
+        &vec![&env, params(&env)],
+        &BASE_FEE,
+        &None,
+    );
+    let token = client.get_token_info(&0).address;
+
+    let holder = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &holder, 1_000)
+    });
+    let recipient = Address::generate(&env);
+    let memo = Bytes::from_slice(&env, b"ordering");
+    client.transfer_with_memo(&token, &holder, &recipient, &400, &memo);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let name = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(name, symbol_short!("xfer_memo"));
+
+    // (version, amount, memo, seq)
+    let (version, amount, decoded_memo, _seq) =
+        <(u32, i128, Bytes, u64)>::try_from_val(&env, &data).unwrap();
+    assert_eq!(version, events::EVENT_PAYLOAD_VERSION);
+    assert_eq!(amount, 400);
+    assert_eq!(decoded_memo, memo);
+}
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, Env, String, TryFromVal, Val};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+/// Sequence numbers stamped on the events of the last invocation
+fn stamped_seqs(env: &Env) -> soroban_sdk::Vec<u64> {
+    let mut seqs = soroban_sdk::Vec::new(env);
+    for (_, _, data) in env.events().all().iter() {
+        let payload = soroban_sdk::Vec::<Val>::try_from_val(env, &data).unwrap();
+        let seq = u64::try_from_val(env, &payload.last().unwrap()).unwrap();
+        seqs.push_back(seq);
+    }
+    seqs
+}
+
+#[test]
+fn test_sequence_increments_by_one_per_event() {
+    let (env, client, admin) = setup();
+    assert_eq!(stamped_seqs(&env), vec![&env, 1]);
+
+    client.pause(&admin);
+    assert_eq!(stamped_seqs(&env), vec![&env, 2]);
+
+    client.update_fees(&admin, &Some(80_000_000), &None);
+    assert_eq!(stamped_seqs(&env), vec![&env, 3]);
+
+    // A creation emits several events, numbered consecutively
+    client.unpause(&admin);
+    let params = token_params(&env, "SEQ");
+    client.batch_create_tokens(
+        &Address::generate(&env),
+        &vec![&env, params],
+        &80_000_000,
+        &None,
+    );
+
+    let seqs = stamped_seqs(&env);
+    assert!(seqs.len() > 1);
+    assert_eq!(seqs.get(0).unwrap(), 5);
+    for i in 1..seqs.len() {
+        assert_eq!(seqs.get(i).unwrap(), seqs.get(i - 1).unwrap() + 1);
+    }
+    assert_eq!(client.get_current_event_seq(), seqs.last().unwrap());
+}
+
+#[test]
+fn test_current_seq_counts_emitted_events() {
+    let (env, client, admin) = setup();
+    let mut emitted = env.events().all().len() as u64;
+
+    client.pause(&admin);
+    emitted += env.events().all().len() as u64;
+    assert_eq!(stamped_seqs(&env), vec![&env, 2]);
+
+    client.unpause(&admin);
+    emitted += env.events().all().len() as u64;
+    assert_eq!(stamped_seqs(&env), vec![&env, 3]);
+
+    // A redundant pause emits nothing and leaves the sequence alone
+    client.unpause(&admin);
+    emitted += env.events().all().len() as u64;
+
+    assert_eq!(client.get_current_event_seq(), emitted);
+    assert_eq!(client.get_current_event_seq(), 3);
+}
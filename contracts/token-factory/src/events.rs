@@ -37,6 +37,7 @@
 /// | burn          | burn_v1        | 7               | Fits within limit                   |
 /// | admin_burn    | adm_bn_v1      | 9               | Removed 'r' to fit limit            |
 /// | batch_burn    | bch_bn_v1      | 9               | Removed 'at' and 'r' to fit limit   |
+/// | fees_applied  | fee_ap_v1      | 9               | Shortened 'applied' to fit limit    |
 ///
 /// ## Schema Stability
 ///
@@ -46,8 +47,57 @@
 /// - Data types for all parameters must remain unchanged
 ///
 /// Any schema changes require creating a new version (e.g., init_v2).
+///
+/// ## Instance Topic
+///
+/// Every event carries the factory's instance id (set at initialization,
+/// `default` when none was given) as an extra, last topic. Indexers watching
+/// several factories can attribute events by that topic; the topics
+/// documented per event below come before it.
+///
+/// ## Payload Version
+///
+/// Every event's payload starts with a `u32` payload version
+/// (`EVENT_PAYLOAD_VERSION`, see `get_event_payload_version`), bumped
+/// whenever any payload shape changes. Indexers can pick a decoder by it;
+/// the payload fields documented per event below come after it.
+///
+/// ## Event Sequence
+///
+/// Every event's payload ends with a `u64` sequence number, one higher
+/// than the previous event's (see `get_current_event_seq`). Indexers can
+/// detect missed events from gaps in the sequence; the payload fields
+/// documented per event below come before it.
 
-use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
+use soroban_sdk::events::Topics;
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, IntoVal, String, TryFromVal, Val, Vec};
+
+/// Version of the payload layout, emitted as the first payload field
+///
+/// Bump whenever an event's payload fields change.
+pub const EVENT_PAYLOAD_VERSION: u32 = 1;
+
+/// Publish an event tagged with the factory instance id and sequence
+///
+/// Appends the instance id as the last topic, prepends the payload version
+/// and appends the next event sequence number to the payload. All contract
+/// events go through this helper.
+pub fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    let mut topics: Vec<Val> = topics.into_val(env);
+    topics.push_back(crate::storage::get_instance_id(env).into_val(env));
+
+    let data: Val = data.into_val(env);
+    let mut payload =
+        Vec::<Val>::try_from_val(env, &data).unwrap_or_else(|_| Vec::from_array(env, [data]));
+    payload.push_front(EVENT_PAYLOAD_VERSION.into_val(env));
+    payload.push_back(crate::storage::next_event_seq(env).into_val(env));
+
+    env.events().publish(topics, payload);
+}
 
 /// Emit initialized event (v1)
 ///
@@ -73,7 +123,8 @@ pub fn emit_initialized(
     base_fee: i128,
     metadata_fee: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("init_v1"),),
         (admin, treasury, base_fee, metadata_fee),
     );
@@ -95,7 +146,8 @@ pub fn emit_initialized(
 ///
 /// Emitted when a new token is created and registered
 pub fn emit_token_registered(env: &Env, token_address: &Address, creator: &Address) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("tok_rg_v1"), token_address.clone()),
         (creator,),
     );
@@ -126,7 +178,8 @@ pub fn emit_token_created(
     decimals: u32,
     initial_supply: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("tok_crt"), token_address.clone()),
         (
             creator.clone(),
@@ -138,10 +191,49 @@ pub fn emit_token_created(
     );
 }
 
+/// Emit lightweight token created ping
+///
+/// **Event Name**: tok_ping
+///
+/// **Topics** (indexed):
+/// - Event name: "tok_ping"
+///
+/// **Payload** (non-indexed):
+/// - token_address: Address - The newly created token's address
+/// - token_index: u32 - The token's registry index
+///
+/// Emitted right after `tok_crt` when the webhook topic is enabled, for
+/// listeners that only need to know a token exists
+pub fn emit_token_ping(env: &Env, token_address: &Address, token_index: u32) {
+    publish(
+        env,
+        (symbol_short!("tok_ping"),),
+        (token_address.clone(), token_index),
+    );
+}
+
+/// Emit token index reserved event
+///
+/// **Event Name**: idx_rsv
+///
+/// **Topics** (indexed):
+/// - Event name: "idx_rsv"
+/// - token_index: u32 - The reserved index
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator holding the reservation
+/// - expires_at: u32 - Ledger sequence at which the reservation lapses
+pub fn emit_index_reserved(env: &Env, token_index: u32, creator: &Address, expires_at: u32) {
+    publish(
+        env,
+        (symbol_short!("idx_rsv"), token_index),
+        (creator.clone(), expires_at),
+    );
+}
+
 /// Emitted when multiple tokens are created in a single batch.
 pub fn emit_batch_tokens_created(env: &Env, creator: &Address, count: u32) {
-    env.events()
-        .publish((symbol_short!("bch_tkn"),), (creator.clone(), count));
+    publish(env, (symbol_short!("bch_tkn"),), (creator.clone(), count));
 }
 
 /// Emit admin transfer event (v1)
@@ -161,8 +253,7 @@ pub fn emit_batch_tokens_created(env: &Env, creator: &Address, count: u32) {
 /// Reduces bytes from 121 to ~95 by removing redundant timestamp.
 /// The ledger automatically records transaction timestamps.
 pub fn emit_admin_transfer(env: &Env, old_admin: &Address, new_admin: &Address) {
-    env.events()
-        .publish((symbol_short!("adm_xf_v1"),), (old_admin, new_admin));
+    publish(env, (symbol_short!("adm_xf_v1"),), (old_admin, new_admin));
 }
 
 /// Emit admin proposed event (v1)
@@ -179,8 +270,7 @@ pub fn emit_admin_transfer(env: &Env, old_admin: &Address, new_admin: &Address)
 ///
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 pub fn emit_admin_proposed(env: &Env, current_admin: &Address, proposed_admin: &Address) {
-    env.events()
-        .publish((symbol_short!("adprp_v1"),), (current_admin, proposed_admin));
+    publish(env, (symbol_short!("adprp_v1"),), (current_admin, proposed_admin));
 }
 
 /// Emit pause event (v1)
@@ -196,7 +286,7 @@ pub fn emit_admin_proposed(env: &Env, current_admin: &Address, proposed_admin: &
 ///
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 pub fn emit_pause(env: &Env, admin: &Address) {
-    env.events().publish((symbol_short!("pause_v1"),), (admin,));
+    publish(env, (symbol_short!("pause_v1"),), (admin,));
 }
 
 /// Emit unpause event (v1)
@@ -212,8 +302,7 @@ pub fn emit_pause(env: &Env, admin: &Address) {
 ///
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 pub fn emit_unpause(env: &Env, admin: &Address) {
-    env.events()
-        .publish((symbol_short!("unpaus_v1"),), (admin,));
+    publish(env, (symbol_short!("unpaus_v1"),), (admin,));
 }
 
 /// Emit fees updated event (v1)
@@ -230,8 +319,41 @@ pub fn emit_unpause(env: &Env, admin: &Address) {
 ///
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 pub fn emit_fees_updated(env: &Env, base_fee: i128, metadata_fee: i128) {
-    env.events()
-        .publish((symbol_short!("fee_up_v1"),), (base_fee, metadata_fee));
+    publish(env, (symbol_short!("fee_up_v1"),), (base_fee, metadata_fee));
+}
+
+/// Emit fees applied event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: fee_ap_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "fee_ap_v1"
+/// - change_id: u64 - The timelocked change that was applied
+///
+/// **Payload** (non-indexed):
+/// - proposer: Address - The admin who scheduled the change
+/// - proposed_base_fee: Option<i128> - Base fee as proposed (None = unchanged)
+/// - proposed_metadata_fee: Option<i128> - Metadata fee as proposed
+/// - applied_base_fee: i128 - Base fee in effect after applying
+/// - applied_metadata_fee: i128 - Metadata fee in effect after applying
+///
+/// Emitted alongside `fee_up_v1` when a scheduled fee update executes, so
+/// observers can reconcile each proposal with what took effect.
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_fees_applied(
+    env: &Env,
+    change_id: u64,
+    proposer: &Address,
+    proposed: (Option<i128>, Option<i128>),
+    applied: (i128, i128),
+) {
+    publish(
+        env,
+        (symbol_short!("fee_ap_v1"), change_id),
+        (proposer.clone(), proposed.0, proposed.1, applied.0, applied.1),
+    );
 }
 
 /// Emit admin burn event (v1)
@@ -258,7 +380,8 @@ pub fn emit_admin_burn(
     from: &Address,
     amount: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("adm_br_v1"), token_address.clone()),
         (admin, from, amount),
     );
@@ -279,7 +402,8 @@ pub fn emit_admin_burn(
 ///
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 pub fn emit_clawback_toggled(env: &Env, token_address: &Address, admin: &Address, enabled: bool) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("clwbck_v1"), token_address.clone()),
         (admin, enabled),
     );
@@ -301,7 +425,8 @@ pub fn emit_clawback_toggled(env: &Env, token_address: &Address, admin: &Address
 ///
 /// Used when multiple tokens are burned in a batch operation
 pub fn emit_token_burned(env: &Env, token_address: &Address, amount: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("tok_br_v1"), token_address.clone()),
         (amount,),
     );
@@ -313,8 +438,7 @@ pub fn emit_token_burned(env: &Env, token_address: &Address, amount: i128) {
 ///
 /// Emitted when timelock is initialized or updated
 pub fn emit_timelock_configured(env: &Env, delay_seconds: u64) {
-    env.events()
-        .publish((symbol_short!("tl_cfg"),), (delay_seconds,));
+    publish(env, (symbol_short!("tl_cfg"),), (delay_seconds,));
 }
 
 /// Emit change scheduled event
@@ -326,7 +450,8 @@ pub fn emit_change_scheduled(
     change_type: crate::types::ChangeType,
     execute_at: u64,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("ch_sched"), change_id),
         (change_type.clone(), execute_at),
     );
@@ -336,7 +461,8 @@ pub fn emit_change_scheduled(
 ///
 /// Emitted when a pending change is successfully executed
 pub fn emit_change_executed(env: &Env, change_id: u64, change_type: crate::types::ChangeType) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("ch_exec"), change_id),
         (change_type.clone(),),
     );
@@ -346,7 +472,8 @@ pub fn emit_change_executed(env: &Env, change_id: u64, change_type: crate::types
 ///
 /// Emitted when a pending change is cancelled before execution
 pub fn emit_change_cancelled(env: &Env, change_id: u64, change_type: crate::types::ChangeType) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("ch_cncl"), change_id),
         (change_type.clone(),),
     );
@@ -356,16 +483,90 @@ pub fn emit_change_cancelled(env: &Env, change_id: u64, change_type: crate::type
 ///
 /// Emitted when treasury address is changed
 pub fn emit_treasury_updated(env: &Env, new_treasury: &Address) {
-    env.events()
-        .publish((symbol_short!("trs_upd"),), (new_treasury,));
+    publish(env, (symbol_short!("trs_upd"),), (new_treasury,));
+}
+
+/// Emit role updated event
+///
+/// **Event Name**: role_upd
+///
+/// **Topics** (indexed):
+/// - Event name: "role_upd"
+/// - account: Address - The account whose role changed
+///
+/// **Payload** (non-indexed):
+/// - role: Role - The role granted or revoked
+/// - granted: bool - `true` on grant, `false` on revoke
+pub fn emit_role_updated(env: &Env, account: &Address, role: crate::types::Role, granted: bool) {
+    publish(
+        env,
+        (symbol_short!("role_upd"), account.clone()),
+        (role, granted),
+    );
+}
+
+/// Emit admin action proposed event
+///
+/// **Event Name**: act_prop
+///
+/// **Topics** (indexed):
+/// - Event name: "act_prop"
+/// - id: u64 - The queued action request
+///
+/// **Payload** (non-indexed):
+/// - proposer: Address - The approver who proposed the action
+/// - action: AdminAction - The action awaiting approval
+pub fn emit_action_proposed(
+    env: &Env,
+    id: u64,
+    proposer: &Address,
+    action: &crate::types::AdminAction,
+) {
+    publish(
+        env,
+        (symbol_short!("act_prop"), id),
+        (proposer.clone(), action.clone()),
+    );
+}
+
+/// Emit admin action approved event
+///
+/// **Event Name**: act_appr
+///
+/// **Topics** (indexed):
+/// - Event name: "act_appr"
+/// - id: u64 - The queued action request
+///
+/// **Payload** (non-indexed):
+/// - approver: Address - The approver who signed off
+/// - approvals: u32 - Approvals gathered so far
+pub fn emit_action_approved(env: &Env, id: u64, approver: &Address, approvals: u32) {
+    publish(
+        env,
+        (symbol_short!("act_appr"), id),
+        (approver.clone(), approvals),
+    );
+}
+
+/// Emit admin action executed event
+///
+/// **Event Name**: act_exec
+///
+/// **Topics** (indexed):
+/// - Event name: "act_exec"
+/// - id: u64 - The queued action request
+///
+/// **Payload** (non-indexed):
+/// - approvals: u32 - Approvals the action executed with
+pub fn emit_action_executed(env: &Env, id: u64, approvals: u32) {
+    publish(env, (symbol_short!("act_exec"), id), (approvals,));
 }
 
 /// Emit mint event
 ///
 /// Emitted when tokens are minted
 pub fn emit_mint(env: &Env, token_index: u32, to: &Address, amount: i128) {
-    env.events()
-        .publish((symbol_short!("mint"), token_index), (to, amount));
+    publish(env, (symbol_short!("mint"), token_index), (to, amount));
 }
 
 // ── Treasury events ─────────────────────────────────────────
@@ -374,39 +575,43 @@ pub fn emit_mint(env: &Env, token_index: u32, to: &Address, amount: i128) {
 ///
 /// Emitted when fees are withdrawn from treasury
 pub fn emit_treasury_withdrawal(env: &Env, recipient: &Address, amount: i128) {
-    env.events()
-        .publish((symbol_short!("trs_wdrw"),), (recipient, amount));
+    publish(env, (symbol_short!("trs_wdrw"),), (recipient, amount));
+}
+
+/// Emit funds rescued event
+///
+/// Emitted when tokens outside the fee pools are recovered
+pub fn emit_funds_rescued(env: &Env, asset: &Address, to: &Address, amount: i128) {
+    publish(env, (symbol_short!("rescued"), asset.clone()), (to, amount));
 }
 
 /// Emit recipient added event
 ///
 /// Emitted when an address is added to the withdrawal allowlist
 pub fn emit_recipient_added(env: &Env, recipient: &Address) {
-    env.events()
-        .publish((symbol_short!("rec_add"),), (recipient,));
+    publish(env, (symbol_short!("rec_add"),), (recipient,));
 }
 
 /// Emit recipient removed event
 ///
 /// Emitted when an address is removed from the withdrawal allowlist
 pub fn emit_recipient_removed(env: &Env, recipient: &Address) {
-    env.events()
-        .publish((symbol_short!("rec_rem"),), (recipient,));
+    publish(env, (symbol_short!("rec_rem"),), (recipient,));
 }
 
 /// Emit treasury policy updated event
 ///
 /// Emitted when treasury withdrawal policy is changed
 pub fn emit_treasury_policy_updated(env: &Env, daily_cap: i128, allowlist_enabled: bool) {
-    env.events()
-        .publish((symbol_short!("trs_pol"),), (daily_cap, allowlist_enabled));
+    publish(env, (symbol_short!("trs_pol"),), (daily_cap, allowlist_enabled));
 }
 
 /// Emit governance configured event
 ///
 /// Emitted when governance parameters are initialized
 pub fn emit_governance_configured(env: &Env, quorum_percent: u32, approval_percent: u32) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("gov_cfg"),),
         (quorum_percent, approval_percent),
     );
@@ -416,7 +621,8 @@ pub fn emit_governance_configured(env: &Env, quorum_percent: u32, approval_perce
 ///
 /// Emitted when governance parameters are changed
 pub fn emit_governance_updated(env: &Env, quorum_percent: u32, approval_percent: u32) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("gov_upd"),),
         (quorum_percent, approval_percent),
     );
@@ -441,18 +647,40 @@ pub fn emit_metadata_set(
     admin: &Address,
     metadata_uri: &String,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("meta_set"), token_address.clone()),
         (admin.clone(), metadata_uri.clone()),
     );
 }
 
+/// Emit metadata updated event
+///
+/// **Event Name**: meta_upd
+///
+/// **Topics** (indexed):
+/// - Event name: "meta_upd"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - updater: Address - The account that changed the metadata
+/// - version: u32 - The token's metadata version after the change
+///
+/// Emitted on every metadata mutation (URI or attribute) so indexers can
+/// invalidate cached metadata
+pub fn emit_metadata_updated(env: &Env, token_address: &Address, updater: &Address, version: u32) {
+    publish(
+        env,
+        (symbol_short!("meta_upd"), token_address.clone()),
+        (updater.clone(), version),
+    );
+}
+
 /// Emit batch streams created event
 ///
 /// Published when multiple streams are created in a batch
 pub fn emit_batch_streams_created(env: &Env, creator: &Address, count: u32) {
-    env.events()
-        .publish((symbol_short!("bch_strm"),), (creator, count));
+    publish(env, (symbol_short!("bch_strm"),), (creator, count));
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -485,7 +713,8 @@ pub fn emit_stream_created(
     amount: i128,
     has_metadata: bool,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cr_v1"), stream_id),
         (creator, recipient, amount, has_metadata),
     );
@@ -513,7 +742,8 @@ pub fn emit_stream_funded(
     funder: &Address,
     amount: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_fd_v1"), stream_id),
         (funder, amount),
     );
@@ -541,7 +771,8 @@ pub fn emit_stream_claimed(
     recipient: &Address,
     amount: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cl_v1"), stream_id),
         (recipient, amount),
     );
@@ -569,7 +800,8 @@ pub fn emit_stream_cancelled(
     canceller: &Address,
     remaining_amount: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cn_v1"), stream_id),
         (canceller, remaining_amount),
     );
@@ -597,7 +829,8 @@ pub fn emit_stream_metadata_updated(
     updater: &Address,
     has_metadata: bool,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_md_v1"), stream_id),
         (updater, has_metadata),
     );
@@ -619,7 +852,8 @@ pub fn emit_proposal_created(
     end_time: u64,
     eta: u64,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_cr"), proposal_id),
         (proposer, action_type.clone(), start_time, end_time, eta),
     );
@@ -634,7 +868,8 @@ pub fn emit_proposal_voted(
     voter: &Address,
     support: crate::types::VoteChoice,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_vote"), proposal_id),
         (voter, support),
     );
@@ -648,7 +883,8 @@ pub fn emit_proposal_queued(
     proposal_id: u64,
     eta: u64,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_que"), proposal_id),
         (eta,),
     );
@@ -663,7 +899,8 @@ pub fn emit_proposal_executed(
     executor: &Address,
     success: bool,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_exec"), proposal_id),
         (executor, success),
     );
@@ -682,7 +919,8 @@ pub fn emit_vault_created(
     unlock_time: u64,
     milestone_hash: &soroban_sdk::BytesN<32>,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_crt"), vault_id),
         (
             creator.clone(),
@@ -699,7 +937,8 @@ pub fn emit_vault_created(
 ///
 /// Published when a vault is successfully claimed.
 pub fn emit_vault_claimed(env: &Env, vault_id: u64, owner: &Address, amount: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_clm"), vault_id),
         (owner.clone(), amount),
     );
@@ -709,7 +948,8 @@ pub fn emit_vault_claimed(env: &Env, vault_id: u64, owner: &Address, amount: i12
 ///
 /// Published when a vault is cancelled.
 pub fn emit_vault_cancelled(env: &Env, vault_id: u64, actor: &Address, remaining_amount: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cnl"), vault_id),
         (actor.clone(), remaining_amount),
     );
@@ -736,7 +976,8 @@ pub fn emit_campaign_created(
     token_index: u32,
     budget_allocated: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_crt"), campaign_id),
         (owner, token_index, budget_allocated),
     );
@@ -758,7 +999,8 @@ pub fn emit_campaign_created(
 ///
 /// Emitted when a campaign is paused
 pub fn emit_campaign_paused(env: &Env, campaign_id: u64, paused_by: &Address) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_ps_v1"), campaign_id),
         (paused_by,),
     );
@@ -780,7 +1022,8 @@ pub fn emit_campaign_paused(env: &Env, campaign_id: u64, paused_by: &Address) {
 ///
 /// Emitted when a campaign is resumed from paused state
 pub fn emit_campaign_resumed(env: &Env, campaign_id: u64, resumed_by: &Address) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_rs_v1"), campaign_id),
         (resumed_by,),
     );
@@ -800,7 +1043,8 @@ pub fn emit_campaign_resumed(env: &Env, campaign_id: u64, resumed_by: &Address)
 ///
 /// Emitted when a campaign completes successfully
 pub fn emit_campaign_completed(env: &Env, campaign_id: u64, tokens_burned: i128, budget_spent: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_cmp"), campaign_id),
         (tokens_burned, budget_spent),
     );
@@ -825,8 +1069,612 @@ pub fn emit_campaign_cancelled(
     cancelled_by: &Address,
     budget_remaining: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_cnl"), campaign_id),
         (cancelled_by, budget_remaining),
     );
 }
+
+/// Emit SAC wrapper deployed event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: sac_wr_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "sac_wr_v1"
+/// - token_address: Address - The factory token that was wrapped
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The token creator who requested the wrapper
+/// - wrapper: Address - The deployed Stellar Asset Contract address
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_sac_wrapped(env: &Env, token_address: &Address, creator: &Address, wrapper: &Address) {
+    publish(
+        env,
+        (symbol_short!("sac_wr_v1"), token_address.clone()),
+        (creator.clone(), wrapper.clone()),
+    );
+}
+
+/// Emit token wasm hash updated event
+///
+/// **Event Name**: wasm_upd
+///
+/// **Topics** (indexed):
+/// - Event name: "wasm_upd"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who changed the hash
+/// - hash: BytesN<32> - Wasm hash recorded for future deployments
+///
+/// Emitted when the admin changes the token wasm used for new deployments
+pub fn emit_token_wasm_hash_updated(env: &Env, admin: &Address, hash: &BytesN<32>) {
+    publish(env, (symbol_short!("wasm_upd"),), (admin.clone(), hash.clone()));
+}
+
+/// Emit factory upgraded event
+///
+/// **Event Name**: upgraded
+///
+/// **Topics** (indexed):
+/// - Event name: "upgraded"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who ran the upgrade
+/// - wasm_hash: BytesN<32> - Hash of the new factory wasm
+/// - version: String - Version label recorded for the upgrade
+///
+/// Emitted when the admin replaces the factory's own wasm
+pub fn emit_upgraded(env: &Env, admin: &Address, wasm_hash: &BytesN<32>, version: &String) {
+    publish(
+        env,
+        (symbol_short!("upgraded"),),
+        (admin.clone(), wasm_hash.clone(), version.clone()),
+    );
+}
+
+/// Emit factory rolled back event
+///
+/// **Event Name**: rollback
+///
+/// **Topics** (indexed):
+/// - Event name: "rollback"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who ran the rollback
+/// - wasm_hash: BytesN<32> - Hash of the restored factory wasm
+/// - version: String - Version label restored
+///
+/// Emitted when the admin reverts the factory to the wasm it ran before
+/// the last upgrade
+pub fn emit_rolled_back(env: &Env, admin: &Address, wasm_hash: &BytesN<32>, version: &String) {
+    publish(
+        env,
+        (symbol_short!("rollback"),),
+        (admin.clone(), wasm_hash.clone(), version.clone()),
+    );
+}
+
+/// Emit creation escrow opened event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: esc_op_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "esc_op_v1"
+/// - escrow_id: u64 - The escrow identifier
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who opened the escrow
+/// - fee: i128 - Fee held in escrow
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_escrow_opened(env: &Env, escrow_id: u64, creator: &Address, fee: i128) {
+    publish(
+        env,
+        (symbol_short!("esc_op_v1"), escrow_id),
+        (creator.clone(), fee),
+    );
+}
+
+/// Emit creation escrow finalized event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: esc_fn_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "esc_fn_v1"
+/// - escrow_id: u64 - The escrow identifier
+///
+/// **Payload** (non-indexed):
+/// - token_address: Address - The token created from the escrow
+/// - fee: i128 - Fee forwarded to the treasury
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_escrow_finalized(env: &Env, escrow_id: u64, token_address: &Address, fee: i128) {
+    publish(
+        env,
+        (symbol_short!("esc_fn_v1"), escrow_id),
+        (token_address.clone(), fee),
+    );
+}
+
+/// Emit creation escrow cancelled event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: esc_cn_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "esc_cn_v1"
+/// - escrow_id: u64 - The escrow identifier
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who received the refund
+/// - fee: i128 - Fee refunded
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_escrow_cancelled(env: &Env, escrow_id: u64, creator: &Address, fee: i128) {
+    publish(
+        env,
+        (symbol_short!("esc_cn_v1"), escrow_id),
+        (creator.clone(), fee),
+    );
+}
+
+/// Emit fee token updated event
+///
+/// **Event Name**: fee_tkn
+///
+/// **Topics** (indexed):
+/// - Event name: "fee_tkn"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who changed the fee asset
+/// - fee_token: Address - Token contract of the new fee asset
+///
+/// Emitted when the admin changes the asset fees are paid in
+pub fn emit_fee_token_updated(env: &Env, admin: &Address, fee_token: &Address) {
+    publish(env, (symbol_short!("fee_tkn"),), (admin.clone(), fee_token.clone()));
+}
+
+/// Emit token attribute set event
+///
+/// **Event Name**: attr_set
+///
+/// **Topics** (indexed):
+/// - Event name: "attr_set"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - key: String - Attribute key
+/// - value: String - New attribute value
+///
+/// Emitted when a creator sets a structured metadata attribute
+pub fn emit_token_attribute_set(env: &Env, token_address: &Address, key: &String, value: &String) {
+    publish(
+        env,
+        (symbol_short!("attr_set"), token_address.clone()),
+        (key.clone(), value.clone()),
+    );
+}
+
+/// Emit waiver created event
+///
+/// **Event Name**: wvr_new
+///
+/// **Topics** (indexed):
+/// - Event name: "wvr_new"
+/// - code_hash: BytesN<32> - sha256 of the promo code
+///
+/// **Payload** (non-indexed):
+/// - uses: u32 - Number of times the waiver can be redeemed
+///
+/// Emitted when the admin creates or resets a fee waiver
+pub fn emit_waiver_created(env: &Env, code_hash: &BytesN<32>, uses: u32) {
+    publish(env, (symbol_short!("wvr_new"), code_hash.clone()), (uses,));
+}
+
+/// Emit waiver redeemed event
+///
+/// **Event Name**: wvr_used
+///
+/// **Topics** (indexed):
+/// - Event name: "wvr_used"
+/// - code_hash: BytesN<32> - sha256 of the promo code
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - Creator whose fee was waived
+/// - remaining: u32 - Uses left after this redemption
+///
+/// Emitted when a token creation redeems a fee waiver
+pub fn emit_waiver_redeemed(env: &Env, code_hash: &BytesN<32>, creator: &Address, remaining: u32) {
+    publish(
+        env,
+        (symbol_short!("wvr_used"), code_hash.clone()),
+        (creator.clone(), remaining),
+    );
+}
+
+/// Emit signed waiver redeemed event
+///
+/// **Event Name**: swvr_used
+///
+/// **Topics** (indexed):
+/// - Event name: "swvr_used"
+/// - nonce: u64 - Nonce of the signed waiver
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - Creator credited with a free creation
+pub fn emit_signed_waiver_redeemed(env: &Env, nonce: u64, creator: &Address) {
+    publish(env, (symbol_short!("swvr_used"), nonce), (creator.clone(),));
+}
+
+/// Emit minting finalized event
+///
+/// **Event Name**: mnt_final
+///
+/// **Topics** (indexed):
+/// - Event name: "mnt_final"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who ended minting
+/// - total_supply: i128 - Final supply of the token
+///
+/// Emitted when a creator permanently ends minting of a token
+pub fn emit_minting_finalized(
+    env: &Env,
+    token_address: &Address,
+    creator: &Address,
+    total_supply: i128,
+) {
+    publish(
+        env,
+        (symbol_short!("mnt_final"), token_address.clone()),
+        (creator.clone(), total_supply),
+    );
+}
+
+/// Emit vesting created event
+///
+/// **Event Name**: vst_new
+///
+/// **Topics** (indexed):
+/// - Event name: "vst_new"
+/// - token_address: Address - The vested token
+/// - beneficiary: Address - Who the tokens vest to
+///
+/// **Payload** (non-indexed):
+/// - total: i128 - Amount locked in the schedule
+/// - start: u64 - Vesting start timestamp
+/// - cliff: u64 - Seconds after start before anything can be claimed
+/// - duration: u64 - Seconds after start until fully vested
+///
+/// Emitted when a creator locks tokens in a vesting schedule
+pub fn emit_vesting_created(
+    env: &Env,
+    token_address: &Address,
+    beneficiary: &Address,
+    terms: &crate::types::VestingTerms,
+) {
+    publish(
+        env,
+        (symbol_short!("vst_new"), token_address.clone(), beneficiary.clone()),
+        (terms.total, terms.start, terms.cliff, terms.duration),
+    );
+}
+
+/// Emit vesting claimed event
+///
+/// **Event Name**: vst_claim
+///
+/// **Topics** (indexed):
+/// - Event name: "vst_claim"
+/// - token_address: Address - The vested token
+/// - beneficiary: Address - Who claimed
+///
+/// **Payload** (non-indexed):
+/// - amount: i128 - Amount released by this claim
+/// - claimed: i128 - Total claimed from the schedule so far
+///
+/// Emitted when a beneficiary claims vested tokens
+pub fn emit_vesting_claimed(
+    env: &Env,
+    token_address: &Address,
+    beneficiary: &Address,
+    amount: i128,
+    claimed: i128,
+) {
+    publish(
+        env,
+        (symbol_short!("vst_claim"), token_address.clone(), beneficiary.clone()),
+        (amount, claimed),
+    );
+}
+
+/// Emit pause expiry event
+///
+/// **Event Name**: pause_exp
+///
+/// **Topics** (indexed):
+/// - Event name: "pause_exp"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who paused the contract
+/// - until_ledger: u32 - Ledger sequence at which the pause ends
+///
+/// Emitted when the contract is paused with a timeout
+pub fn emit_pause_expiry(env: &Env, admin: &Address, until_ledger: u32) {
+    publish(
+        env,
+        (symbol_short!("pause_exp"),),
+        (admin.clone(), until_ledger),
+    );
+}
+
+/// Emit batch transfer event
+///
+/// **Event Name**: batch_xfr
+///
+/// **Topics** (indexed):
+/// - Event name: "batch_xfr"
+/// - token_address: Address - The token address
+/// - from: Address - Sender
+///
+/// **Payload** (non-indexed):
+/// - count: u32 - Number of recipients paid
+/// - total: i128 - Total amount transferred
+///
+/// Emitted once per successful `batch_transfer`
+pub fn emit_batch_transfer(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    count: u32,
+    total: i128,
+) {
+    publish(
+        env,
+        (
+            symbol_short!("batch_xfr"),
+            token_address.clone(),
+            from.clone(),
+        ),
+        (count, total),
+    );
+}
+
+/// Emit transfer with memo event
+///
+/// **Event Name**: xfer_memo
+///
+/// **Topics** (indexed):
+/// - Event name: "xfer_memo"
+/// - token_address: Address - The token address
+/// - from: Address - Sender
+/// - to: Address - Recipient
+///
+/// **Payload** (non-indexed):
+/// - amount: i128 - Amount transferred
+/// - memo: Bytes - Caller-supplied reference (not stored)
+///
+/// Emitted when tokens are transferred with `transfer_with_memo`
+pub fn emit_transfer_with_memo(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: &Bytes,
+) {
+    publish(
+        env,
+        (
+            symbol_short!("xfer_memo"),
+            token_address.clone(),
+            from.clone(),
+            to.clone(),
+        ),
+        (amount, memo.clone()),
+    );
+}
+
+/// Emit emergency shutdown event
+///
+/// **Event Name**: shutdown
+///
+/// **Topics** (indexed):
+/// - Event name: "shutdown"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who disabled creation
+///
+/// Emitted when token creation is permanently disabled
+pub fn emit_emergency_shutdown(env: &Env, admin: &Address) {
+    publish(env, (symbol_short!("shutdown"),), (admin.clone(),));
+}
+
+/// Emit token archived event
+///
+/// **Event Name**: tkn_arch
+///
+/// **Topics** (indexed):
+/// - Event name: "tkn_arch"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who archived the token
+/// - index: u32 - The token's (unchanged) index
+///
+/// Emitted when a creator archives a token
+pub fn emit_token_archived(env: &Env, token_address: &Address, creator: &Address, index: u32) {
+    publish(
+        env,
+        (symbol_short!("tkn_arch"), token_address.clone()),
+        (creator.clone(), index),
+    );
+}
+
+/// Emit token closed event
+///
+/// **Event Name**: tkn_close
+///
+/// **Topics** (indexed):
+/// - Event name: "tkn_close"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who closed the token
+/// - index: u32 - The token's (retired) index
+///
+/// Emitted when the admin closes a drained token and frees its storage
+pub fn emit_token_closed(env: &Env, token_address: &Address, admin: &Address, index: u32) {
+    publish(
+        env,
+        (symbol_short!("tkn_close"), token_address.clone()),
+        (admin.clone(), index),
+    );
+}
+
+/// Emit token events muted event
+///
+/// **Event Name**: evt_mute
+///
+/// **Topics** (indexed):
+/// - Event name: "evt_mute"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who changed the setting
+/// - muted: bool - Whether routine events are now suppressed
+///
+/// Emitted even for muted tokens, so the switch itself is always visible
+pub fn emit_token_events_muted(env: &Env, token_address: &Address, creator: &Address, muted: bool) {
+    publish(
+        env,
+        (symbol_short!("evt_mute"), token_address.clone()),
+        (creator.clone(), muted),
+    );
+}
+
+/// Emit operation fee charged event
+///
+/// **Event Name**: op_fee
+///
+/// **Topics** (indexed):
+/// - Event name: "op_fee"
+/// - payer: Address - The caller charged for the operation
+///
+/// **Payload** (non-indexed):
+/// - kind: OpKind - The operation that carried the fee
+/// - amount: i128 - Fee collected
+pub fn emit_op_fee_charged(env: &Env, kind: crate::types::OpKind, payer: &Address, amount: i128) {
+    publish(
+        env,
+        (symbol_short!("op_fee"), payer.clone()),
+        (kind, amount),
+    );
+}
+
+/// Emit token gate updated event
+///
+/// **Event Name**: tok_gate
+///
+/// **Topics** (indexed):
+/// - Event name: "tok_gate"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who changed the gate
+/// - gate_token: Option<Address> - Token recipients must now hold, if any
+pub fn emit_token_gate_updated(
+    env: &Env,
+    token_address: &Address,
+    creator: &Address,
+    gate_token: &Option<Address>,
+) {
+    publish(
+        env,
+        (symbol_short!("tok_gate"), token_address.clone()),
+        (creator.clone(), gate_token.clone()),
+    );
+}
+
+/// Emit metadata frozen event
+///
+/// **Event Name**: meta_frz
+///
+/// **Topics** (indexed):
+/// - Event name: "meta_frz"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who froze the metadata
+///
+/// Emitted when a creator permanently freezes a token's metadata
+pub fn emit_metadata_frozen(env: &Env, token_address: &Address, creator: &Address) {
+    publish(
+        env,
+        (symbol_short!("meta_frz"), token_address.clone()),
+        (creator.clone(),),
+    );
+}
+
+/// Emit creator renounced event
+///
+/// **Event Name**: renounced
+///
+/// **Topics** (indexed):
+/// - Event name: "renounced"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who gave up their powers
+///
+/// Emitted when a creator permanently gives up mint, clawback and metadata
+/// control of a token
+pub fn emit_creator_renounced(env: &Env, token_address: &Address, creator: &Address) {
+    publish(
+        env,
+        (symbol_short!("renounced"), token_address.clone()),
+        (creator.clone(),),
+    );
+}
+
+/// Emit token locked event
+///
+/// **Event Name**: tok_lock
+///
+/// **Topics** (indexed):
+/// - Event name: "tok_lock"
+/// - token_address: Address - The token address
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who locked the token
+///
+/// Emitted when the admin permanently locks a token
+pub fn emit_token_locked(env: &Env, token_address: &Address, admin: &Address) {
+    publish(
+        env,
+        (symbol_short!("tok_lock"), token_address.clone()),
+        (admin.clone(),),
+    );
+}
+
+/// Emit fee burn share updated event
+///
+/// **Event Name**: fee_bps
+///
+/// **Topics** (indexed):
+/// - Event name: "fee_bps"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who changed the share
+/// - bps: u32 - Share of each fee that is burned, in basis points
+///
+/// Emitted when the admin changes the fee burn share
+pub fn emit_fee_burn_bps_updated(env: &Env, admin: &Address, bps: u32) {
+    publish(env, (symbol_short!("fee_bps"),), (admin.clone(), bps));
+}
@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, Bytes, Env, String, TryFromVal};
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    holder: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let params = token_params(&env, "QUIET");
+    let creator = Address::generate(&env);
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+    let holder = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &holder, 1_000)
+    });
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        creator,
+        holder,
+        token,
+    }
+}
+
+/// Run every routine operation, returning the number of events each emitted
+fn routine_event_counts(s: &Setup) -> [u32; 4] {
+    let recipient = Address::generate(&s.env);
+    let memo = Bytes::from_slice(&s.env, b"quiet");
+
+    s.client
+        .transfer_with_memo(&s.token, &s.holder, &recipient, &100, &memo);
+    let transfer = s.env.events().all().len();
+
+    s.client.batch_transfer(
+        &s.token,
+        &s.holder,
+        &vec![&s.env, (recipient.clone(), 50_i128)],
+    );
+    let batch = s.env.events().all().len();
+
+    s.client.burn(&s.holder, &0, &10);
+    let burn = s.env.events().all().len();
+
+    let spender = Address::generate(&s.env);
+    s.client.approve(&s.token, &s.holder, &spender, &20);
+    s.client.burn_from(&s.token, &spender, &s.holder, &20);
+    let burn_from = s.env.events().all().len();
+
+    [transfer, batch, burn, burn_from]
+}
+
+#[test]
+fn test_unmuted_token_emits_routine_events() {
+    let s = setup();
+
+    assert_eq!(routine_event_counts(&s), [1, 1, 1, 1]);
+}
+
+#[test]
+fn test_muted_token_suppresses_routine_events() {
+    let s = setup();
+    s.client.set_token_events_muted(&s.token, &s.creator, &true);
+
+    assert_eq!(routine_event_counts(&s), [0, 0, 0, 0]);
+
+    // Balances still moved
+    let remaining = s.env.as_contract(&s.contract_id, || {
+        storage::get_balance(&s.env, 0, &s.holder)
+    });
+    assert_eq!(remaining, 1_000 - 100 - 50 - 10 - 20);
+}
+
+#[test]
+fn test_toggle_itself_is_always_emitted() {
+    let s = setup();
+
+    s.client.set_token_events_muted(&s.token, &s.creator, &true);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    let name = Symbol::try_from_val(&s.env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(name, symbol_short!("evt_mute"));
+    let (_version, creator, muted, _seq) =
+        <(u32, Address, bool, u64)>::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(creator, s.creator);
+    assert!(muted);
+    assert!(s.client.get_token_info(&0).events_muted);
+
+    s.client
+        .set_token_events_muted(&s.token, &s.creator, &false);
+    assert_eq!(routine_event_counts(&s), [1, 1, 1, 1]);
+}
+
+#[test]
+fn test_only_creator_can_mute() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_set_token_events_muted(&s.token, &Address::generate(&s.env), &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_set_token_events_muted(&Address::generate(&s.env), &s.creator, &true),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert!(!s.client.get_token_info(&0).events_muted);
+}
+
+#[test]
+fn test_muting_keeps_burn_accounting() {
+    let s = setup();
+    s.client.burn(&s.creator, &0, &400_000);
+
+    s.client.set_token_events_muted(&s.token, &s.creator, &true);
+
+    let info = s.client.get_token_info(&0);
+    assert!(info.events_muted);
+    assert_eq!(info.total_supply, 600_000);
+    assert_eq!(info.total_burned, 400_000);
+    assert_eq!(info.burn_count, 1);
+}
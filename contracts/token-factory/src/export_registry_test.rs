@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+const SYMBOLS: [&str; 5] = ["AAA", "BBB", "CCC", "DDD", "EEE"];
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Vec<Address>, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let mut tokens = Vec::new(&env);
+    for (i, symbol) in SYMBOLS.iter().enumerate() {
+        let spec = TokenCreationParams {
+            decimals: i as u32,
+            initial_supply: 1_000 * (i as i128 + 1),
+            ..token_params(&env, symbol)
+        };
+        tokens.push_back(client.create_token(
+            &creator,
+            &spec,
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        ));
+    }
+
+    (env, client, tokens, creator)
+}
+
+#[test]
+fn test_export_pages_in_index_order() {
+    let (env, client, tokens, creator) = setup();
+
+    let mut exported = Vec::new(&env);
+    let mut start = 0;
+    loop {
+        let page = client.export_registry(&start, &2);
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 2);
+        start += page.len();
+        exported.append(&page);
+    }
+
+    assert_eq!(exported.len(), SYMBOLS.len() as u32);
+    for (i, summary) in exported.iter().enumerate() {
+        assert_eq!(summary.address, tokens.get(i as u32).unwrap());
+        assert_eq!(summary.creator, creator);
+        assert_eq!(summary.symbol, String::from_str(&env, SYMBOLS[i]));
+        assert_eq!(summary.decimals, i as u32);
+        assert_eq!(summary.total_supply, 1_000 * (i as i128 + 1));
+    }
+}
+
+#[test]
+fn test_export_reflects_current_supply() {
+    let (env, client, _tokens, creator) = setup();
+
+    client.mint(&creator, &1, &Address::generate(&env), &500);
+
+    let page = client.export_registry(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().total_supply, 2_500);
+}
+
+#[test]
+fn test_export_includes_archived_tokens() {
+    let (_env, client, tokens, creator) = setup();
+
+    client.archive_token(&tokens.get(0).unwrap(), &creator);
+
+    let page = client.export_registry(&0, &100);
+    assert_eq!(page.len(), SYMBOLS.len() as u32);
+    assert_eq!(page.get(0).unwrap().address, tokens.get(0).unwrap());
+}
+
+#[test]
+fn test_export_past_end_is_empty() {
+    let (_env, client, _tokens, _creator) = setup();
+
+    assert_eq!(client.export_registry(&4, &10).len(), 1);
+    assert!(client.export_registry(&5, &10).is_empty());
+    assert!(client.export_registry(&u32::MAX, &10).is_empty());
+}
@@ -0,0 +1,164 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, String};
+
+const STARTING_BALANCE: i128 = 1_000_000_000;
+const STRAY: i128 = 5_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    contract_id: Address,
+    admin: Address,
+    creator: Address,
+    fee_token: TokenClient<'static>,
+    fee_minter: StellarAssetClient<'static>,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+    client.initialize_treasury_policy(&admin, &Some(STARTING_BALANCE), &false);
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let creator = Address::generate(&env);
+    let fee_minter = StellarAssetClient::new(&env, &fee_asset.address());
+    fee_minter.mint(&creator, &STARTING_BALANCE);
+
+    Setup {
+        fee_token: TokenClient::new(&env, &fee_asset.address()),
+        fee_minter,
+        env,
+        client,
+        contract_id,
+        admin,
+        creator,
+    }
+}
+
+fn create_token(s: &Setup) {
+    let params = token_params(&s.env, "FEE");
+    s.client
+        .batch_create_tokens(&s.creator, &vec![&s.env, params], &BASE_FEE, &None);
+}
+
+#[test]
+fn test_fee_balance_excludes_stray_tokens() {
+    let s = setup();
+    create_token(&s);
+
+    // Unrelated transfer straight to the contract
+    s.fee_minter.mint(&s.contract_id, &STRAY);
+
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), BASE_FEE);
+    assert_eq!(s.fee_token.balance(&s.contract_id), BASE_FEE + STRAY);
+}
+
+#[test]
+fn test_rescue_recovers_only_strays() {
+    let s = setup();
+    create_token(&s);
+    s.fee_minter.mint(&s.contract_id, &STRAY);
+    let rescuer = Address::generate(&s.env);
+
+    let result = s
+        .client
+        .try_rescue_funds(&s.admin, &s.fee_token.address, &rescuer, &(STRAY + 1));
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+
+    s.client
+        .rescue_funds(&s.admin, &s.fee_token.address, &rescuer, &STRAY);
+
+    assert_eq!(s.fee_token.balance(&rescuer), STRAY);
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), BASE_FEE);
+    assert_eq!(s.fee_token.balance(&s.contract_id), BASE_FEE);
+}
+
+#[test]
+fn test_rescue_leaves_pending_escrows() {
+    let s = setup();
+    let spec = token_params(&s.env, "ESC");
+    s.client.begin_create(&s.creator, &spec, &BASE_FEE, &None);
+
+    let result = s
+        .client
+        .try_rescue_funds(&s.admin, &s.fee_token.address, &s.admin, &1);
+
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+}
+
+#[test]
+fn test_withdraw_fees_draws_from_fee_balance() {
+    let s = setup();
+    create_token(&s);
+    s.fee_minter.mint(&s.contract_id, &STRAY);
+    let recipient = Address::generate(&s.env);
+
+    s.client.withdraw_fees(&s.admin, &recipient, &BASE_FEE);
+
+    assert_eq!(s.fee_token.balance(&recipient), BASE_FEE);
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), 0);
+
+    // Stray tokens are not withdrawable as fees
+    let result = s.client.try_withdraw_fees(&s.admin, &recipient, &1);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+}
+
+#[test]
+fn test_rescue_requires_admin() {
+    let s = setup();
+    s.fee_minter.mint(&s.contract_id, &STRAY);
+
+    let result = s
+        .client
+        .try_rescue_funds(&s.creator, &s.fee_token.address, &s.creator, &STRAY);
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_contract_balance_matches_collected_fees() {
+    let s = setup();
+    create_token(&s);
+    create_token(&s);
+
+    assert_eq!(
+        s.client.get_contract_balance(&s.fee_token.address),
+        2 * BASE_FEE
+    );
+    assert_eq!(
+        s.client.get_contract_balance(&s.fee_token.address),
+        s.client.get_fee_balance(&s.fee_token.address)
+    );
+
+    let recipient = Address::generate(&s.env);
+    s.client.withdraw_fees(&s.admin, &recipient, &BASE_FEE);
+
+    assert_eq!(
+        s.client.get_contract_balance(&s.fee_token.address),
+        BASE_FEE
+    );
+}
+
+#[test]
+fn test_contract_balance_includes_strays() {
+    let s = setup();
+    create_token(&s);
+    s.fee_minter.mint(&s.contract_id, &STRAY);
+
+    assert_eq!(
+        s.client.get_contract_balance(&s.fee_token.address),
+        s.client.get_fee_balance(&s.fee_token.address) + STRAY
+    );
+}
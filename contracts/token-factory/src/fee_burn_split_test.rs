@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, String};
+
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    treasury: Address,
+    creator: Address,
+    fee_token: TokenClient<'static>,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        client,
+        admin,
+        treasury,
+        ..
+    } = FactoryEnv::new();
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset.address()).mint(&creator, &STARTING_BALANCE);
+
+    Setup {
+        fee_token: TokenClient::new(&env, &fee_asset.address()),
+        env,
+        client,
+        admin,
+        treasury,
+        creator,
+    }
+}
+
+fn create_token(s: &Setup) {
+    let params = token_params(&s.env, "SPLT");
+    s.client
+        .batch_create_tokens(&s.creator, &vec![&s.env, params], &BASE_FEE, &None);
+}
+
+#[test]
+fn test_fee_split_between_treasury_and_burn() {
+    let s = setup();
+    s.client.set_fee_burn_bps(&s.admin, &2000);
+
+    create_token(&s);
+
+    assert_eq!(s.fee_token.balance(&s.creator), STARTING_BALANCE - BASE_FEE);
+    assert_eq!(
+        s.client.get_fee_balance(&s.fee_token.address),
+        BASE_FEE * 80 / 100
+    );
+
+    let totals = s.client.get_fee_totals();
+    assert_eq!(totals.to_treasury, 56_000_000);
+    assert_eq!(totals.burned, 14_000_000);
+}
+
+#[test]
+fn test_treasury_share_reaches_treasury_on_withdraw() {
+    let s = setup();
+    s.client.set_fee_burn_bps(&s.admin, &2000);
+    create_token(&s);
+
+    // Collection only credits the fee balance
+    assert_eq!(s.fee_token.balance(&s.treasury), 0);
+
+    s.client.withdraw_fees(&s.admin, &s.treasury, &56_000_000);
+
+    assert_eq!(s.fee_token.balance(&s.treasury), 56_000_000);
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), 0);
+    assert_eq!(s.fee_token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_default_sends_whole_fee_to_treasury() {
+    let s = setup();
+
+    create_token(&s);
+
+    assert_eq!(s.client.get_fee_burn_bps(), 0);
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), BASE_FEE);
+    assert_eq!(s.client.get_fee_totals().burned, 0);
+}
+
+#[test]
+fn test_full_burn_share() {
+    let s = setup();
+    s.client.set_fee_burn_bps(&s.admin, &10_000);
+
+    create_token(&s);
+
+    assert_eq!(s.client.get_fee_balance(&s.fee_token.address), 0);
+    assert_eq!(s.client.get_fee_totals().burned, BASE_FEE);
+}
+
+#[test]
+fn test_bps_above_denominator_rejected() {
+    let s = setup();
+
+    let result = s.client.try_set_fee_burn_bps(&s.admin, &10_001);
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    assert_eq!(s.client.get_fee_burn_bps(), 0);
+}
+
+#[test]
+fn test_set_fee_burn_bps_requires_admin() {
+    let s = setup();
+
+    let result = s.client.try_set_fee_burn_bps(&s.creator, &2000);
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE, METADATA_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, String};
+
+const STARTING_BALANCE: i128 = 1_000_000_000;
+const OVERPAY: i128 = 12_345;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    contract_id: Address,
+    admin: Address,
+    creator: Address,
+    fee_token: TokenClient<'static>,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset.address()).mint(&creator, &STARTING_BALANCE);
+
+    Setup {
+        fee_token: TokenClient::new(&env, &fee_asset.address()),
+        env,
+        client,
+        contract_id,
+        admin,
+        creator,
+    }
+}
+
+fn params(env: &Env, symbol: &str, with_metadata: bool) -> TokenCreationParams {
+    TokenCreationParams {
+        metadata_uri: with_metadata.then(|| String::from_str(env, "ipfs://QmCollect")),
+        ..token_params(env, symbol)
+    }
+}
+
+fn paid(s: &Setup) -> i128 {
+    STARTING_BALANCE - s.fee_token.balance(&s.creator)
+}
+
+#[test]
+fn test_overpay_collects_only_required_fee() {
+    let s = setup();
+
+    let collected = s.env.as_contract(&s.contract_id, || {
+        fees::validate_and_collect(
+            &s.env,
+            &s.creator,
+            BASE_FEE,
+            METADATA_FEE,
+            true,
+            BASE_FEE + METADATA_FEE + OVERPAY,
+        )
+    });
+
+    assert_eq!(collected, Ok(BASE_FEE + METADATA_FEE));
+    assert_eq!(paid(&s), BASE_FEE + METADATA_FEE);
+}
+
+#[test]
+fn test_metadata_fee_only_when_requested() {
+    let s = setup();
+
+    let collected = s.env.as_contract(&s.contract_id, || {
+        fees::validate_and_collect(&s.env, &s.creator, BASE_FEE, METADATA_FEE, false, BASE_FEE)
+    });
+
+    assert_eq!(collected, Ok(BASE_FEE));
+    assert_eq!(paid(&s), BASE_FEE);
+}
+
+#[test]
+fn test_insufficient_and_negative_fees_rejected() {
+    let s = setup();
+
+    s.env.as_contract(&s.contract_id, || {
+        assert_eq!(
+            fees::validate_and_collect(
+                &s.env,
+                &s.creator,
+                BASE_FEE,
+                METADATA_FEE,
+                true,
+                BASE_FEE + METADATA_FEE - 1,
+            ),
+            Err(Error::InsufficientFee)
+        );
+        assert_eq!(
+            fees::validate_and_collect(&s.env, &s.creator, -1, 0, false, BASE_FEE),
+            Err(Error::InvalidParameters)
+        );
+    });
+    assert_eq!(paid(&s), 0);
+}
+
+#[test]
+fn test_create_token_overpay_matches_direct_collect() {
+    let s = setup();
+
+    s.client.create_token(
+        &s.creator,
+        &params(&s.env, "ONE", true),
+        &(BASE_FEE + METADATA_FEE + OVERPAY),
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert_eq!(paid(&s), BASE_FEE + METADATA_FEE);
+}
+
+#[test]
+fn test_batch_create_overpay_matches_direct_collect() {
+    let s = setup();
+    let tokens = vec![
+        &s.env,
+        params(&s.env, "ONE", true),
+        params(&s.env, "TWO", false),
+    ];
+
+    s.client.batch_create_tokens(
+        &s.creator,
+        &tokens,
+        &(2 * BASE_FEE + METADATA_FEE + OVERPAY),
+        &None,
+    );
+
+    assert_eq!(paid(&s), 2 * BASE_FEE + METADATA_FEE);
+}
+
+#[test]
+fn test_insufficient_fee_identical_across_call_sites() {
+    let s = setup();
+
+    let single = s.client.try_create_token(
+        &s.creator,
+        &params(&s.env, "ONE", true),
+        &(BASE_FEE + METADATA_FEE - 1),
+        &TokenKind::Fungible,
+        &None,
+    );
+    let batch = s.client.try_batch_create_tokens(
+        &s.creator,
+        &vec![
+            &s.env,
+            params(&s.env, "ONE", false),
+            params(&s.env, "TWO", true),
+        ],
+        &(2 * BASE_FEE + METADATA_FEE - 1),
+        &None,
+    );
+
+    assert_eq!(single, Err(Ok(Error::InsufficientFee)));
+    assert_eq!(batch, Err(Ok(Error::InsufficientFee)));
+    assert_eq!(paid(&s), 0);
+}
+
+#[test]
+fn test_negative_fees_rejected_by_every_setter() {
+    let s = setup();
+
+    let fresh = TokenFactoryClient::new(&s.env, &s.env.register_contract(None, TokenFactory));
+    let treasury = Address::generate(&s.env);
+    assert_eq!(
+        fresh.try_initialize(&s.admin, &treasury, &-1, &METADATA_FEE),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.try_update_fees(&s.admin, &None, &Some(-1)),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_batch_update_admin(&s.admin, &Some(-1), &None, &None, &None, &None),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(s.client.get_base_fee(), BASE_FEE);
+    assert_eq!(s.client.get_metadata_fee(), METADATA_FEE);
+}
@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+#[test]
+fn test_history_lists_changes_newest_first() {
+    let (env, client, admin) = setup();
+    assert_eq!(client.get_fee_history(&10).len(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.update_fees(&admin, &Some(80_000_000), &None);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.batch_update_admin(&admin, &None, &Some(20_000_000), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.update_fees(&admin, &Some(90_000_000), &Some(25_000_000));
+
+    let history = client.get_fee_history(&10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history.get(0).unwrap(),
+        types::FeeRecord {
+            base_fee: 90_000_000,
+            metadata_fee: 25_000_000,
+            timestamp: 300,
+        }
+    );
+    assert_eq!(
+        history.get(1).unwrap(),
+        types::FeeRecord {
+            base_fee: 80_000_000,
+            metadata_fee: 20_000_000,
+            timestamp: 200,
+        }
+    );
+    assert_eq!(
+        history.get(2).unwrap(),
+        types::FeeRecord {
+            base_fee: 80_000_000,
+            metadata_fee: 30_000_000,
+            timestamp: 100,
+        }
+    );
+
+    let newest = client.get_fee_history(&1);
+    assert_eq!(newest.len(), 1);
+    assert_eq!(newest.get(0).unwrap().base_fee, 90_000_000);
+}
+
+#[test]
+fn test_history_drops_oldest_beyond_cap() {
+    let (_env, client, admin) = setup();
+
+    let changes = fees::MAX_FEE_HISTORY as i128 + 5;
+    for fee in 1..=changes {
+        client.update_fees(&admin, &Some(fee), &None);
+    }
+
+    let history = client.get_fee_history(&u32::MAX);
+    assert_eq!(history.len(), fees::MAX_FEE_HISTORY);
+    assert_eq!(history.get(0).unwrap().base_fee, changes);
+    assert_eq!(
+        history.get(fees::MAX_FEE_HISTORY - 1).unwrap().base_fee,
+        changes - fees::MAX_FEE_HISTORY as i128 + 1
+    );
+}
+
+#[test]
+fn test_non_fee_admin_updates_are_not_recorded() {
+    let (_env, client, admin) = setup();
+
+    client.batch_update_admin(&admin, &None, &None, &Some(true), &None, &None);
+
+    assert_eq!(client.get_fee_history(&10).len(), 0);
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client, admin)
+}
+
+#[test]
+fn test_quote_scales_to_token_decimals() {
+    let (env, _contract_id, client, _admin) = setup();
+    let creator = Address::generate(&env);
+
+    // 7 XLM
+    assert_eq!(client.quote_fee_in_token(&creator, &7), BASE_FEE);
+    assert_eq!(client.quote_fee_in_token(&creator, &0), 7);
+    assert_eq!(client.quote_fee_in_token(&creator, &2), 700);
+    assert_eq!(client.quote_fee_in_token(&creator, &9), BASE_FEE * 100);
+    assert_eq!(
+        client.quote_fee_in_token(&creator, &18),
+        7_000_000_000_000_000_000
+    );
+}
+
+#[test]
+fn test_quote_truncates_fractions() {
+    let (env, _contract_id, client, admin) = setup();
+    client.update_fees(&admin, &Some(12_345_678), &None);
+    let creator = Address::generate(&env);
+
+    assert_eq!(client.quote_fee_in_token(&creator, &0), 1);
+    assert_eq!(client.quote_fee_in_token(&creator, &3), 1_234);
+}
+
+#[test]
+fn test_quote_for_exempt_creator_is_zero() {
+    let (env, _contract_id, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.set_fee_exempt(&admin, &creator, &true);
+
+    assert_eq!(client.quote_fee_in_token(&creator, &18), 0);
+}
+
+#[test]
+fn test_quote_rejects_invalid_decimals() {
+    let (env, _contract_id, client, _admin) = setup();
+
+    assert_eq!(
+        client.try_quote_fee_in_token(&Address::generate(&env), &19),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_quote_overflow_is_reported() {
+    let (env, contract_id, client, _admin) = setup();
+    env.as_contract(&contract_id, || {
+        storage::set_base_fee(&env, i128::MAX / 1_000)
+    });
+    let creator = Address::generate(&env);
+
+    assert_eq!(
+        client.try_quote_fee_in_token(&creator, &18),
+        Err(Ok(Error::ArithmeticError))
+    );
+    assert_eq!(
+        client.quote_fee_in_token(&creator, &10),
+        i128::MAX / 1_000 * 1_000
+    );
+    assert_eq!(
+        client.quote_fee_in_token(&creator, &0),
+        i128::MAX / 1_000 / 10_000_000
+    );
+}
+
+#[test]
+fn test_scale_to_decimals_extremes() {
+    assert_eq!(fees::scale_to_decimals(i128::MAX, 7), Ok(i128::MAX));
+    assert_eq!(
+        fees::scale_to_decimals(i128::MAX, 8),
+        Err(Error::ArithmeticError)
+    );
+    assert_eq!(
+        fees::scale_to_decimals(i128::MIN, 0),
+        Ok(i128::MIN / 10_000_000)
+    );
+    assert_eq!(fees::scale_to_decimals(1, 0), Ok(0));
+    assert_eq!(fees::scale_to_decimals(0, 18), Ok(0));
+}
@@ -0,0 +1,228 @@
+#![cfg(test)]
+//! Differential test of creation fees against an independent reference
+//!
+//! The reference below recomputes, from first principles, what a creator
+//! pays for a token and how that fee is split between treasury and burn.
+//! Each case builds a fresh factory from a random fee configuration and
+//! creator state, then checks the contract charges exactly the reference
+//! fee (one stroop less is rejected) and books exactly the reference split.
+//!
+//! The factory's fee rules today are the base fee, the metadata fee,
+//! waiver codes and the basis-point burn split. Discount tiers or verified
+//! creator rates are not implemented; when a fee rule is added it must be
+//! added to both `reference_fee` and the generated state.
+
+extern crate std;
+
+use super::*;
+use crate::test_helpers::token_params;
+use proptest::prelude::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+use types::RoundingMode;
+
+const WAIVER_CODE: &[u8] = b"REFERENCE";
+
+/// Factory-wide fee settings
+#[derive(Clone, Debug)]
+struct FeeConfig {
+    base_fee: i128,
+    metadata_fee: i128,
+    burn_bps: u32,
+    rounding: RoundingMode,
+}
+
+/// State of the creator at the time of the measured creation
+#[derive(Clone, Debug)]
+struct CreatorState {
+    /// Tokens the creator made before, each paying the full fee
+    prior_tokens: u32,
+    /// Remaining uses of the waiver code (0 = no waiver exists)
+    waiver_uses: u32,
+    /// Whether the creator presents the waiver code
+    presents_waiver: bool,
+    has_metadata: bool,
+}
+
+/// Reference creation fee
+fn reference_fee(config: &FeeConfig, state: &CreatorState) -> i128 {
+    if state.presents_waiver && state.waiver_uses > 0 {
+        return 0;
+    }
+    let metadata_fee = if state.has_metadata {
+        config.metadata_fee
+    } else {
+        0
+    };
+    config.base_fee + metadata_fee
+}
+
+/// Reference `(to_treasury, burned)` split of a collected fee
+fn reference_split(config: &FeeConfig, fee: i128) -> (i128, i128) {
+    let scaled = fee * config.burn_bps as i128;
+    let denominator = fees::BPS_DENOMINATOR as i128;
+    let burned = match config.rounding {
+        RoundingMode::Floor => scaled / denominator,
+        RoundingMode::Ceil => (scaled + denominator - 1) / denominator,
+        RoundingMode::Nearest => (2 * scaled + denominator) / (2 * denominator),
+    };
+    (fee - burned, burned)
+}
+
+/// What the contract charged and booked for the measured creation
+#[derive(Debug, PartialEq)]
+struct Observed {
+    /// Whether paying one stroop below the reference fee was rejected
+    underpayment_rejected: bool,
+    to_treasury: i128,
+    burned: i128,
+    waiver_uses_left: u32,
+}
+
+fn spec(env: &Env, has_metadata: bool) -> TokenCreationParams {
+    TokenCreationParams {
+        metadata_uri: has_metadata.then(|| String::from_str(env, "ipfs://QmReference")),
+        ..token_params(env, "REF")
+    }
+}
+
+fn run_contract(config: &FeeConfig, state: &CreatorState, fee: i128) -> Observed {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &config.base_fee,
+        &config.metadata_fee,
+    );
+    client.set_fee_burn_bps(&admin, &config.burn_bps);
+    client.set_rounding_mode(&admin, &config.rounding);
+
+    let code = Bytes::from_slice(&env, WAIVER_CODE);
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    if state.waiver_uses > 0 {
+        client.create_waiver(&admin, &code_hash, &state.waiver_uses);
+    }
+
+    let creator = Address::generate(&env);
+    let full_fee = config.base_fee + config.metadata_fee;
+    for _ in 0..state.prior_tokens {
+        client.create_token(
+            &creator,
+            &spec(&env, true),
+            &full_fee,
+            &TokenKind::Fungible,
+            &None,
+        );
+    }
+
+    let spec = spec(&env, state.has_metadata);
+    let waiver = state.presents_waiver.then_some(code);
+    let underpayment_rejected = fee == 0
+        || client.try_create_token(&creator, &spec, &(fee - 1), &TokenKind::Fungible, &waiver)
+            == Err(Ok(Error::InsufficientFee));
+
+    let before = client.get_fee_totals();
+    client.create_token(&creator, &spec, &fee, &TokenKind::Fungible, &waiver);
+    let after = client.get_fee_totals();
+
+    Observed {
+        underpayment_rejected,
+        to_treasury: after.to_treasury - before.to_treasury,
+        burned: after.burned - before.burned,
+        waiver_uses_left: client.get_waiver_uses(&code_hash),
+    }
+}
+
+fn config_strategy() -> impl Strategy<Value = FeeConfig> {
+    (
+        0i128..1_000_000_000,
+        0i128..1_000_000_000,
+        0u32..=10_000,
+        prop_oneof![
+            Just(RoundingMode::Floor),
+            Just(RoundingMode::Ceil),
+            Just(RoundingMode::Nearest),
+        ],
+    )
+        .prop_map(|(base_fee, metadata_fee, burn_bps, rounding)| FeeConfig {
+            base_fee,
+            metadata_fee,
+            burn_bps,
+            rounding,
+        })
+}
+
+fn state_strategy() -> impl Strategy<Value = CreatorState> {
+    (0u32..3, 0u32..3, any::<bool>(), any::<bool>()).prop_map(
+        |(prior_tokens, waiver_uses, presents_waiver, has_metadata)| CreatorState {
+            prior_tokens,
+            waiver_uses,
+            presents_waiver,
+            has_metadata,
+        },
+    )
+}
+
+#[test]
+fn test_creation_fee_matches_reference() {
+    fuzz_support::run(
+        fuzz_support::config(64),
+        (config_strategy(), state_strategy()),
+        |(config, state)| {
+            let fee = reference_fee(&config, &state);
+            let (to_treasury, burned) = reference_split(&config, fee);
+            let redeemed = state.presents_waiver && state.waiver_uses > 0;
+            let expected = Observed {
+                underpayment_rejected: true,
+                to_treasury,
+                burned,
+                waiver_uses_left: state.waiver_uses - redeemed as u32,
+            };
+
+            let observed = run_contract(&config, &state, fee);
+            prop_assert_eq!(
+                &observed,
+                &expected,
+                "reference fee {} for {:?} / {:?}: contract {:?}, reference {:?}",
+                fee,
+                config,
+                state,
+                observed,
+                expected
+            );
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_reference_waiver_zeroes_fee() {
+    let config = FeeConfig {
+        base_fee: 70_000_000,
+        metadata_fee: 30_000_000,
+        burn_bps: 2_500,
+        rounding: RoundingMode::Floor,
+    };
+    let state = CreatorState {
+        prior_tokens: 1,
+        waiver_uses: 1,
+        presents_waiver: true,
+        has_metadata: true,
+    };
+
+    assert_eq!(reference_fee(&config, &state), 0);
+    assert_eq!(
+        run_contract(&config, &state, 0),
+        Observed {
+            underpayment_rejected: true,
+            to_treasury: 0,
+            burned: 0,
+            waiver_uses_left: 0,
+        }
+    );
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, String};
+
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    fee_token: TokenClient<'static>,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset.address()).mint(&creator, &STARTING_BALANCE);
+
+    Setup {
+        fee_token: TokenClient::new(&env, &fee_asset.address()),
+        env,
+        client,
+        admin,
+        creator,
+    }
+}
+
+fn code(env: &Env, code: &str) -> Bytes {
+    Bytes::from_slice(env, code.as_bytes())
+}
+
+fn code_hash(env: &Env, raw: &str) -> BytesN<32> {
+    env.crypto().sha256(&code(env, raw)).into()
+}
+
+fn spec(s: &Setup) -> TokenCreationParams {
+    token_params(&s.env, "PRMO")
+}
+
+fn create(s: &Setup, waiver_code: Option<Bytes>) {
+    s.client.create_token(
+        &s.creator,
+        &spec(s),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &waiver_code,
+    );
+}
+
+#[test]
+fn test_waiver_uses_are_consumed_then_full_fee_applies() {
+    let s = setup();
+    s.client
+        .create_waiver(&s.admin, &code_hash(&s.env, "LAUNCH"), &2);
+
+    create(&s, Some(code(&s.env, "LAUNCH")));
+    create(&s, Some(code(&s.env, "LAUNCH")));
+
+    assert_eq!(s.fee_token.balance(&s.creator), STARTING_BALANCE);
+    assert_eq!(s.client.get_waiver_uses(&code_hash(&s.env, "LAUNCH")), 0);
+
+    create(&s, Some(code(&s.env, "LAUNCH")));
+
+    assert_eq!(s.fee_token.balance(&s.creator), STARTING_BALANCE - BASE_FEE);
+}
+
+#[test]
+fn test_invalid_code_is_ignored() {
+    let s = setup();
+    s.client
+        .create_waiver(&s.admin, &code_hash(&s.env, "LAUNCH"), &2);
+
+    create(&s, Some(code(&s.env, "WRONG")));
+
+    assert_eq!(s.fee_token.balance(&s.creator), STARTING_BALANCE - BASE_FEE);
+    assert_eq!(s.client.get_waiver_uses(&code_hash(&s.env, "LAUNCH")), 2);
+}
+
+#[test]
+fn test_batch_redeems_one_use_per_token() {
+    let s = setup();
+    s.client
+        .create_waiver(&s.admin, &code_hash(&s.env, "LAUNCH"), &2);
+
+    let tokens = vec![&s.env, spec(&s), spec(&s), spec(&s)];
+    s.client.batch_create_tokens(
+        &s.creator,
+        &tokens,
+        &BASE_FEE,
+        &Some(code(&s.env, "LAUNCH")),
+    );
+
+    // Two tokens were waived, the third paid
+    assert_eq!(s.fee_token.balance(&s.creator), STARTING_BALANCE - BASE_FEE);
+    assert_eq!(s.client.get_waiver_uses(&code_hash(&s.env, "LAUNCH")), 0);
+}
+
+#[test]
+fn test_escrow_redeems_waiver() {
+    let s = setup();
+    s.client
+        .create_waiver(&s.admin, &code_hash(&s.env, "LAUNCH"), &1);
+
+    let escrow_id = s
+        .client
+        .begin_create(&s.creator, &spec(&s), &0, &Some(code(&s.env, "LAUNCH")));
+    s.client.finalize_create(&s.creator, &escrow_id);
+
+    assert_eq!(s.fee_token.balance(&s.creator), STARTING_BALANCE);
+    assert_eq!(s.client.get_waiver_uses(&code_hash(&s.env, "LAUNCH")), 0);
+    assert_eq!(s.client.get_token_count(&true), 1);
+}
+
+#[test]
+fn test_create_waiver_requires_admin_and_uses() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_create_waiver(&s.creator, &code_hash(&s.env, "LAUNCH"), &2),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_create_waiver(&s.admin, &code_hash(&s.env, "LAUNCH"), &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
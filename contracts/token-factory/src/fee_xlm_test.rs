@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin, &treasury, &100_000_000, &50_000_000);
+
+    (env, client, admin)
+}
+
+#[test]
+fn test_update_fees_xlm_stores_stroops() {
+    let (_env, client, admin) = setup();
+
+    client.update_fees_xlm(&admin, &7, &3);
+
+    assert_eq!(client.get_base_fee(), 70_000_000);
+    assert_eq!(client.get_metadata_fee(), 30_000_000);
+    assert_eq!(client.get_fees_xlm(), (7, 3));
+}
+
+#[test]
+fn test_get_fees_xlm_truncates() {
+    let (_env, client, admin) = setup();
+
+    client.update_fees(&admin, &Some(79_999_999), &Some(9_999_999));
+
+    assert_eq!(client.get_fees_xlm(), (7, 0));
+}
+
+#[test]
+fn test_update_fees_xlm_rejects_overflow() {
+    let (_env, client, admin) = setup();
+    let too_large = i128::MAX / fees::STROOPS_PER_XLM + 1;
+
+    assert_eq!(
+        client.try_update_fees_xlm(&admin, &too_large, &3),
+        Err(Ok(Error::ArithmeticError))
+    );
+    assert_eq!(
+        client.try_update_fees_xlm(&admin, &7, &too_large),
+        Err(Ok(Error::ArithmeticError))
+    );
+    assert_eq!(client.get_fees_xlm(), (10, 5));
+}
+
+#[test]
+fn test_update_fees_xlm_requires_admin() {
+    let (env, client, _admin) = setup();
+
+    assert_eq!(
+        client.try_update_fees_xlm(&Address::generate(&env), &7, &3),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_update_fees_xlm_rejects_negative() {
+    let (_env, client, admin) = setup();
+
+    assert_eq!(
+        client.try_update_fees_xlm(&admin, &-1, &3),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
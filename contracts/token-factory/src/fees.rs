@@ -0,0 +1,440 @@
+//! Fee asset movements
+//!
+//! All transfers of the fee asset go through this module. When no fee
+//! asset has been configured the factory only checks fee amounts and moves
+//! nothing, which keeps deployments without a fee asset working as before.
+//!
+//! Collected fees can be split: `FeeBurnBps` basis points of every fee
+//! are burned (of the fee asset) and the remainder is kept by the factory
+//! for the treasury: it accrues in `FeeBalance` and is only transferred
+//! when withdrawn. The burned share is rounded with the configured
+//! `RoundingMode` (`Floor` by default); the treasury gets the rest, so no
+//! fee is ever created or lost by rounding.
+//!
+//! The factory keeps separate pools per asset so stray tokens sent to it
+//! are never mistaken for fees:
+//! - `FeeBalance` - collected fees, paid out by `withdraw`
+//! - `EscrowedFees` - fees held for pending creation escrows
+//!
+//! Anything the factory holds above those pools can be recovered with
+//! `rescue`.
+//!
+//! Creation fees can be waived with promo codes. Only the sha256 hash of a
+//! code is stored, together with how many more times it can be redeemed.
+//! The admin can also sign single-use waivers off-chain; redeeming one
+//! burns its nonce and credits the creator one free creation.
+//! The admin's own deployments (unless turned off) and accounts granted the
+//! fee exemption role never pay the creation fee.
+//!
+//! Larger tokens can be charged more: supply fee tiers add the extra fee of
+//! the highest tier whose threshold the initial supply exceeds.
+//!
+//! Ongoing operations (mints, transfers, metadata edits) can carry an
+//! operation fee per `OpKind`, paid by the caller. All are zero by default.
+//!
+//! Every fee change is recorded in a bounded fee history, readable newest
+//! first with `get_fee_history`.
+
+use crate::storage;
+use crate::types::{Error, FeeRecord, OpKind, RoundingMode, SignedWaiver};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{token, Address, Bytes, BytesN, Env, Vec};
+
+/// Basis point denominator for the fee burn share
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Stroops in one XLM
+pub const STROOPS_PER_XLM: i128 = 10_000_000;
+
+/// Convert whole XLM to stroops
+pub fn xlm_to_stroops(xlm: i128) -> Result<i128, Error> {
+    xlm.checked_mul(STROOPS_PER_XLM)
+        .ok_or(Error::ArithmeticError)
+}
+
+/// Convert stroops to whole XLM, truncating any fraction
+pub fn stroops_to_xlm(stroops: i128) -> i128 {
+    stroops / STROOPS_PER_XLM
+}
+
+/// Decimal places of fee amounts (stroops)
+pub const FEE_DECIMALS: u32 = 7;
+
+/// Express a stroop amount with `decimals` decimal places, 1:1 in value
+///
+/// Fewer decimals truncate the fraction. This only rescales units; it
+/// says nothing about what a token is worth.
+pub fn scale_to_decimals(stroops: i128, decimals: u32) -> Result<i128, Error> {
+    if decimals > crate::token_creation::MAX_DECIMALS {
+        return Err(Error::InvalidParameters);
+    }
+    if decimals >= FEE_DECIMALS {
+        let factor = 10i128.pow(decimals - FEE_DECIMALS);
+        stroops.checked_mul(factor).ok_or(Error::ArithmeticError)
+    } else {
+        Ok(stroops / 10i128.pow(FEE_DECIMALS - decimals))
+    }
+}
+
+/// Maximum number of supply fee tiers
+pub const MAX_SUPPLY_FEE_TIERS: u32 = 10;
+
+/// Check supply fee tiers: thresholds strictly ascending and nothing negative
+pub fn validate_supply_fee_tiers(tiers: &Vec<(i128, i128)>) -> Result<(), Error> {
+    if tiers.len() > MAX_SUPPLY_FEE_TIERS {
+        return Err(Error::InvalidParameters);
+    }
+    let mut previous: Option<i128> = None;
+    for (threshold, extra_fee) in tiers.iter() {
+        if threshold < 0 || extra_fee < 0 || previous.is_some_and(|p| threshold <= p) {
+            return Err(Error::InvalidParameters);
+        }
+        previous = Some(threshold);
+    }
+    Ok(())
+}
+
+/// Extra creation fee for a token with `supply` initial supply
+///
+/// Tiers are stored in ascending threshold order, so the last tier the
+/// supply exceeds is the one that applies. No tier means no surcharge.
+pub fn supply_surcharge(env: &Env, supply: i128) -> i128 {
+    let mut surcharge = 0;
+    for (threshold, extra_fee) in storage::get_supply_fee_tiers(env).iter() {
+        if supply <= threshold {
+            break;
+        }
+        surcharge = extra_fee;
+    }
+    surcharge
+}
+
+/// Whether `creator` deploys without paying the creation fee
+pub fn is_fee_exempt(env: &Env, creator: &Address) -> bool {
+    if storage::is_admin_fee_exempt(env) && *creator == storage::get_admin(env) {
+        return true;
+    }
+    storage::is_fee_exempt(env, creator)
+}
+
+/// Check deployment fee amounts: none may be negative
+///
+/// Shared by every entry point that sets fees, so they reject the same
+/// inputs. `None` means the fee is left unchanged and is not checked.
+pub fn validate_fee_amounts(base: Option<i128>, metadata: Option<i128>) -> Result<(), Error> {
+    if base.is_some_and(|fee| fee < 0) || metadata.is_some_and(|fee| fee < 0) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Number of fee changes kept by the fee history
+pub const MAX_FEE_HISTORY: u32 = 20;
+
+/// Record the fees now in effect in the fee history
+///
+/// The history is a ring buffer of `MAX_FEE_HISTORY` slots: once full,
+/// each new record overwrites the oldest.
+pub fn record_fee_change(env: &Env) {
+    let count = storage::get_fee_history_count(env);
+    let slot = (count % MAX_FEE_HISTORY as u64) as u32;
+    storage::set_fee_record(
+        env,
+        slot,
+        &FeeRecord {
+            base_fee: storage::get_base_fee(env),
+            metadata_fee: storage::get_metadata_fee(env),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    storage::set_fee_history_count(env, count + 1);
+}
+
+/// Get up to `limit` recorded fee changes, newest first
+pub fn get_fee_history(env: &Env, limit: u32) -> Vec<FeeRecord> {
+    let count = storage::get_fee_history_count(env);
+    let kept = count.min(MAX_FEE_HISTORY as u64);
+    let limit = (limit.min(MAX_FEE_HISTORY) as u64).min(kept);
+
+    let mut records = Vec::new(env);
+    for back in 1..=limit {
+        let slot = ((count - back) % MAX_FEE_HISTORY as u64) as u32;
+        if let Some(record) = storage::get_fee_record(env, slot) {
+            records.push_back(record);
+        }
+    }
+    records
+}
+
+/// Check a fee payment and collect the fee it covers
+///
+/// The fee is `base`, plus `metadata` when `with_metadata` is set. Only
+/// the fee itself is taken from `payer`: the excess of an overpayment is
+/// never moved, so it stays with the payer. Every path that charges a
+/// creation fee goes through here.
+///
+/// # Returns
+/// The amount collected
+///
+/// # Errors
+/// * `InvalidParameters` - A fee component is negative
+/// * `ArithmeticError` - The fee overflows
+/// * `InsufficientFee` - `payment` is below the fee
+pub fn validate_and_collect(
+    env: &Env,
+    payer: &Address,
+    base: i128,
+    metadata: i128,
+    with_metadata: bool,
+    payment: i128,
+) -> Result<i128, Error> {
+    validate_fee_amounts(Some(base), Some(metadata))?;
+    let required = if with_metadata {
+        base.checked_add(metadata).ok_or(Error::ArithmeticError)?
+    } else {
+        base
+    };
+    if payment < required {
+        return Err(Error::InsufficientFee);
+    }
+
+    collect(env, payer, required)?;
+    Ok(required)
+}
+
+/// Collect a fee from `payer`, splitting it between treasury and burn
+pub fn collect(env: &Env, payer: &Address, amount: i128) -> Result<(), Error> {
+    distribute(env, payer, amount)
+}
+
+/// Charge `payer` the operation fee configured for `kind`, if any
+///
+/// The fee is collected like a creation fee: split between treasury and
+/// burn. Operations without a fee (the default) move nothing.
+pub fn charge_op_fee(env: &Env, payer: &Address, kind: OpKind) -> Result<(), Error> {
+    let fee = storage::get_op_fee(env, kind);
+    if fee == 0 {
+        return Ok(());
+    }
+    collect(env, payer, fee)?;
+    crate::events::emit_op_fee_charged(env, kind, payer, fee);
+    Ok(())
+}
+
+/// Move a fee from `payer` into the factory's custody
+pub fn hold(env: &Env, payer: &Address, amount: i128) -> Result<(), Error> {
+    transfer(env, payer, &env.current_contract_address(), amount)?;
+    adjust_escrowed(env, amount)
+}
+
+/// Forward a fee held by the factory, splitting it between treasury and burn
+pub fn release(env: &Env, amount: i128) -> Result<(), Error> {
+    adjust_escrowed(env, -amount)?;
+    distribute(env, &env.current_contract_address(), amount)
+}
+
+/// Return a fee held by the factory to `recipient`
+pub fn refund(env: &Env, recipient: &Address, amount: i128) -> Result<(), Error> {
+    adjust_escrowed(env, -amount)?;
+    transfer(env, &env.current_contract_address(), recipient, amount)
+}
+
+/// Pay collected fees out of the fee balance to `recipient`
+pub fn withdraw(env: &Env, recipient: &Address, amount: i128) -> Result<(), Error> {
+    let Some(fee_token) = storage::get_fee_token(env) else {
+        return Ok(());
+    };
+
+    let balance = storage::get_fee_balance(env, &fee_token);
+    if amount > balance {
+        return Err(Error::InsufficientBalance);
+    }
+    storage::set_fee_balance(env, &fee_token, balance - amount);
+
+    transfer(env, &env.current_contract_address(), recipient, amount)
+}
+
+/// Recover `asset` held by the factory outside its fee pools
+///
+/// Only the amount above the collected fee balance and pending escrows
+/// can be moved.
+pub fn rescue(env: &Env, asset: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if *to == env.current_contract_address() {
+        return Err(Error::InvalidParameters);
+    }
+
+    let client = token::Client::new(env, asset);
+    let held = client.balance(&env.current_contract_address());
+    let reserved = storage::get_fee_balance(env, asset)
+        .checked_add(storage::get_escrowed_fees(env, asset))
+        .ok_or(Error::ArithmeticError)?;
+    if amount > held.saturating_sub(reserved) {
+        return Err(Error::InsufficientBalance);
+    }
+
+    client.transfer(&env.current_contract_address(), to, &amount);
+    Ok(())
+}
+
+/// Redeem one use of the fee waiver matching `code`
+///
+/// Returns `true` if `code` hashes to a waiver with uses left, consuming
+/// one use. Unknown and used-up codes are ignored and return `false`.
+pub fn redeem_waiver(env: &Env, creator: &Address, code: &Bytes) -> bool {
+    let code_hash: BytesN<32> = env.crypto().sha256(code).into();
+    let uses = storage::get_waiver_uses(env, &code_hash);
+    if uses == 0 {
+        return false;
+    }
+
+    storage::set_waiver_uses(env, &code_hash, uses - 1);
+    crate::events::emit_waiver_redeemed(env, &code_hash, creator, uses - 1);
+    true
+}
+
+/// Bytes a waiver signer signs for `creator`
+///
+/// XDR of the factory address and the creator, then the big-endian nonce
+/// and expiry, so a waiver is bound to one factory and one creator.
+pub fn waiver_message(env: &Env, creator: &Address, nonce: u64, expires_at: u64) -> Bytes {
+    let mut message = env.current_contract_address().to_xdr(env);
+    message.append(&creator.clone().to_xdr(env));
+    message.extend_from_array(&nonce.to_be_bytes());
+    message.extend_from_array(&expires_at.to_be_bytes());
+    message
+}
+
+/// Redeem a signed waiver, crediting `creator` one free creation
+///
+/// An invalid signature aborts the invocation.
+pub fn redeem_signed_waiver(
+    env: &Env,
+    creator: &Address,
+    waiver: &SignedWaiver,
+) -> Result<(), Error> {
+    let signer = storage::get_waiver_signer(env).ok_or(Error::Unauthorized)?;
+    if env.ledger().timestamp() >= waiver.expires_at {
+        return Err(Error::WaiverExpired);
+    }
+    if storage::is_waiver_nonce_used(env, waiver.nonce) {
+        return Err(Error::WaiverNonceUsed);
+    }
+
+    let message = waiver_message(env, creator, waiver.nonce, waiver.expires_at);
+    env.crypto().ed25519_verify(&signer, &message, &waiver.signature);
+
+    storage::set_waiver_nonce_used(env, waiver.nonce);
+    let credits = storage::get_signed_waiver_credits(env, creator)
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_signed_waiver_credits(env, creator, credits);
+
+    crate::events::emit_signed_waiver_redeemed(env, waiver.nonce, creator);
+    Ok(())
+}
+
+/// Spend one of `creator`'s signed waiver credits, if any
+pub fn take_signed_waiver(env: &Env, creator: &Address) -> bool {
+    let credits = storage::get_signed_waiver_credits(env, creator);
+    if credits == 0 {
+        return false;
+    }
+    storage::set_signed_waiver_credits(env, creator, credits - 1);
+    true
+}
+
+/// Take `bps` basis points of `amount`, rounded with `mode`
+pub fn apply_bps(amount: i128, bps: u32, mode: RoundingMode) -> Result<i128, Error> {
+    let denominator = BPS_DENOMINATOR as i128;
+    let scaled = amount
+        .checked_mul(bps as i128)
+        .ok_or(Error::ArithmeticError)?;
+    let quotient = scaled.div_euclid(denominator);
+    let remainder = scaled.rem_euclid(denominator);
+
+    let round_up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => remainder > 0,
+        RoundingMode::Nearest => remainder * 2 >= denominator,
+    };
+    if round_up {
+        quotient.checked_add(1).ok_or(Error::ArithmeticError)
+    } else {
+        Ok(quotient)
+    }
+}
+
+/// Split `amount` into `(to_treasury, burned)` using the configured share
+pub fn split(env: &Env, amount: i128) -> Result<(i128, i128), Error> {
+    let burned = apply_bps(
+        amount,
+        storage::get_fee_burn_bps(env),
+        storage::get_rounding_mode(env),
+    )?;
+    let to_treasury = amount.checked_sub(burned).ok_or(Error::ArithmeticError)?;
+    Ok((to_treasury, burned))
+}
+
+fn distribute(env: &Env, from: &Address, amount: i128) -> Result<(), Error> {
+    if amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    let (to_treasury, burned) = split(env, amount)?;
+
+    // The treasury share stays with the factory until withdrawn
+    let contract = env.current_contract_address();
+    if *from != contract {
+        transfer(env, from, &contract, to_treasury)?;
+    }
+    if let Some(fee_token) = storage::get_fee_token(env) {
+        if burned > 0 {
+            token::Client::new(env, &fee_token).burn(from, &burned);
+        }
+        let balance = storage::get_fee_balance(env, &fee_token)
+            .checked_add(to_treasury)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_fee_balance(env, &fee_token, balance);
+    }
+
+    let mut totals = storage::get_fee_totals(env);
+    totals.to_treasury = totals
+        .to_treasury
+        .checked_add(to_treasury)
+        .ok_or(Error::ArithmeticError)?;
+    totals.burned = totals
+        .burned
+        .checked_add(burned)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_fee_totals(env, &totals);
+
+    Ok(())
+}
+
+fn adjust_escrowed(env: &Env, delta: i128) -> Result<(), Error> {
+    if let Some(fee_token) = storage::get_fee_token(env) {
+        let escrowed = storage::get_escrowed_fees(env, &fee_token)
+            .checked_add(delta)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_escrowed_fees(env, &fee_token, escrowed);
+    }
+    Ok(())
+}
+
+fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    if amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if from == to {
+        return Err(Error::InvalidParameters);
+    }
+    if amount == 0 {
+        return Ok(());
+    }
+    if let Some(fee_token) = storage::get_fee_token(env) {
+        token::Client::new(env, &fee_token).transfer(from, to, &amount);
+    }
+    Ok(())
+}
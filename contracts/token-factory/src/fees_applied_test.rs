@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, TryFromVal, Val};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn wait_out_timelock(env: &Env, client: &TokenFactoryClient) {
+    let delay = client.get_timelock_config().delay_seconds;
+    env.ledger().with_mut(|li| li.timestamp += delay + 1);
+}
+
+type FeesApplied = (u64, Address, Option<i128>, Option<i128>, i128, i128);
+
+/// Decode the `fee_ap_v1` event of the last invocation, if any
+fn fees_applied(env: &Env) -> Option<FeesApplied> {
+    let name: Val = symbol_short!("fee_ap_v1").into_val(env);
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.get(0).unwrap().shallow_eq(&name) {
+            let change_id = u64::try_from_val(env, &topics.get(1).unwrap()).unwrap();
+            let (_version, proposer, proposed_base, proposed_metadata, base, metadata, _seq) =
+                <(u32, Address, Option<i128>, Option<i128>, i128, i128, u64)>::try_from_val(
+                    env, &data,
+                )
+                .unwrap();
+            return Some((
+                change_id,
+                proposer,
+                proposed_base,
+                proposed_metadata,
+                base,
+                metadata,
+            ));
+        }
+    }
+    None
+}
+
+#[test]
+fn test_applied_event_records_proposal_and_result() {
+    let (env, client, admin) = setup();
+    let change_id = client.schedule_fee_update(&admin, &Some(90_000_000), &Some(40_000_000));
+    wait_out_timelock(&env, &client);
+
+    client.execute_change(&change_id);
+
+    assert_eq!(
+        fees_applied(&env),
+        Some((
+            change_id,
+            admin,
+            Some(90_000_000),
+            Some(40_000_000),
+            90_000_000,
+            40_000_000
+        ))
+    );
+    assert_eq!(client.get_base_fee(), 90_000_000);
+}
+
+#[test]
+fn test_partial_proposal_reports_unchanged_fee_as_applied() {
+    let (env, client, admin) = setup();
+    let change_id = client.schedule_fee_update(&admin, &None, &Some(10_000_000));
+    wait_out_timelock(&env, &client);
+
+    client.execute_change(&change_id);
+
+    assert_eq!(
+        fees_applied(&env),
+        Some((
+            change_id,
+            admin,
+            None,
+            Some(10_000_000),
+            BASE_FEE,
+            10_000_000
+        ))
+    );
+}
+
+#[test]
+fn test_immediate_update_emits_no_applied_event() {
+    let (env, client, admin) = setup();
+
+    client.update_fees(&admin, &Some(1), &None);
+
+    assert_eq!(fees_applied(&env), None);
+}
@@ -32,14 +32,14 @@ pub fn freeze_address(
     admin: &Address,
     address_to_freeze: &Address,
 ) -> Result<(), Error> {
+    // Require admin authorization
+    admin.require_auth();
+
     // Check if contract is paused
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
 
-    // Require admin authorization
-    admin.require_auth();
-
     // Verify token exists and get info
     let token_info =
         storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
@@ -63,7 +63,8 @@ pub fn freeze_address(
     storage::set_address_frozen(env, token_address, address_to_freeze, true);
 
     // Emit freeze event
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("freeze"), token_address.clone()),
         (
             admin.clone(),
@@ -106,14 +107,14 @@ pub fn unfreeze_address(
     admin: &Address,
     address_to_unfreeze: &Address,
 ) -> Result<(), Error> {
+    // Require admin authorization
+    admin.require_auth();
+
     // Check if contract is paused
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
 
-    // Require admin authorization
-    admin.require_auth();
-
     // Verify token exists and get info
     let token_info =
         storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
@@ -137,7 +138,8 @@ pub fn unfreeze_address(
     storage::set_address_frozen(env, token_address, address_to_unfreeze, false);
 
     // Emit unfreeze event
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("unfreeze"), token_address.clone()),
         (
             admin.clone(),
@@ -183,14 +185,14 @@ pub fn set_freeze_enabled(
     admin: &Address,
     enabled: bool,
 ) -> Result<(), Error> {
+    // Require admin authorization
+    admin.require_auth();
+
     // Check if contract is paused
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
 
-    // Require admin authorization
-    admin.require_auth();
-
     // Get token info
     let mut token_info =
         storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
@@ -205,7 +207,8 @@ pub fn set_freeze_enabled(
     storage::set_token_info_by_address(env, token_address, &token_info);
 
     // Emit event
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("frz_set"), token_address.clone()),
         (admin.clone(), enabled, env.ledger().timestamp()),
     );
@@ -160,7 +160,7 @@ fn metadata_uri_strategy() -> impl Strategy<Value = Option<String>> {
 // ============================================================================
 
 proptest! {
-    #![proptest_config(ProptestConfig::with_cases(FUZZ_ITERATIONS))]
+    #![proptest_config(fuzz_support::config(FUZZ_ITERATIONS))]
 
     /// Test 1: Fuzz with random token names
     /// Validates name length constraints and character handling
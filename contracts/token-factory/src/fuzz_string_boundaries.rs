@@ -107,7 +107,7 @@ fn boundary_length_symbols() -> impl Strategy<Value = String> {
 }
 
 proptest! {
-    #![proptest_config(ProptestConfig::with_cases(FUZZ_ITERATIONS))]
+    #![proptest_config(fuzz_support::config(FUZZ_ITERATIONS))]
     
     /// Test empty string inputs for token names
     #[test]
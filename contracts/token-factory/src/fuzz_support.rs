@@ -0,0 +1,128 @@
+#![cfg(test)]
+//! Shared seed handling for the proptest fuzz harnesses
+//!
+//! Every harness runs from a fixed u64 seed so a failure can be replayed
+//! exactly. The seed is read from `FUZZ_SEED` (decimal or `0x` hex) and
+//! falls back to `DEFAULT_SEED`.
+//!
+//! Harnesses built with `proptest!` take their config from `config`, which
+//! prints the seed; cargo shows it next to proptest's minimal failing input
+//! when a case fails. Harnesses driven through `run` get a single report
+//! with the seed, the failing input and the replay command.
+//!
+//! To reproduce a failure, rerun with `FUZZ_SEED=<seed>` or pin the seed in
+//! the harness with `replay(seed)`.
+
+extern crate std;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::{Config, RngSeed, TestCaseError, TestError, TestRunner};
+use std::env;
+use std::fmt;
+use std::format;
+use std::string::{String, ToString};
+
+/// Environment variable overriding the fuzz seed
+pub const SEED_VAR: &str = "FUZZ_SEED";
+
+/// Seed used when `FUZZ_SEED` is not set
+pub const DEFAULT_SEED: u64 = 0x5EED_F022;
+
+/// Parse a seed given in decimal or `0x`-prefixed hex
+pub fn parse_seed(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix("0x") {
+        return u64::from_str_radix(hex, 16).map_err(|e| format!("invalid hex seed: {e}"));
+    }
+    value
+        .parse::<u64>()
+        .map_err(|e| format!("invalid decimal seed: {e}"))
+}
+
+/// Seed for this run, from `FUZZ_SEED` or `DEFAULT_SEED`
+pub fn seed() -> u64 {
+    match env::var(SEED_VAR) {
+        Ok(raw) => parse_seed(&raw).unwrap_or_else(|e| panic!("{SEED_VAR}: {e}")),
+        Err(_) => DEFAULT_SEED,
+    }
+}
+
+/// Proptest config running `cases` cases from the current seed
+pub fn config(cases: u32) -> Config {
+    seeded(cases, seed())
+}
+
+/// Proptest config replaying a single run from `seed`
+pub fn replay(seed: u64) -> Config {
+    seeded(Config::default().cases, seed)
+}
+
+fn seeded(cases: u32, seed: u64) -> Config {
+    std::println!("fuzz seed={seed} (replay with {SEED_VAR}={seed})");
+    Config {
+        cases,
+        rng_seed: RngSeed::Fixed(seed),
+        // Seeds are reproduced through FUZZ_SEED, not regression files
+        failure_persistence: None,
+        ..Config::default()
+    }
+}
+
+/// A failing fuzz case
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub seed: u64,
+    /// Debug rendering of the (shrunk) input that failed
+    pub input: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "fuzz case failed")?;
+        writeln!(f, "seed={}", self.seed)?;
+        writeln!(f, "input={}", self.input)?;
+        writeln!(f, "reason={}", self.reason)?;
+        write!(f, "replay={SEED_VAR}={} cargo test --lib", self.seed)
+    }
+}
+
+/// Run `test` against `strategy` under `config`, returning the failure
+pub fn check<S>(
+    config: Config,
+    strategy: S,
+    test: impl Fn(S::Value) -> Result<(), TestCaseError>,
+) -> Result<(), Failure>
+where
+    S: Strategy,
+{
+    let seed = match config.rng_seed {
+        RngSeed::Fixed(seed) => seed,
+        RngSeed::Random => panic!("fuzz configs must use a fixed seed"),
+    };
+
+    let mut runner = TestRunner::new(config);
+    match runner.run(&strategy, test) {
+        Ok(()) => Ok(()),
+        Err(TestError::Fail(reason, input)) => Err(Failure {
+            seed,
+            input: format!("{input:?}"),
+            reason: reason.to_string(),
+        }),
+        Err(TestError::Abort(reason)) => Err(Failure {
+            seed,
+            input: String::from("<none>"),
+            reason: reason.to_string(),
+        }),
+    }
+}
+
+/// Like `check`, but panics with the full failure report
+pub fn run<S>(config: Config, strategy: S, test: impl Fn(S::Value) -> Result<(), TestCaseError>)
+where
+    S: Strategy,
+{
+    if let Err(failure) = check(config, strategy, test) {
+        panic!("{failure}");
+    }
+}
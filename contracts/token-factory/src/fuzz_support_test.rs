@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use fuzz_support::{check, parse_seed, replay, Failure};
+use proptest::prelude::*;
+use std::format;
+use std::string::ToString;
+
+const FIXED_SEED: u64 = 0xDEAD_BEEF;
+
+/// Fails for every fee at or above 500; shrinking lands on exactly 500
+fn fee_below_500(fee: i128) -> Result<(), TestCaseError> {
+    prop_assert!(fee < 500, "fee {} too large", fee);
+    Ok(())
+}
+
+#[test]
+fn test_failure_reports_seed_and_exact_input() {
+    let failure = check(replay(FIXED_SEED), 0i128..1_000, fee_below_500).unwrap_err();
+
+    assert_eq!(failure.seed, FIXED_SEED);
+    assert_eq!(failure.input, "500");
+    assert!(failure.reason.contains("fee 500 too large"));
+
+    let report = failure.to_string();
+    assert!(report.contains(&format!("seed={FIXED_SEED}")));
+    assert!(report.contains("input=500"));
+    assert!(report.contains(&format!("FUZZ_SEED={FIXED_SEED}")));
+}
+
+#[test]
+fn test_replay_reproduces_failure() {
+    let strategy = (0u32..100, 0i128..1_000);
+    let test = |(decimals, fee): (u32, i128)| -> Result<(), TestCaseError> {
+        prop_assert!(decimals < 50 || fee < 900);
+        Ok(())
+    };
+
+    let first: Failure = check(replay(FIXED_SEED), strategy.clone(), test).unwrap_err();
+    let second: Failure = check(replay(FIXED_SEED), strategy, test).unwrap_err();
+
+    assert_eq!(first, second);
+    assert_eq!(first.input, "(50, 900)");
+}
+
+#[test]
+fn test_passing_run_reports_nothing() {
+    assert_eq!(check(replay(FIXED_SEED), 0i128..500, fee_below_500), Ok(()));
+}
+
+#[test]
+#[should_panic(expected = "input=500")]
+fn test_run_panics_with_report() {
+    fuzz_support::run(replay(FIXED_SEED), 0i128..1_000, fee_below_500);
+}
+
+#[test]
+fn test_parse_seed() {
+    assert_eq!(parse_seed("42"), Ok(42));
+    assert_eq!(parse_seed("0xff"), Ok(255));
+    assert!(parse_seed("seed").is_err());
+}
@@ -48,7 +48,7 @@ fn optional_fee_strategy() -> impl Strategy<Value = Option<i128>> {
 }
 
 proptest! {
-    #![proptest_config(ProptestConfig::with_cases(FUZZ_ITERATIONS))]
+    #![proptest_config(fuzz_support::config(FUZZ_ITERATIONS))]
 
     /// Test 1: Fuzz update_fees with random base fee values
     /// Tests all possible base fee values while keeping metadata fee unchanged
@@ -164,6 +164,16 @@ impl GovBenchSetup {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         
         };
         
@@ -327,6 +337,8 @@ fn bench_gov_batch_update_admin() {
                 &Some(base_fee),
                 &Some(metadata_fee),
                 &Some(paused),
+                &None,
+                &None,
             );
         });
         
@@ -479,6 +491,8 @@ fn bench_gov_batch_vs_individual() {
                 &Some(base_fee),
                 &Some(metadata_fee),
                 &Some(paused),
+                &None,
+                &None,
             );
         });
         batch_samples.push(cpu);
@@ -603,6 +617,8 @@ fn bench_gov_comprehensive_report() {
                 &Some(100_000_000 + i as i128),
                 &Some(50_000_000 + i as i128),
                 &Some(i % 2 == 0),
+                &None,
+                &None,
             );
         });
         batch_update_samples.push(cpu);
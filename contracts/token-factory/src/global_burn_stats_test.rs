@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    contract_id: Address,
+    admin: Address,
+    creator: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    for symbol in ["ONE", "TWO"] {
+        let spec = token_params(&env, symbol);
+        client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+    }
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        admin,
+        creator,
+    }
+}
+
+#[test]
+fn test_starts_empty() {
+    let s = setup();
+
+    assert_eq!(s.client.get_global_burn_stats(), (0, 0));
+}
+
+#[test]
+fn test_global_stats_sum_per_token_burns() {
+    let s = setup();
+    let holder = Address::generate(&s.env);
+    s.env.as_contract(&s.contract_id, || {
+        storage::set_balance(&s.env, 1, &holder, 500);
+    });
+    let token = s.client.get_token_info(&1).address;
+    s.client.set_clawback(&token, &s.creator, &true);
+
+    s.client.burn(&s.creator, &0, &1_000);
+    s.client.burn(&s.creator, &0, &250);
+    s.client.admin_burn(&s.admin, &1, &holder, &100);
+    s.client.batch_burn(
+        &s.admin,
+        &1,
+        &vec![&s.env, (s.creator.clone(), 300), (holder.clone(), 50)],
+    );
+
+    let first = s.client.get_token_info(&0);
+    let second = s.client.get_token_info(&1);
+    assert_eq!(first.total_burned, 1_250);
+    assert_eq!(second.total_burned, 450);
+
+    assert_eq!(
+        s.client.get_global_burn_stats(),
+        (
+            first.total_burned + second.total_burned,
+            first.burn_count + second.burn_count
+        )
+    );
+    assert_eq!(s.client.get_global_burn_stats(), (1_700, 5));
+}
+
+#[test]
+fn test_failed_burn_leaves_stats() {
+    let s = setup();
+
+    assert!(s.client.try_burn(&s.creator, &0, &2_000_000).is_err());
+
+    assert_eq!(s.client.get_global_burn_stats(), (0, 0));
+}
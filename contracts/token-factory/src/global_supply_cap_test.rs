@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+const SUPPLY: i128 = 1_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    Setup {
+        creator: Address::generate(&env),
+        env,
+        client,
+        admin,
+    }
+}
+
+fn spec(env: &Env) -> TokenCreationParams {
+    TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(env, "CAP")
+    }
+}
+
+fn try_create(s: &Setup) -> Result<Address, Result<Error, soroban_sdk::InvokeError>> {
+    s.client
+        .try_create_token(
+            &s.creator,
+            &spec(&s.env),
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        )
+        .map(|created| created.unwrap())
+}
+
+#[test]
+fn test_creation_up_to_cap() {
+    let s = setup();
+    s.client.set_global_supply_cap(&s.admin, &Some(2 * SUPPLY));
+
+    assert!(try_create(&s).is_ok());
+    assert!(try_create(&s).is_ok());
+    assert_eq!(s.client.get_global_total_supply(), 2 * SUPPLY);
+
+    assert_eq!(try_create(&s), Err(Ok(Error::GlobalCapExceeded)));
+    assert_eq!(s.client.get_token_count(&true), 2);
+}
+
+#[test]
+fn test_validation_rejects_spec_over_cap() {
+    let s = setup();
+    s.client.set_global_supply_cap(&s.admin, &Some(SUPPLY));
+    assert!(try_create(&s).is_ok());
+
+    let spec = spec(&s.env);
+    assert_eq!(
+        s.client.try_validate_token_spec(
+            &s.creator,
+            &spec.name,
+            &spec.symbol,
+            &spec.decimals,
+            &spec.initial_supply,
+            &spec.metadata_uri,
+        ),
+        Err(Ok(Error::GlobalCapExceeded))
+    );
+    assert_eq!(try_create(&s), Err(Ok(Error::GlobalCapExceeded)));
+}
+
+#[test]
+fn test_mint_over_cap_rejected() {
+    let s = setup();
+    s.client
+        .set_global_supply_cap(&s.admin, &Some(SUPPLY + 500));
+    try_create(&s).unwrap();
+
+    s.client.mint(&s.creator, &0, &s.creator, &500);
+    assert_eq!(s.client.get_global_total_supply(), SUPPLY + 500);
+
+    assert_eq!(
+        s.client.try_mint(&s.creator, &0, &s.creator, &1),
+        Err(Ok(Error::GlobalCapExceeded))
+    );
+}
+
+#[test]
+fn test_raising_cap_permits_more() {
+    let s = setup();
+    s.client.set_global_supply_cap(&s.admin, &Some(SUPPLY));
+    let token = try_create(&s).unwrap();
+    assert_eq!(try_create(&s), Err(Ok(Error::GlobalCapExceeded)));
+    assert!(!s.client.can_mint(&token, &1));
+
+    s.client.set_global_supply_cap(&s.admin, &Some(3 * SUPPLY));
+    assert!(try_create(&s).is_ok());
+    s.client.mint(&s.creator, &0, &s.creator, &SUPPLY);
+    assert_eq!(s.client.get_mint_headroom(&token), 0);
+
+    s.client.set_global_supply_cap(&s.admin, &None);
+    assert!(try_create(&s).is_ok());
+    assert_eq!(s.client.get_global_total_supply(), 4 * SUPPLY);
+}
+
+#[test]
+fn test_burn_frees_room() {
+    let s = setup();
+    s.client.set_global_supply_cap(&s.admin, &Some(SUPPLY));
+    try_create(&s).unwrap();
+
+    s.client.burn(&s.creator, &0, &400);
+
+    assert_eq!(s.client.get_global_total_supply(), SUPPLY - 400);
+    s.client.mint(&s.creator, &0, &s.creator, &400);
+}
+
+#[test]
+fn test_set_global_supply_cap_validation() {
+    let s = setup();
+
+    assert_eq!(s.client.get_global_supply_cap(), None);
+    assert_eq!(
+        s.client.try_set_global_supply_cap(&s.admin, &Some(-1)),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_set_global_supply_cap(&Address::generate(&s.env), &Some(SUPPLY)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.client.set_global_supply_cap(&s.admin, &Some(SUPPLY));
+    assert_eq!(s.client.get_global_supply_cap(), Some(SUPPLY));
+}
@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Env, IntoVal};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let guardian = Address::generate(&env);
+    client.set_guardian(&admin, &guardian);
+
+    (env, contract_id, client, admin, guardian)
+}
+
+/// Authorize only `signer` for `transfer_admin(admin, new_admin)`
+fn auth_transfer(
+    env: &Env,
+    contract_id: &Address,
+    signer: &Address,
+    admin: &Address,
+    new_admin: &Address,
+) {
+    env.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: contract_id,
+            fn_name: "transfer_admin",
+            args: (admin, new_admin).into_val(env),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn test_transfer_without_guardian_auth_fails() {
+    let (env, contract_id, client, admin, _guardian) = setup();
+    let new_admin = Address::generate(&env);
+
+    auth_transfer(&env, &contract_id, &admin, &admin, &new_admin);
+
+    assert!(client.try_transfer_admin(&admin, &new_admin).is_err());
+    assert_eq!(client.get_state().admin, admin);
+}
+
+#[test]
+fn test_transfer_with_admin_and_guardian_succeeds() {
+    let (env, contract_id, client, admin, guardian) = setup();
+    let new_admin = Address::generate(&env);
+
+    let invoke = MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "transfer_admin",
+        args: (&admin, &new_admin).into_val(&env),
+        sub_invokes: &[],
+    };
+    env.mock_auths(&[
+        MockAuth {
+            address: &admin,
+            invoke: &invoke,
+        },
+        MockAuth {
+            address: &guardian,
+            invoke: &invoke,
+        },
+    ]);
+
+    client.transfer_admin(&admin, &new_admin);
+
+    assert_eq!(client.get_state().admin, new_admin);
+}
+
+#[test]
+fn test_accept_admin_requires_guardian() {
+    let (env, contract_id, client, admin, _guardian) = setup();
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&admin, &new_admin);
+
+    env.mock_auths(&[MockAuth {
+        address: &new_admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "accept_admin",
+            args: (&new_admin,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_accept_admin(&new_admin).is_err());
+
+    env.mock_all_auths();
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_state().admin, new_admin);
+}
+
+#[test]
+fn test_single_auth_path_without_guardian() {
+    let (env, contract_id, client, admin, _guardian) = setup();
+    client.remove_guardian(&admin);
+    assert_eq!(client.get_guardian(), None);
+
+    let new_admin = Address::generate(&env);
+    auth_transfer(&env, &contract_id, &admin, &admin, &new_admin);
+
+    client.transfer_admin(&admin, &new_admin);
+
+    assert_eq!(client.get_state().admin, new_admin);
+}
+
+#[test]
+fn test_guardian_changes_need_guardian_auth() {
+    let (env, contract_id, client, admin, guardian) = setup();
+    assert_eq!(client.get_guardian(), Some(guardian.clone()));
+
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "remove_guardian",
+            args: (&admin,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_remove_guardian(&admin).is_err());
+    assert_eq!(client.get_guardian(), Some(guardian));
+}
+
+#[test]
+fn test_set_guardian_validation() {
+    let (env, _contract_id, client, admin, _guardian) = setup();
+
+    assert_eq!(
+        client.try_set_guardian(&admin, &admin),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_set_guardian(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String};
+
+#[test]
+fn test_uninitialized_contract_reports_unhealthy() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let health = client.health_check();
+    assert!(!health.initialized);
+    assert!(!health.invariants_ok);
+    assert_eq!(health.token_count, 0);
+}
+
+#[test]
+fn test_health_tracks_pause_tokens_and_version() {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let health = client.health_check();
+    assert_eq!(
+        health,
+        types::HealthStatus {
+            initialized: true,
+            paused: false,
+            token_count: 0,
+            invariants_ok: true,
+            version: None,
+        }
+    );
+
+    let spec = token_params(&env, "HLTH");
+    let creator = Address::generate(&env);
+    client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+    client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+    env.as_contract(&contract_id, || {
+        upgrade::record_version(
+            &env,
+            &String::from_str(&env, "1.2.0"),
+            &BytesN::from_array(&env, &[7; 32]),
+        )
+        .unwrap();
+    });
+    client.pause(&admin);
+
+    let health = client.health_check();
+    assert!(health.paused);
+    assert!(health.invariants_ok);
+    assert_eq!(health.token_count, client.get_token_count(&true));
+    assert_eq!(health.token_count, 2);
+    assert_eq!(health.version, Some(String::from_str(&env, "1.2.0")));
+}
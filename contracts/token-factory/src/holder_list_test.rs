@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+const SUPPLY: i128 = 1_000_000;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let params = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&env, "HOLD")
+    };
+    let creator = Address::generate(&env);
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    (env, client, creator, token)
+}
+
+#[test]
+fn test_lists_distributed_holders() {
+    let (env, client, creator, token) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    client.batch_transfer(
+        &token,
+        &creator,
+        &vec![
+            &env,
+            (alice.clone(), 100_i128),
+            (bob.clone(), 200_i128),
+            (carol.clone(), 300_i128),
+        ],
+    );
+
+    assert_eq!(
+        client.list_holders(&0, &0, &10),
+        vec![
+            &env,
+            (creator.clone(), SUPPLY - 600),
+            (alice.clone(), 100),
+            (bob.clone(), 200),
+            (carol.clone(), 300),
+        ]
+    );
+    assert_eq!(client.get_holder_count(&0), 4);
+}
+
+#[test]
+fn test_pagination() {
+    let (env, client, creator, token) = setup();
+    let mut transfers = Vec::new(&env);
+    for _ in 0..5 {
+        transfers.push_back((Address::generate(&env), 10_i128));
+    }
+    client.batch_transfer(&token, &creator, &transfers);
+
+    let first = client.list_holders(&0, &0, &4);
+    let second = client.list_holders(&0, &4, &4);
+    assert_eq!(first.len(), 4);
+    assert_eq!(second.len(), 2);
+    assert!(client.list_holders(&0, &6, &4).is_empty());
+
+    let mut listed = first;
+    listed.append(&second);
+    for (i, (to, amount)) in transfers.iter().enumerate() {
+        assert_eq!(listed.get(i as u32 + 1).unwrap(), (to, amount));
+    }
+}
+
+#[test]
+fn test_emptied_holder_is_removed() {
+    let (env, client, creator, token) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let memo = Bytes::new(&env);
+    client.transfer_with_memo(&token, &creator, &alice, &100, &memo);
+    client.transfer_with_memo(&token, &creator, &bob, &50, &memo);
+
+    // Alice sends everything on; burning to zero empties bob
+    client.transfer_with_memo(&token, &alice, &creator, &100, &memo);
+    client.burn(&bob, &0, &50);
+
+    assert_eq!(
+        client.list_holders(&0, &0, &10),
+        vec![&env, (creator.clone(), SUPPLY - 50)]
+    );
+    assert_eq!(client.get_holder_count(&0), 1);
+
+    // A returning holder is listed again, at the end
+    client.transfer_with_memo(&token, &creator, &alice, &5, &memo);
+    assert_eq!(
+        client.list_holders(&0, &0, &10),
+        vec![&env, (creator.clone(), SUPPLY - 55), (alice, 5)]
+    );
+}
+
+#[test]
+fn test_emptied_holder_is_replaced_by_last() {
+    let (env, client, creator, token) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    client.batch_transfer(
+        &token,
+        &creator,
+        &vec![
+            &env,
+            (alice.clone(), 100_i128),
+            (bob.clone(), 200_i128),
+            (carol.clone(), 300_i128),
+        ],
+    );
+
+    client.burn(&alice, &0, &100);
+
+    assert_eq!(
+        client.list_holders(&0, &0, &10),
+        vec![
+            &env,
+            (creator.clone(), SUPPLY - 600),
+            (carol, 300),
+            (bob, 200),
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_token_lists_nothing() {
+    let (_env, client, _creator, _token) = setup();
+
+    assert!(client.list_holders(&7, &0, &10).is_empty());
+}
@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let params = token_params(&env, "CNT");
+    client.batch_create_tokens(
+        &Address::generate(&env),
+        &vec![&env, params],
+        &BASE_FEE,
+        &None,
+    );
+
+    (env, contract_id, client, admin)
+}
+
+fn holders(env: &Env, contract_id: &Address, count: u32) -> Vec<Address> {
+    let mut out = Vec::new(env);
+    for _ in 0..count {
+        let holder = Address::generate(env);
+        env.as_contract(contract_id, || storage::set_balance(env, 0, &holder, 100));
+        out.push_back(holder);
+    }
+    out
+}
+
+fn corrupt(env: &Env, contract_id: &Address, count: u32) {
+    env.as_contract(contract_id, || storage::set_holder_count(env, 0, count));
+}
+
+#[test]
+fn test_recompute_corrects_drifted_count() {
+    let (env, contract_id, client, admin) = setup();
+    let mut holders = holders(&env, &contract_id, 3);
+    // The creator holds the initial supply
+    holders.push_back(client.get_token_info(&0).creator);
+    let expected = client.get_holder_count(&0);
+    assert_eq!(expected, 4);
+
+    corrupt(&env, &contract_id, 42);
+    assert_eq!(client.get_holder_count(&0), 42);
+
+    let recounted = client.recompute_holder_count(&admin, &0, &holders);
+
+    assert_eq!(recounted, expected);
+    assert_eq!(client.get_holder_count(&0), expected);
+}
+
+#[test]
+fn test_recompute_ignores_duplicates_and_empty_balances() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 2);
+    corrupt(&env, &contract_id, 0);
+
+    let mut candidates = holders.clone();
+    candidates.push_back(holders.get(0).unwrap());
+    candidates.push_back(Address::generate(&env));
+
+    assert_eq!(client.recompute_holder_count(&admin, &0, &candidates), 2);
+    assert_eq!(client.get_holder_count(&0), 2);
+}
+
+#[test]
+fn test_recompute_validation() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 1);
+
+    assert_eq!(
+        client.try_recompute_holder_count(&Address::generate(&env), &0, &holders),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_recompute_holder_count(&admin, &9, &holders),
+        Err(Ok(Error::TokenNotFound))
+    );
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..101 {
+        too_many.push_back(Address::generate(&env));
+    }
+    assert_eq!(
+        client.try_recompute_holder_count(&admin, &0, &too_many),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
+
+#[test]
+fn test_recompute_rebuilds_holder_list() {
+    let (env, contract_id, client, admin) = setup();
+    let holders = holders(&env, &contract_id, 3);
+    assert_eq!(client.list_holders(&0, &0, &100).len(), 4);
+
+    let kept = vec![&env, holders.get(2).unwrap(), holders.get(0).unwrap()];
+    assert_eq!(client.recompute_holder_count(&admin, &0, &kept), 2);
+
+    assert_eq!(
+        client.list_holders(&0, &0, &100),
+        vec![
+            &env,
+            (holders.get(2).unwrap(), 100),
+            (holders.get(0).unwrap(), 100)
+        ]
+    );
+    assert_eq!(client.get_holder_count(&0), 2);
+
+    // Balance changes keep the rebuilt list and count in step
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &holders.get(2).unwrap(), 0)
+    });
+    assert_eq!(
+        client.list_holders(&0, &0, &100),
+        vec![&env, (holders.get(0).unwrap(), 100)]
+    );
+    assert_eq!(client.get_holder_count(&0), 1);
+}
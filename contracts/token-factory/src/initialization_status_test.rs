@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    (env, client)
+}
+
+#[test]
+fn test_uninitialized_factory() {
+    let (_env, client) = setup();
+
+    assert!(!client.is_initialized());
+    assert_eq!(client.get_init_params(), None);
+}
+
+#[test]
+fn test_initialized_factory() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+
+    assert!(client.is_initialized());
+    assert_eq!(
+        client.get_init_params(),
+        Some((admin, treasury, 70_000_000, 30_000_000))
+    );
+}
+
+#[test]
+fn test_params_reflect_current_fees() {
+    let (env, client) = setup();
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+
+    client.update_fees(&admin, &Some(1_000), &None);
+
+    assert_eq!(
+        client.get_init_params(),
+        Some((admin, treasury, 1_000, 30_000_000))
+    );
+}
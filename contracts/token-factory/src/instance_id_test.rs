@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, TryFromVal, Val};
+
+fn register(env: &Env) -> TokenFactoryClient<'static> {
+    let contract_id = env.register_contract(None, TokenFactory);
+    TokenFactoryClient::new(env, &contract_id)
+}
+
+/// Last topic of the last event emitted by the last invocation
+fn last_instance_topic(env: &Env) -> Symbol {
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let topic: Val = topics.last().unwrap();
+    Symbol::try_from_val(env, &topic).unwrap()
+}
+
+#[test]
+fn test_factories_tag_events_with_their_instance_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let first = register(&env);
+    first.initialize_with_instance_id(
+        &admin,
+        &treasury,
+        &70_000_000,
+        &30_000_000,
+        &symbol_short!("alpha"),
+    );
+    let first_init = last_instance_topic(&env);
+
+    let second = register(&env);
+    second.initialize_with_instance_id(
+        &admin,
+        &treasury,
+        &70_000_000,
+        &30_000_000,
+        &symbol_short!("beta"),
+    );
+    let second_init = last_instance_topic(&env);
+
+    assert_eq!(first_init, symbol_short!("alpha"));
+    assert_eq!(second_init, symbol_short!("beta"));
+
+    first.pause(&admin);
+    assert_eq!(last_instance_topic(&env), symbol_short!("alpha"));
+    second.pause(&admin);
+    assert_eq!(last_instance_topic(&env), symbol_short!("beta"));
+
+    assert_eq!(first.get_instance_id(), symbol_short!("alpha"));
+    assert_eq!(second.get_instance_id(), symbol_short!("beta"));
+}
+
+#[test]
+fn test_instance_id_defaults_when_omitted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let client = register(&env);
+    client.initialize(&admin, &Address::generate(&env), &70_000_000, &30_000_000);
+
+    assert_eq!(last_instance_topic(&env), symbol_short!("default"));
+    assert_eq!(client.get_instance_id(), symbol_short!("default"));
+}
+
+#[test]
+fn test_instance_id_cannot_be_set_after_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let client = register(&env);
+    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+
+    let result = client.try_initialize_with_instance_id(
+        &admin,
+        &treasury,
+        &70_000_000,
+        &30_000_000,
+        &symbol_short!("late"),
+    );
+
+    assert_eq!(result, Err(Ok(Error::AlreadyInitialized)));
+    assert_eq!(client.get_instance_id(), symbol_short!("default"));
+}
@@ -4,14 +4,18 @@
 #![allow(deprecated)]
 #![allow(unused_must_use)]
 
+mod allowance;
+mod approvals;
 mod campaign_validation;
 mod freeze_functions;
 mod governance;
 
 mod burn;
 mod differential_engine;
+mod escrow;
 mod event_versions;
 mod events;
+mod fees;
 mod milestone_verification;
 #[cfg(all(test, feature = "legacy-tests"))]
 mod milestone_verification_test;
@@ -21,14 +25,20 @@ mod mint;
 mod pagination;
 mod payload_validation;
 mod proposal_state_machine;
+mod sac_bridge;
 mod storage;
 mod stream_types;
 #[cfg(test)]
+mod fuzz_support;
+#[cfg(test)]
 mod test_helpers;
 mod timelock;
+mod token_attributes;
 mod token_creation;
+mod transfer;
 mod treasury;
 mod types;
+mod upgrade;
 mod vesting;
 mod validation;
 
@@ -56,14 +66,28 @@ mod stream_claim_differential_test;
 // #[cfg(test)]
 // mod governance_test;
 
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec,
+};
 use types::{
     BuybackCampaign, CampaignStatus, ContractMetadata, Error, FactoryState, PaginationCursor,
-    StreamInfo, StreamPage, StreamParams, TokenCreationParams, TokenInfo, TokenStats, Vault,
-    VaultStatus,
+    StreamInfo, StreamPage, StreamParams, TimeSource, TokenCreationParams, TokenInfo, TokenKind,
+    TokenStats, Vault, VaultStatus,
 };
 use crate::milestone_verification::MilestoneVerifier;
 
+/// Maximum number of tokens accepted by `bulk_set_clawback`
+const MAX_BULK_CLAWBACK: u32 = 100;
+
+/// Maximum number of reads accepted by `multicall_reads`
+const MAX_MULTICALL_READS: u32 = 50;
+
+/// Maximum number of addresses accepted by `get_token_infos_by_address`
+const MAX_ADDRESS_LOOKUPS: u32 = 50;
+
+/// Maximum number of candidate holders accepted by `recompute_holder_count`
+const MAX_HOLDER_RECOUNT: u32 = 100;
+
 #[contract]
 pub struct TokenFactory;
 
@@ -111,10 +135,7 @@ impl TokenFactory {
         }
 
         // Combined parameter validation (Phase 1 optimization)
-        // Check both fees in single evaluation
-        if base_fee < 0 || metadata_fee < 0 {
-            return Err(Error::InvalidParameters);
-        }
+        fees::validate_fee_amounts(Some(base_fee), Some(metadata_fee))?;
 
         // Set initial state
         storage::set_admin(&env, &admin);
@@ -128,6 +149,84 @@ impl TokenFactory {
         Ok(())
     }
 
+    /// Initialize the token factory contract with an instance id
+    ///
+    /// Same as `initialize`, but also records `instance_id`, which is added
+    /// as the last topic of every event this factory emits. Factories
+    /// initialized with `initialize` use the id `default`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Address with administrative privileges
+    /// * `treasury` - Address that will receive deployment fees
+    /// * `base_fee` - Base fee for token deployment in stroops (must be >= 0)
+    /// * `metadata_fee` - Additional fee for metadata in stroops (must be >= 0)
+    /// * `instance_id` - Identifier distinguishing this factory's events
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::AlreadyInitialized` - Contract has already been initialized
+    /// * `Error::InvalidParameters` - Either fee is negative
+    ///
+    /// # Examples
+    /// ```
+    /// factory.initialize_with_instance_id(
+    ///     &env,
+    ///     admin_address,
+    ///     treasury_address,
+    ///     1_000_000,
+    ///     500_000,
+    ///     symbol_short!("mainnet1"),
+    /// )?;
+    /// ```
+    pub fn initialize_with_instance_id(
+        env: Env,
+        admin: Address,
+        treasury: Address,
+        base_fee: i128,
+        metadata_fee: i128,
+        instance_id: Symbol,
+    ) -> Result<(), Error> {
+        if storage::has_admin(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        // Set before initializing so the init event already carries the id
+        storage::set_instance_id(&env, &instance_id);
+
+        Self::initialize(env, admin, treasury, base_fee, metadata_fee)
+    }
+
+    /// Get the instance id tagged onto every event
+    ///
+    /// # Returns
+    /// The id given at initialization, or `default` if none was given
+    pub fn get_instance_id(env: Env) -> Symbol {
+        storage::get_instance_id(&env)
+    }
+
+    /// Get the sequence number of the last emitted event
+    ///
+    /// Every event carries its sequence number as the last payload field,
+    /// so indexers can compare against this value to detect missed events.
+    ///
+    /// # Returns
+    /// The last sequence number issued, or 0 if no event was emitted
+    pub fn get_current_event_seq(env: Env) -> u64 {
+        storage::get_event_seq(&env)
+    }
+
+    /// Get the payload version carried by every event
+    ///
+    /// Every event's payload starts with this version; it is bumped
+    /// whenever an event payload changes shape, so indexers can choose the
+    /// right decoder.
+    pub fn get_event_payload_version(_env: Env) -> u32 {
+        events::EVENT_PAYLOAD_VERSION
+    }
+
 
 
     /// Get the current factory state
@@ -151,6 +250,50 @@ impl TokenFactory {
         storage::get_factory_state(&env)
     }
 
+    /// Check whether `initialize` has already run
+    ///
+    /// Lets deploy tooling probe the factory without triggering
+    /// `Error::AlreadyInitialized`.
+    pub fn is_initialized(env: Env) -> bool {
+        storage::has_admin(&env)
+    }
+
+    /// Summarize contract health for monitoring
+    ///
+    /// Read-only and cheap: reports whether the factory is initialized and
+    /// paused, its token count, whether the admin, treasury and fee
+    /// invariants hold, and the current version label. Safe to call before
+    /// `initialize`.
+    pub fn health_check(env: Env) -> types::HealthStatus {
+        validation::health_check(&env)
+    }
+
+    /// Get the initialization parameters as they stand now
+    ///
+    /// Returns `(admin, treasury, base_fee, metadata_fee)` with their current
+    /// values, which may have changed since `initialize`, or `None` if the
+    /// factory is not initialized yet.
+    pub fn get_init_params(env: Env) -> Option<(Address, Address, i128, i128)> {
+        if !storage::has_admin(&env) {
+            return None;
+        }
+
+        Some((
+            storage::get_admin(&env),
+            storage::get_treasury(&env),
+            storage::get_base_fee(&env),
+            storage::get_metadata_fee(&env),
+        ))
+    }
+
+    /// Get every admin-tunable parameter in one call
+    ///
+    /// Bundles fees, creation limits, cooldowns, symbol and allowlist modes
+    /// and pause flags, so clients need not query each setting separately.
+    pub fn get_admin_config(env: Env) -> types::AdminConfig {
+        storage::get_admin_config(&env)
+    }
+
     /// Get the current base fee for token deployment
     ///
     /// Returns the base fee amount in stroops that must be paid
@@ -192,6 +335,125 @@ impl TokenFactory {
         storage::get_metadata_fee(&env)
     }
 
+    /// Set the asset deployment fees are paid in (admin only)
+    ///
+    /// Once set, creation fees are transferred in this asset (typically the
+    /// native XLM Stellar Asset Contract). Until then fees are only checked.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    /// * `fee_token` - Token contract address of the fee asset
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    ///
+    /// # Examples
+    /// ```
+    /// factory.set_fee_token(&env, admin, xlm_sac_address)?;
+    /// ```
+    pub fn set_fee_token(env: Env, admin: Address, fee_token: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_fee_token(&env, &fee_token);
+        events::emit_fee_token_updated(&env, &admin, &fee_token);
+
+        Ok(())
+    }
+
+    /// Get the asset deployment fees are paid in, if configured
+    pub fn get_fee_token(env: Env) -> Option<Address> {
+        storage::get_fee_token(&env)
+    }
+
+    /// Set the share of collected fees that is burned (admin only)
+    ///
+    /// On every fee collection, `bps` basis points of the fee are burned
+    /// (of the fee asset). The remainder is not transferred at collection:
+    /// it is added to the fee balance and reaches the treasury through
+    /// `withdraw_fees`, like every collected fee.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    /// * `bps` - Burn share in basis points (0-10000)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - `bps` is greater than 10000
+    ///
+    /// # Examples
+    /// ```
+    /// // Burn 20% of every fee, keep 80% for the treasury
+    /// factory.set_fee_burn_bps(&env, admin, 2000)?;
+    /// ```
+    pub fn set_fee_burn_bps(env: Env, admin: Address, bps: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if bps > fees::BPS_DENOMINATOR {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_fee_burn_bps(&env, bps);
+        events::emit_fee_burn_bps_updated(&env, &admin, bps);
+
+        Ok(())
+    }
+
+    /// Get the share of collected fees that is burned, in basis points
+    pub fn get_fee_burn_bps(env: Env) -> u32 {
+        storage::get_fee_burn_bps(&env)
+    }
+
+    /// Set how basis-point shares are rounded (admin only)
+    ///
+    /// Applies to the burned share of every collected fee. The remainder
+    /// goes to the treasury, so fees are conserved under every mode.
+    /// Defaults to `RoundingMode::Floor`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    /// * `mode` - New rounding mode
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_rounding_mode(
+        env: Env,
+        admin: Address,
+        mode: types::RoundingMode,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_rounding_mode(&env, mode);
+        Ok(())
+    }
+
+    /// Get the rounding mode for basis-point shares
+    pub fn get_rounding_mode(env: Env) -> types::RoundingMode {
+        storage::get_rounding_mode(&env)
+    }
+
+    /// Get running totals of fees sent to the treasury and burned
+    pub fn get_fee_totals(env: Env) -> types::FeeTotals {
+        storage::get_fee_totals(&env)
+    }
+
     /// Transfer admin rights to a new address
     ///
     /// Allows the current admin to transfer administrative control to a new address.
@@ -203,9 +465,13 @@ impl TokenFactory {
     /// * `current_admin` - The current admin address (must authorize)
     /// * `new_admin` - The new admin address to transfer rights to
     ///
+    /// When a guardian is set (see `set_guardian`), the guardian must
+    /// authorize the transfer as well.
+    ///
     /// # Errors
     /// * `Unauthorized` - If caller is not the current admin
-    /// * `InvalidParameters` - If new admin is same as current or invalid
+    /// * `InvalidParameters` - If new admin is same as current, the factory
+    ///   itself or a token deployed by the factory
     pub fn transfer_admin(
         env: Env,
         current_admin: Address,
@@ -213,6 +479,7 @@ impl TokenFactory {
     ) -> Result<(), Error> {
         // Require current admin authorization
         current_admin.require_auth();
+        storage::require_guardian_auth(&env);
 
         // Combined verification (Phase 1 optimization)
         // Early return if not authorized
@@ -238,6 +505,49 @@ impl TokenFactory {
         Ok(())
     }
 
+    /// Swap the admin and treasury addresses
+    ///
+    /// Atomically makes the current treasury the admin and the current
+    /// admin the treasury. Any pending two-step admin proposal is dropped.
+    ///
+    /// The treasury only ever receives funds, so nothing checks that it can
+    /// sign. Swap only when the treasury is an account whose keys are held:
+    /// if it cannot authorize, the factory is left without a usable admin.
+    /// A guardian, when set, must authorize the swap as well.
+    ///
+    /// # Arguments
+    /// * `admin` - The current admin address (must authorize)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the current admin
+    /// * `InvalidParameters` - If admin and treasury are the same address,
+    ///   or the treasury is the factory itself or a factory token
+    pub fn swap_admin_treasury(env: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+        storage::require_guardian_auth(&env);
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let treasury = storage::get_treasury(&env);
+        if treasury == current_admin {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_admin(&env, &treasury);
+        storage::set_treasury(&env, &current_admin);
+        storage::clear_pending_admin(&env);
+
+        validation::validate_admin(&env)?;
+
+        events::emit_admin_transfer(&env, &current_admin, &treasury);
+        events::emit_treasury_updated(&env, &current_admin);
+
+        Ok(())
+    }
+
     /// Propose a new admin (two-step transfer - step 1)
     ///
     /// Initiates a two-step admin transfer by proposing a new admin.
@@ -283,6 +593,7 @@ impl TokenFactory {
     ///
     /// Completes the admin transfer by accepting the pending proposal.
     /// Only the proposed admin can call this. Clears the pending admin after acceptance.
+    /// When a guardian is set, the guardian must authorize the acceptance as well.
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -295,6 +606,7 @@ impl TokenFactory {
     /// * `Unauthorized` - If caller is not the pending admin or no pending admin exists
     pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), Error> {
         new_admin.require_auth();
+        storage::require_guardian_auth(&env);
 
         let pending = storage::get_pending_admin(&env).ok_or(Error::Unauthorized)?;
 
@@ -313,89 +625,304 @@ impl TokenFactory {
         Ok(())
     }
 
-    /// Pause the contract (admin only)
-    ///
-    /// Halts critical operations like token creation and metadata updates.
-    /// Admin functions like fee updates remain operational during pause.
-    /// This is a safety mechanism for emergency situations.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// Set the guardian that co-signs admin transfers (admin only)
     ///
-    /// # Returns
-    /// Returns `Ok(())` on success
+    /// While a guardian is set, `transfer_admin`, `accept_admin` and
+    /// `swap_admin_treasury` also require the guardian's authorization.
+    /// Replacing an existing guardian needs that guardian's authorization
+    /// too, so the admin alone cannot lift the requirement.
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the admin
-    ///
-    /// # Examples
-    /// ```
-    /// // Emergency pause
-    /// factory.pause(&env, admin_address)?;
-    /// assert!(factory.is_paused(&env));
-    /// ```
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
+    /// * `Unauthorized` - Caller is not the current admin
+    /// * `InvalidParameters` - Guardian is the admin itself
+    pub fn set_guardian(env: Env, admin: Address, guardian: Address) -> Result<(), Error> {
         admin.require_auth();
 
-        // Combined verification (Phase 1 optimization)
         let current_admin = storage::get_admin(&env);
         if admin != current_admin {
             return Err(Error::Unauthorized);
         }
+        if guardian == current_admin {
+            return Err(Error::InvalidParameters);
+        }
 
-        storage::set_paused(&env, true);
-
-        // Use optimized event
-        events::emit_pause(&env, &admin);
-
+        storage::require_guardian_auth(&env);
+        storage::set_guardian(&env, &guardian);
         Ok(())
     }
 
-    /// Unpause the contract (admin only)
+    /// Remove the guardian (admin only, co-signed by the guardian)
     ///
-    /// Resumes normal operations after a pause. All previously
-    /// restricted operations become available again.
+    /// Admin transfers go back to needing only the usual authorization.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the current admin
+    pub fn remove_guardian(env: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::require_guardian_auth(&env);
+        storage::remove_guardian(&env);
+        Ok(())
+    }
+
+    /// Get the guardian that co-signs admin transfers, if any
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        storage::get_guardian(&env)
+    }
+
+    /// Grant or revoke the approver role for an account (admin only)
     ///
-    /// # Returns
-    /// Returns `Ok(())` on success
+    /// Approvers propose and approve actions in the admin action queue.
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the admin
-    ///
-    /// # Examples
-    /// ```
-    /// // Resume operations
-    /// factory.unpause(&env, admin_address)?;
-    /// assert!(!factory.is_paused(&env));
-    /// ```
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_approver(
+        env: Env,
+        admin: Address,
+        account: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
         admin.require_auth();
 
-        // Combined verification (Phase 1 optimization)
         let current_admin = storage::get_admin(&env);
         if admin != current_admin {
             return Err(Error::Unauthorized);
         }
 
-        storage::set_paused(&env, false);
+        storage::set_approver(&env, &account, enabled);
+        Ok(())
+    }
 
-        // Use optimized event
-        events::emit_unpause(&env, &admin);
+    /// Check whether an account holds the approver role
+    pub fn is_approver(env: Env, account: Address) -> bool {
+        storage::is_approver(&env, &account)
+    }
+
+    /// Set how many approvals a queued admin action needs (admin only)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - Quorum is zero
+    pub fn set_action_quorum(env: Env, admin: Address, quorum: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if quorum == 0 {
+            return Err(Error::InvalidParameters);
+        }
 
+        storage::set_action_quorum(&env, quorum);
         Ok(())
     }
 
-    /// Check if contract is currently paused
+    /// Get how many approvals a queued admin action needs (default 1)
+    pub fn get_action_quorum(env: Env) -> u32 {
+        storage::get_action_quorum(&env)
+    }
+
+    /// Queue an admin action for approval (approvers only)
     ///
-    /// Returns the current pause state of the contract.
+    /// The proposal counts as the proposer's approval. See `approvals`.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
+    /// # Errors
+    /// * `Error::Unauthorized` - Proposer is not an approver
+    /// * `Error::InvalidParameters` - The action would be rejected
+    pub fn propose_action(
+        env: Env,
+        proposer: Address,
+        action: types::AdminAction,
+    ) -> Result<u64, Error> {
+        approvals::propose_action(&env, &proposer, action)
+    }
+
+    /// Approve a queued admin action (approvers only)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Approver does not hold the approver role
+    /// * `Error::ProposalNotFound` - No request with this ID
+    /// * `Error::ChangeAlreadyExecuted` - The request already ran
+    /// * `Error::AlreadyVoted` - Approver already signed off
+    pub fn approve_action(env: Env, approver: Address, id: u64) -> Result<(), Error> {
+        approvals::approve_action(&env, &approver, id)
+    }
+
+    /// Execute a queued admin action that reached the quorum
+    ///
+    /// Anyone can call this once enough approvers have signed off.
+    ///
+    /// # Errors
+    /// * `Error::ProposalNotFound` - No request with this ID
+    /// * `Error::ChangeAlreadyExecuted` - The request already ran
+    /// * `Error::QuorumNotMet` - Not enough current approvers signed off
+    /// * `Error::InvalidParameters` - The action is no longer valid
+    pub fn execute_action(env: Env, id: u64) -> Result<(), Error> {
+        approvals::execute_action(&env, id)
+    }
+
+    /// Get a queued admin action request
+    pub fn get_action(env: Env, id: u64) -> Option<types::ActionRequest> {
+        storage::get_action_request(&env, id)
+    }
+
+    /// Pause the contract (admin only)
+    ///
+    /// Halts critical operations like token creation and metadata updates.
+    /// Admin functions like fee updates remain operational during pause.
+    /// This is a safety mechanism for emergency situations.
+    ///
+    /// Pausing an already paused contract emits no event and returns
+    /// `false`; it only clears any expiry set by `pause_with_timeout`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    ///
+    /// # Returns
+    /// Returns `Ok(true)` if the contract was paused, `Ok(false)` if it
+    /// already was
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    ///
+    /// # Examples
+    /// ```
+    /// // Emergency pause
+    /// assert!(factory.pause(&env, admin_address)?);
+    /// assert!(factory.is_paused(&env));
+    /// ```
+    pub fn pause(env: Env, admin: Address) -> Result<bool, Error> {
+        admin.require_auth();
+
+        // Combined verification (Phase 1 optimization)
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if storage::is_paused(&env) {
+            storage::set_paused(&env, true);
+            return Ok(false);
+        }
+
+        storage::set_paused(&env, true);
+
+        // Use optimized event
+        events::emit_pause(&env, &admin);
+
+        Ok(true)
+    }
+
+    /// Unpause the contract (admin only)
+    ///
+    /// Resumes normal operations after a pause. All previously
+    /// restricted operations become available again.
+    ///
+    /// Unpausing a contract that is not paused is a no-op: nothing is
+    /// stored, no event is emitted and `false` is returned.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    ///
+    /// # Returns
+    /// Returns `Ok(true)` if the contract was unpaused, `Ok(false)` if it
+    /// was not paused
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    ///
+    /// # Examples
+    /// ```
+    /// // Resume operations
+    /// assert!(factory.unpause(&env, admin_address)?);
+    /// assert!(!factory.is_paused(&env));
+    /// ```
+    pub fn unpause(env: Env, admin: Address) -> Result<bool, Error> {
+        admin.require_auth();
+
+        // Combined verification (Phase 1 optimization)
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if !storage::is_paused(&env) {
+            return Ok(false);
+        }
+
+        storage::set_paused(&env, false);
+
+        // Use optimized event
+        events::emit_unpause(&env, &admin);
+
+        Ok(true)
+    }
+
+    /// Pause the contract until a ledger sequence (admin only)
+    ///
+    /// Works like `pause`, but the pause lifts on its own once the ledger
+    /// sequence reaches `until_ledger`, so a lost admin key cannot leave
+    /// the contract paused forever. Calling it while already paused sets
+    /// the expiry of the current pause. `pause` and `unpause` clear any
+    /// expiry.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `until_ledger` - Ledger sequence at which the pause ends
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `until_ledger` is not in the future
+    pub fn pause_with_timeout(env: Env, admin: Address, until_ledger: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if until_ledger <= env.ledger().sequence() {
+            return Err(Error::InvalidParameters);
+        }
+
+        if !storage::is_paused(&env) {
+            storage::set_paused(&env, true);
+            events::emit_pause(&env, &admin);
+        }
+        storage::set_pause_expiry(&env, until_ledger);
+
+        events::emit_pause_expiry(&env, &admin, until_ledger);
+
+        Ok(())
+    }
+
+    /// Get the ledger sequence at which the current pause ends
+    ///
+    /// # Returns
+    /// `Some(u32)` while paused with a timeout, `None` otherwise
+    pub fn get_pause_expiry(env: Env) -> Option<u32> {
+        if !storage::is_pause_active(&env) {
+            return None;
+        }
+        storage::get_pause_expiry(&env)
+    }
+
+    /// Check if contract is currently paused
+    ///
+    /// Returns the current pause state of the contract.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
     ///
     /// # Returns
     /// Returns `true` if paused, `false` if operational
@@ -408,7 +935,44 @@ impl TokenFactory {
     /// }
     /// ```
     pub fn is_paused(env: Env) -> bool {
-        storage::is_paused(&env)
+        storage::is_pause_active(&env)
+    }
+
+    /// Permanently disable token creation (admin only)
+    ///
+    /// A one-way kill switch: `create_token`, batch creation and creation
+    /// escrows are rejected with `Error::CreationDisabled` from then on.
+    /// Existing tokens stay fully operable (burns, minting, metadata) and
+    /// admin operations keep working. There is no way to re-enable creation.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::CreationDisabled` - Creation was already shut down
+    pub fn emergency_shutdown(env: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if storage::is_creation_disabled(&env) {
+            return Err(Error::CreationDisabled);
+        }
+
+        storage::set_creation_disabled(&env);
+        events::emit_emergency_shutdown(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Check whether token creation has been permanently disabled
+    pub fn is_creation_disabled(env: Env) -> bool {
+        storage::is_creation_disabled(&env)
     }
 
     /// Update fee structure (admin only)
@@ -457,22 +1021,19 @@ impl TokenFactory {
         }
 
         // Validate fees before updating (Phase 1 optimization)
+        fees::validate_fee_amounts(base_fee, metadata_fee)?;
+
         if let Some(fee) = base_fee {
-            if fee < 0 {
-                return Err(Error::InvalidParameters);
-            }
             storage::set_base_fee(&env, fee);
         }
 
         if let Some(fee) = metadata_fee {
-            if fee < 0 {
-                return Err(Error::InvalidParameters);
-            }
             storage::set_metadata_fee(&env, fee);
         }
 
         // Validate fees after update
         validation::validate_fees(&env)?;
+        fees::record_fee_change(&env);
 
         // Get updated fees for event
         let new_base_fee = base_fee.unwrap_or_else(|| storage::get_base_fee(&env));
@@ -483,6 +1044,125 @@ impl TokenFactory {
         Ok(())
     }
 
+    /// Update both deployment fees, given in whole XLM
+    ///
+    /// Converts each fee to stroops and applies it as `update_fees` would.
+    ///
+    /// # Errors
+    /// * `Error::ArithmeticError` - A fee overflows when scaled to stroops
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Any fee is negative
+    pub fn update_fees_xlm(
+        env: Env,
+        admin: Address,
+        base_xlm: i128,
+        metadata_xlm: i128,
+    ) -> Result<(), Error> {
+        let base_fee = fees::xlm_to_stroops(base_xlm)?;
+        let metadata_fee = fees::xlm_to_stroops(metadata_xlm)?;
+        Self::update_fees(env, admin, Some(base_fee), Some(metadata_fee))
+    }
+
+    /// Replace the supply-tiered creation surcharges (admin only)
+    ///
+    /// Each tier is `(supply_threshold, extra_fee)`. A token whose initial
+    /// supply exceeds a threshold pays that tier's extra fee on top of the
+    /// base and metadata fees; when several match, the highest threshold
+    /// wins. An empty list removes the surcharge.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - More than 10 tiers, thresholds not
+    ///   strictly ascending, or a negative threshold or fee
+    pub fn set_supply_fee_tiers(
+        env: Env,
+        admin: Address,
+        tiers: Vec<(i128, i128)>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        fees::validate_supply_fee_tiers(&tiers)?;
+        storage::set_supply_fee_tiers(&env, &tiers);
+        Ok(())
+    }
+
+    /// Get the supply-tiered creation surcharges
+    pub fn get_supply_fee_tiers(env: Env) -> Vec<(i128, i128)> {
+        storage::get_supply_fee_tiers(&env)
+    }
+
+    /// Set the fee charged for an ongoing operation (admin only)
+    ///
+    /// Each mint, transfer or metadata edit of the given kind then charges
+    /// its caller `fee`, collected like a creation fee. 0 (the default)
+    /// makes the operation free.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Fee is negative
+    pub fn set_op_fee(
+        env: Env,
+        admin: Address,
+        op_kind: types::OpKind,
+        fee: i128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        fees::validate_fee_amounts(Some(fee), None)?;
+        storage::set_op_fee(&env, op_kind, fee);
+        Ok(())
+    }
+
+    /// Get the fee charged for an ongoing operation (0 when free)
+    pub fn get_op_fee(env: Env, op_kind: types::OpKind) -> i128 {
+        storage::get_op_fee(&env, op_kind)
+    }
+
+    /// Get up to `limit` past fee changes, newest first
+    ///
+    /// Each record holds the fees in effect after a change and when it was
+    /// made. Only the last `fees::MAX_FEE_HISTORY` changes are kept.
+    pub fn get_fee_history(env: Env, limit: u32) -> Vec<types::FeeRecord> {
+        fees::get_fee_history(&env, limit)
+    }
+
+    /// Get `(base_fee, metadata_fee)` in whole XLM, truncated
+    pub fn get_fees_xlm(env: Env) -> (i128, i128) {
+        (
+            fees::stroops_to_xlm(storage::get_base_fee(&env)),
+            fees::stroops_to_xlm(storage::get_metadata_fee(&env)),
+        )
+    }
+
+    /// Quote the deployment fee in the units of a token with `decimals`
+    ///
+    /// A display convenience for front-ends: the base fee `creator` would
+    /// pay (0 if fee exempt), rescaled from stroops to `decimals` decimal
+    /// places assuming one whole token is worth one XLM. This is not a
+    /// price oracle; the fee is always charged in the fee asset.
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - `decimals` is above 18
+    /// * `Error::ArithmeticError` - The scaled fee overflows
+    pub fn quote_fee_in_token(env: Env, creator: Address, decimals: u32) -> Result<i128, Error> {
+        let fee = if fees::is_fee_exempt(&env, &creator) {
+            0
+        } else {
+            storage::get_base_fee(&env)
+        };
+        fees::scale_to_decimals(fee, decimals)
+    }
+
     /// Get token info by index
     pub fn get_token_info(env: Env, index: u32) -> Result<TokenInfo, Error> {
         let mut info = storage::get_token_info(&env, index).ok_or(Error::TokenNotFound)?;
@@ -490,6 +1170,28 @@ impl TokenFactory {
         Ok(info)
     }
 
+    /// Get token info by index, saying why a lookup failed
+    ///
+    /// Unlike `get_token_info`, archived tokens are reported as errors so
+    /// clients can tell them apart from live ones.
+    ///
+    /// # Errors
+    /// * `Error::TokenIndexOutOfRange` - Index is at or past the token count
+    /// * `Error::TokenArchived` - Token at the index was archived
+    /// * `Error::TokenNotYetCreated` - Index is reserved but nothing is stored
+    ///   there yet
+    pub fn get_token_info_detailed(env: Env, index: u32) -> Result<TokenInfo, Error> {
+        if index >= storage::get_token_count(&env) {
+            return Err(Error::TokenIndexOutOfRange);
+        }
+        if storage::is_token_archived(&env, index) {
+            return Err(Error::TokenArchived);
+        }
+        let mut info = storage::get_token_info(&env, index).ok_or(Error::TokenNotYetCreated)?;
+        info.is_paused = storage::is_token_paused(&env, index);
+        Ok(info)
+    }
+
     /// Batch update admin operations (Phase 2 optimization)
     ///
     /// Updates multiple admin parameters in a single transaction,
@@ -502,13 +1204,20 @@ impl TokenFactory {
     /// * `base_fee` - Optional new base fee in stroops (None = no change)
     /// * `metadata_fee` - Optional new metadata fee in stroops (None = no change)
     /// * `paused` - Optional new pause state (None = no change)
+    /// * `treasury` - Optional new treasury address (None = no change)
+    /// * `role_change` - Optional `(account, role, grant)` to grant (`true`)
+    ///   or revoke (`false`) a role (None = no change)
+    ///
+    /// All inputs are validated before any storage write, so a rejected
+    /// call leaves every field unchanged.
     ///
     /// # Returns
     /// Returns `Ok(())` on success
     ///
     /// # Errors
     /// * `Error::Unauthorized` - Caller is not the admin
-    /// * `Error::InvalidParameters` - All parameters are None or any fee is negative
+    /// * `Error::InvalidParameters` - All parameters are None, any fee is
+    ///   negative, or the treasury is the factory itself or a factory token
     ///
     /// # Gas Savings
     /// - Batch both fee updates: -2,000 to 3,000 CPU instructions
@@ -524,6 +1233,8 @@ impl TokenFactory {
     ///     Some(2_000_000),
     ///     Some(1_000_000),
     ///     Some(true),
+    ///     None,
+    ///     None,
     /// )?;
     /// ```
     pub fn batch_update_admin(
@@ -532,6 +1243,8 @@ impl TokenFactory {
         base_fee: Option<i128>,
         metadata_fee: Option<i128>,
         paused: Option<bool>,
+        treasury: Option<Address>,
+        role_change: Option<(Address, types::Role, bool)>,
     ) -> Result<(), Error> {
         admin.require_auth();
 
@@ -542,22 +1255,31 @@ impl TokenFactory {
         }
 
         // Early return if no changes
-        if base_fee.is_none() && metadata_fee.is_none() && paused.is_none() {
+        if base_fee.is_none()
+            && metadata_fee.is_none()
+            && paused.is_none()
+            && treasury.is_none()
+            && role_change.is_none()
+        {
             return Err(Error::InvalidParameters);
         }
 
         // Validate all inputs before any storage writes (Phase 2 optimization)
-        if let Some(fee) = base_fee {
-            if fee < 0 {
+        fees::validate_fee_amounts(base_fee, metadata_fee)?;
+        if let Some(new_treasury) = &treasury {
+            // Contract-controlled addresses could never withdraw the fees
+            if *new_treasury == env.current_contract_address()
+                || storage::get_token_info_by_address(&env, new_treasury).is_some()
+            {
                 return Err(Error::InvalidParameters);
             }
+        }
+
+        if let Some(fee) = base_fee {
             storage::set_base_fee(&env, fee);
         }
 
         if let Some(fee) = metadata_fee {
-            if fee < 0 {
-                return Err(Error::InvalidParameters);
-            }
             storage::set_metadata_fee(&env, fee);
         }
 
@@ -565,8 +1287,24 @@ impl TokenFactory {
             storage::set_paused(&env, pause_state);
         }
 
+        if let Some(new_treasury) = &treasury {
+            storage::set_treasury(&env, new_treasury);
+            events::emit_treasury_updated(&env, new_treasury);
+        }
+
+        if let Some((account, role, grant)) = &role_change {
+            match role {
+                types::Role::FeeExempt => storage::set_fee_exempt(&env, account, *grant),
+                types::Role::Approver => storage::set_approver(&env, account, *grant),
+            }
+            events::emit_role_updated(&env, account, *role, *grant);
+        }
+
         // Validate fees after update
         validation::validate_fees(&env)?;
+        if base_fee.is_some() || metadata_fee.is_some() {
+            fees::record_fee_change(&env);
+        }
 
         // Get final state for event
         let final_base_fee = storage::get_base_fee(&env);
@@ -602,314 +1340,1985 @@ impl TokenFactory {
         storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)
     }
 
-    /// * `symbol` - Token symbol
-    /// * `decimals` - Number of decimal places
-    /// * `initial_supply` - Initial token supply
-    /// * `fee_payment` - Fee amount (must be >= base_fee)
-    /// Toggle clawback capability for a token (creator only)
+    /// Get token information for several token addresses at once
     ///
-    /// Allows the token creator to enable or disable clawback functionality.
-    /// When enabled, the creator can burn tokens from any holder's address.
-    /// This setting can be toggled multiple times by the creator.
+    /// Results line up with `addresses`; an address the factory did not
+    /// create yields `None` in its slot.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `token_address` - The token's contract address
-    /// * `admin` - Token creator address (must authorize and match creator)
-    /// * `enabled` - True to enable clawback, false to disable
+    /// # Errors
+    /// * `Error::BatchTooLarge` - More than 50 addresses
+    pub fn get_token_infos_by_address(
+        env: Env,
+        addresses: Vec<Address>,
+    ) -> Result<Vec<Option<TokenInfo>>, Error> {
+        if addresses.len() > MAX_ADDRESS_LOOKUPS {
+            return Err(Error::BatchTooLarge);
+        }
+
+        let mut infos = Vec::new(&env);
+        for address in addresses.iter() {
+            infos.push_back(storage::get_token_info_by_address(&env, &address));
+        }
+        Ok(infos)
+    }
+
+    /// Get the decimals of several tokens at once
     ///
-    /// # Returns
-    /// Returns `Ok(())` on success
+    /// Returns `(address, decimals)` pairs in request order, for front-ends
+    /// that only need decimals to render balances. Addresses the factory
+    /// did not create are left out.
     ///
     /// # Errors
-    /// * `Error::ContractPaused` - Contract is currently paused
-    /// * `Error::TokenNotFound` - Token address not found
-    /// * `Error::Unauthorized` - Caller is not the token creator
-    ///
-    /// # Examples
-    /// ```
-    /// // Enable clawback for emergency situations
-    /// factory.set_clawback(&env, token_addr, creator, true)?;
-    ///
-    /// // Disable clawback for decentralization
-    /// factory.set_clawback(&env, token_addr, creator, false)?;
-    /// ```
-    pub fn set_clawback(
+    /// * `Error::BatchTooLarge` - More than 50 addresses
+    pub fn get_decimals_map(
         env: Env,
-        token_address: Address,
-        admin: Address,
-        enabled: bool,
-    ) -> Result<(), Error> {
-        // Early return if contract is paused (Phase 1 optimization)
-        if storage::is_paused(&env) {
-            return Err(Error::ContractPaused);
+        addresses: Vec<Address>,
+    ) -> Result<Vec<(Address, u32)>, Error> {
+        if addresses.len() > MAX_ADDRESS_LOOKUPS {
+            return Err(Error::BatchTooLarge);
         }
 
-        // Require admin authorization
-        admin.require_auth();
-
-        // Get token info
-        let mut token_info =
-            storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)?;
-
-        // Verify admin is the token creator
-        if token_info.creator != admin {
-            return Err(Error::Unauthorized);
+        let mut decimals = Vec::new(&env);
+        for address in addresses.iter() {
+            if let Some(info) = storage::get_token_info_by_address(&env, &address) {
+                decimals.push_back((address, info.decimals));
+            }
         }
-
-        // Update clawback setting
-        token_info.clawback_enabled = enabled;
-        storage::set_token_info_by_address(&env, &token_address, &token_info);
-
-        // Emit optimized event
-        events::emit_clawback_toggled(&env, &token_address, &admin, enabled);
-        Ok(())
+        Ok(decimals)
     }
 
-    /// Burn tokens from caller's own balance
-    ///
-    /// Allows a token holder to permanently destroy tokens from their
-    /// own balance, reducing the total supply.
+    /// Get the kind of a token
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `caller` - Address burning tokens (must authorize)
-    /// * `token_index` - Index of the token to burn
-    /// * `amount` - Amount to burn (must be > 0 and <= balance)
+    /// * `token_address` - The token's contract address
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// Returns `Ok(TokenKind)` recorded at creation
     ///
     /// # Errors
-    /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::InvalidParameters` - Amount is zero or negative
-    /// * `Error::InsufficientBalance` - Caller balance is less than amount
-    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    /// * `Error::TokenNotFound` - Token address not found in registry
     ///
     /// # Examples
     /// ```
-    /// // Burn 1000 tokens
-    /// factory.burn(&env, caller, 0, 1_000_0000000)?;
+    /// let kind = factory.get_token_kind(&env, token_addr)?;
+    /// assert_eq!(kind, TokenKind::NonFungible);
     /// ```
-    pub fn burn(env: Env, caller: Address, token_index: u32, amount: i128) -> Result<(), Error> {
-        burn::burn(&env, caller, token_index, amount)
+    pub fn get_token_kind(env: Env, token_address: Address) -> Result<TokenKind, Error> {
+        storage::get_token_info_by_address(&env, &token_address)
+            .map(|info| info.token_kind)
+            .ok_or(Error::TokenNotFound)
     }
 
-    /// Batch burn tokens from multiple holders (admin only)
+    /// Get token information by symbol
     ///
-    /// Allows the admin to burn tokens from multiple addresses in a single
-    /// transaction. All burns must succeed or the entire batch fails.
+    /// Resolves the symbol through the symbol registry. The registry is only
+    /// authoritative while unique symbols are enabled, so lookups are
+    /// refused otherwise.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
-    /// * `token_index` - Index of the token to burn
-    /// * `burns` - Vector of (holder_address, amount) tuples (max 100 entries)
+    /// * `symbol` - The token symbol
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// Returns `Ok(TokenInfo)` with token details
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the admin
-    /// * `Error::BatchTooLarge` - More than 100 burn entries
-    /// * `Error::InvalidParameters` - Empty batch or invalid amounts
-    /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::InsufficientBalance` - Any holder has insufficient balance
-    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    /// * `Error::UniqueSymbolsDisabled` - Unique symbol mode is off
+    /// * `Error::TokenNotFound` - No token is registered under the symbol
     ///
     /// # Examples
     /// ```
-    /// let burns = vec![
-    ///     &env,
-    ///     (holder1, 1_000_0000000),
-    ///     (holder2, 2_000_0000000),
-    /// ];
-    /// factory.batch_burn(&env, admin, 0, burns)?;
+    /// let token = factory.get_token_by_symbol(&env, "XYZ")?;
     /// ```
-    pub fn batch_burn(
-        env: Env,
-        admin: Address,
-        token_index: u32,
-        burns: soroban_sdk::Vec<(Address, i128)>,
-    ) -> Result<(), Error> {
-        burn::batch_burn(&env, admin, token_index, burns)
+    pub fn get_token_by_symbol(env: Env, symbol: String) -> Result<TokenInfo, Error> {
+        if !storage::is_unique_symbols_enabled(&env) {
+            return Err(Error::UniqueSymbolsDisabled);
+        }
+        let symbol = token_creation::canonical_symbol(&env, &symbol);
+        let token_address = storage::get_symbol_owner(&env, &symbol).ok_or(Error::TokenNotFound)?;
+        let index = storage::get_token_index(&env, &token_address).ok_or(Error::TokenNotFound)?;
+        Self::get_token_info(env, index)
     }
 
-    /// Get the total number of burn operations for a token
+    /// Enable or disable unique token symbols (admin only)
     ///
-    /// Returns the count of all burn operations (both user and admin burns)
-    /// performed on the specified token.
+    /// While enabled, creating a token whose symbol is already registered
+    /// fails with `Error::SymbolTaken`, and new tokens are recorded in the
+    /// symbol registry. Tokens created while disabled are not registered.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_index` - Index of the token
-    ///
-    /// # Returns
-    /// Returns the burn count as a u32
+    /// * `admin` - Current admin address (must authorize)
+    /// * `enabled` - True to require unique symbols
     ///
-    /// # Examples
-    /// ```
-    /// let burn_count = factory.get_burn_count(&env, 0);
-    /// assert!(burn_count > 0);
-    /// ```
-    pub fn get_burn_count(env: Env, token_index: u32) -> u32 {
-        burn::get_burn_count(&env, token_index)
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_unique_symbols(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_unique_symbols_enabled(&env, enabled);
+        Ok(())
     }
 
-    /// Admin-initiated burn from any holder's balance
-    ///
-    /// Allows the admin to burn tokens from any holder's address.
-    /// This is a privileged operation that requires admin authentication.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
-    /// * `token_index` - Index of the token to burn
-    /// * `holder` - Address holding the tokens to burn
-    /// * `amount` - Amount to burn (must be > 0 and <= holder's balance)
+    /// Set the largest raw initial supply a new token may have (admin only)
     ///
-    /// # Returns
-    /// Returns `Ok(())` on success
+    /// Measured in raw units regardless of decimals. Creation rejects a
+    /// larger `initial_supply` with `Error::InvalidParameters`. Defaults to
+    /// `i128::MAX / 2`, leaving headroom for later mints.
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the admin
-    /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::InvalidParameters` - Amount is zero or negative
-    /// * `Error::InsufficientBalance` - Holder balance is less than amount
-    /// * `Error::ArithmeticError` - Numeric overflow/underflow
-    ///
-    /// # Examples
-    /// ```
-    /// // Admin burns 1000 tokens from a holder
-    /// factory.admin_burn(&env, admin, 0, holder, 1_000_0000000)?;
-    /// ```
-    pub fn admin_burn(
-        env: Env,
-        admin: Address,
-        token_index: u32,
-        holder: Address,
-        amount: i128,
-    ) -> Result<(), Error> {
-        burn::admin_burn(&env, admin, token_index, holder, amount)
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - `max` is zero or negative
+    pub fn set_max_raw_supply(env: Env, admin: Address, max: i128) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if max <= 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_max_raw_supply(&env, max);
+        Ok(())
     }
 
-    /// Set metadata URI for a token (one-time only)
-    ///
-    /// Allows the token creator to set an IPFS metadata URI for their token.
-    /// This operation can only be performed once per token - metadata is
-    /// immutable after being set to ensure data integrity and trust.
-    ///
-    /// # Mutability Rules
-    /// - Metadata can only be set if it's currently `None`
-    /// - Once set, metadata cannot be changed or removed
-    /// - This ensures permanent, tamper-proof token metadata
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `token_index` - Index of the token to update
-    /// * `admin` - Token creator address (must authorize and match creator)
-    /// * `metadata_uri` - IPFS URI for token metadata (e.g., "ipfs://Qm...")
+    /// Get the largest raw initial supply a new token may have
+    pub fn get_max_raw_supply(env: Env) -> i128 {
+        storage::get_max_raw_supply(&env)
+    }
+
+    /// Enable or disable symbol normalization (admin only)
     ///
-    /// # Returns
-    /// Returns `Ok(())` on success
+    /// While enabled, symbols are ASCII-uppercased at creation, so the
+    /// stored `TokenInfo::symbol` is canonical. The unique-symbol registry,
+    /// reserved symbols and `get_token_by_symbol` then match regardless of
+    /// case. Tokens created earlier keep their symbols.
     ///
     /// # Errors
-    /// * `Error::ContractPaused` - Contract is currently paused
-    /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::Unauthorized` - Caller is not the token creator
-    /// * `Error::MetadataAlreadySet` - Metadata has already been set (immutable)
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_normalize_symbols(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_normalize_symbols_enabled(&env, enabled);
+        Ok(())
+    }
+
+    /// Check whether symbols are uppercased at creation
+    pub fn is_normalize_symbols_enabled(env: Env) -> bool {
+        storage::is_normalize_symbols_enabled(&env)
+    }
+
+    /// Cap how many tokens can be created per ledger window (admin only)
     ///
-    /// # Examples
-    /// ```
-    /// // Set metadata for the first time
-    /// let metadata_uri = String::from_str(&env, "ipfs://QmTest123");
-    /// factory.set_metadata(&env, 0, creator, metadata_uri)?;
+    /// Windows are 12 ledgers long. Once `cap` tokens have been created in
+    /// the current window, further creations fail with
+    /// `Error::CreateRateLimited` until the next window starts. Every token
+    /// in a batch counts. A cap of 0 removes the limit (the default).
     ///
-    /// // Attempting to change metadata will fail
-    /// let new_uri = String::from_str(&env, "ipfs://QmTest456");
-    /// let result = factory.set_metadata(&env, 0, creator, new_uri);
-    /// assert_eq!(result, Err(Error::MetadataAlreadySet));
-    /// ```
-    pub fn set_metadata(
-        env: Env,
-        creator: Address,
-        tokens: Vec<TokenCreationParams>,
-        total_fee_payment: i128,
-    ) -> Result<Vec<Address>, Error> {
-        token_creation::batch_create_tokens(&env, creator, tokens, total_fee_payment)
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_window_create_cap(env: Env, admin: Address, cap: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_window_create_cap(&env, cap);
+        Ok(())
     }
 
-    /// Set metadata for a token
-    /// 
-    /// Allows the token creator to set metadata URI once
-    pub fn set_token_metadata(
+    /// Get the per-window creation cap (0 = no cap)
+    pub fn get_window_create_cap(env: Env) -> u32 {
+        storage::get_window_create_cap(&env)
+    }
+
+    /// Require creators to hold a minimum native XLM balance (admin only)
+    ///
+    /// Deters throwaway accounts: token creation, batch creation and
+    /// opening a creation escrow fail with `Error::InsufficientCreatorBalance`
+    /// while the creator's balance, read from `native_asset`, is below
+    /// `min_balance`. `native_asset` must be the network's native XLM Stellar
+    /// Asset Contract. A minimum of 0 disables the check (the default).
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - `min_balance` is negative
+    pub fn set_min_creator_balance(
         env: Env,
         admin: Address,
-        token_index: u32,
-        metadata_uri: String,
+        native_asset: Address,
+        min_balance: i128,
     ) -> Result<(), Error> {
-        // Require admin authorization
         admin.require_auth();
 
-        // Get token info
-        let mut token_info =
-            storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
-
-        // Verify admin is the token creator
-        if token_info.creator != admin {
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
             return Err(Error::Unauthorized);
         }
 
-        // Enforce immutability: metadata can only be set once
-        if token_info.metadata_uri.is_some() {
-            return Err(Error::MetadataAlreadySet);
+        if min_balance < 0 {
+            return Err(Error::InvalidParameters);
         }
 
-        // Set metadata URI
-        token_info.metadata_uri = Some(metadata_uri.clone());
-        storage::set_token_info(&env, token_index, &token_info);
-
-        // Also update by address lookup
-        storage::set_token_info_by_address(&env, &token_info.address, &token_info);
-
-        // Emit metadata set event
-        events::emit_metadata_set(&env, &token_info.address, &admin, &metadata_uri);
-
+        if min_balance == 0 {
+            storage::remove_min_creator_balance(&env);
+        } else {
+            storage::set_min_creator_balance(&env, &native_asset, min_balance);
+        }
         Ok(())
     }
 
-    pub fn pause_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
+    /// Get the native asset contract and minimum creator balance, if set
+    pub fn get_min_creator_balance(env: Env) -> Option<(Address, i128)> {
+        storage::get_min_creator_balance(&env)
+    }
+
+    /// Reserve a symbol so no new token can use it (admin only)
+    ///
+    /// Guards against tokens impersonating major assets. Creation, batch
+    /// creation and escrowed creation reject a reserved symbol with
+    /// `Error::SymbolReserved`; existing tokens are not affected. Symbols
+    /// are matched exactly.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidSymbol` - Symbol is empty
+    pub fn add_reserved_symbol(env: Env, admin: Address, symbol: String) -> Result<(), Error> {
         admin.require_auth();
-        if admin != storage::get_admin(&env) {
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
             return Err(Error::Unauthorized);
         }
-        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
-        storage::set_token_paused(&env, token_index, true);
+
+        if symbol.is_empty() {
+            return Err(Error::InvalidSymbol);
+        }
+
+        storage::set_symbol_reserved(&env, &symbol, true);
         Ok(())
     }
 
-    pub fn unpause_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
+    /// Remove a symbol from the reserved list (admin only)
+    ///
+    /// Removing a symbol that was never reserved is a no-op.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn remove_reserved_symbol(env: Env, admin: Address, symbol: String) -> Result<(), Error> {
         admin.require_auth();
-        if admin != storage::get_admin(&env) {
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
             return Err(Error::Unauthorized);
         }
-        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
-        storage::set_token_paused(&env, token_index, false);
+
+        storage::set_symbol_reserved(&env, &symbol, false);
         Ok(())
     }
 
-    pub fn is_token_paused(env: Env, token_index: u32) -> bool {
-        storage::is_token_paused(&env, token_index)
+    /// Check whether a symbol is reserved
+    pub fn is_symbol_reserved(env: Env, symbol: String) -> bool {
+        storage::is_symbol_reserved(&env, &symbol)
     }
 
-    /// Return a compact stats snapshot for a token
-    pub fn get_token_stats(env: Env, token_index: u32) -> Result<TokenStats, Error> {
-        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
-
-        Ok(TokenStats {
-            current_supply: storage::get_token_info(&env, token_index)
-                .map(|i| i.total_supply)
-                .unwrap_or(0),
+    /// Set the maximum metadata URI length (admin only)
+    ///
+    /// Token creation and `set_token_metadata` reject URIs longer than this
+    /// with `Error::InvalidParameters`. Defaults to 256.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    /// * `len` - New maximum length (must be > 0)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - `len` is zero
+    pub fn set_max_uri_length(env: Env, admin: Address, len: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if len == 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_max_uri_length(&env, len);
+        Ok(())
+    }
+
+    /// Get the maximum metadata URI length
+    pub fn get_max_uri_length(env: Env) -> u32 {
+        storage::get_max_uri_length(&env)
+    }
+
+    /// Set the minimum decimals for new tokens (admin only)
+    ///
+    /// Lets issuers of monetary tokens enforce a decimals floor. Token
+    /// creation rejects fewer decimals with `Error::InvalidParameters`;
+    /// the 18-decimal ceiling still applies. Defaults to 0.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    /// * `min` - New decimals floor (at most 18)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - `min` is above 18
+    pub fn set_min_decimals(env: Env, admin: Address, min: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if min > 18 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_min_decimals(&env, min);
+        Ok(())
+    }
+
+    /// Get the minimum decimals for new tokens
+    pub fn get_min_decimals(env: Env) -> u32 {
+        storage::get_min_decimals(&env)
+    }
+
+    /// Set the clock used to stamp `created_at` on new tokens (admin only)
+    ///
+    /// `Timestamp` (the default) records the ledger close time; `Sequence`
+    /// records the ledger sequence. Each token keeps the source it was
+    /// created under in `created_at_source`; existing tokens are unchanged.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_time_source(env: Env, admin: Address, source: TimeSource) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_time_source(&env, source);
+        Ok(())
+    }
+
+    /// Get the clock used to stamp `created_at` on new tokens
+    pub fn get_time_source(env: Env) -> TimeSource {
+        storage::get_time_source(&env)
+    }
+
+    /// Enable or disable the lightweight creation topic (admin only)
+    ///
+    /// While enabled, every token creation emits a small `tok_ping` event
+    /// with just the token address and index, right after `tok_crt`.
+    /// Disabled by default.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_webhook_topic(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_webhook_topic_enabled(&env, enabled);
+        Ok(())
+    }
+
+    /// Check whether token creation emits the `tok_ping` event
+    pub fn is_webhook_topic_enabled(env: Env) -> bool {
+        storage::is_webhook_topic_enabled(&env)
+    }
+
+    /// Let the admin create tokens without paying the creation fee (admin only)
+    ///
+    /// Enabled by default. While enabled, `create_token` called by the
+    /// current admin charges nothing and leaves any waiver code unused.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_admin_fee_exempt(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_admin_fee_exempt(&env, enabled);
+        Ok(())
+    }
+
+    /// Check whether the admin's own deployments are fee exempt
+    pub fn is_admin_fee_exempt(env: Env) -> bool {
+        storage::is_admin_fee_exempt(&env)
+    }
+
+    /// Grant or revoke the fee exemption role for an account (admin only)
+    ///
+    /// Holders of the role create tokens through `create_token` without
+    /// paying the creation fee, independently of `set_admin_fee_exempt`.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_fee_exempt(
+        env: Env,
+        admin: Address,
+        account: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_fee_exempt(&env, &account, exempt);
+        Ok(())
+    }
+
+    /// Check whether an account holds the fee exemption role
+    pub fn is_fee_exempt(env: Env, account: Address) -> bool {
+        storage::is_fee_exempt(&env, &account)
+    }
+
+    /// Set the factory-wide supply cap (admin only)
+    ///
+    /// Bounds the combined supply of all factory tokens. Creations and
+    /// mints that would take the total above the cap fail with
+    /// `Error::GlobalCapExceeded`; burns free up room again. A cap below
+    /// the current total blocks further issuance but touches no balances.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    /// * `cap` - New cap, or `None` for unlimited
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - `cap` is negative
+    pub fn set_global_supply_cap(env: Env, admin: Address, cap: Option<i128>) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if cap.is_some_and(|cap| cap < 0) {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_global_supply_cap(&env, cap);
+        Ok(())
+    }
+
+    /// Get the factory-wide supply cap (`None` = unlimited)
+    pub fn get_global_supply_cap(env: Env) -> Option<i128> {
+        storage::get_global_supply_cap(&env)
+    }
+
+    /// Get the combined supply of all factory tokens
+    pub fn get_global_total_supply(env: Env) -> i128 {
+        storage::get_global_supply(&env)
+    }
+
+    /// Get the combined supply of all tokens in a common 7-decimal unit
+    ///
+    /// Each supply is scaled by `10^(7 - decimals)`. Tokens with more than
+    /// 7 decimals are scaled down and truncated toward zero, so fractions
+    /// below 10^-7 of a whole token are dropped. Reads every token.
+    ///
+    /// # Errors
+    /// * `Error::ArithmeticError` - The scaled sum overflows
+    pub fn get_weighted_supply(env: Env) -> Result<i128, Error> {
+        pagination::weighted_supply(&env)
+    }
+
+    /// Get `(total burned, burn count)` summed over every factory token
+    ///
+    /// Kept as running totals by every burn path, so this is O(1). The
+    /// count matches the sum of per-token `burn_count`: a batch burn counts
+    /// once per entry.
+    pub fn get_global_burn_stats(env: Env) -> (i128, u32) {
+        storage::get_global_burn_stats(&env)
+    }
+
+    /// Check whether unique token symbols are enforced
+    pub fn is_unique_symbols_enabled(env: Env) -> bool {
+        storage::is_unique_symbols_enabled(&env)
+    }
+
+    /// Get the token wasm hash recorded when a token was created
+    ///
+    /// Lets integrators confirm a token was created from the trusted
+    /// token wasm. Tokens keep the hash that was configured at creation
+    /// time even if the factory's hash is later changed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    ///
+    /// # Returns
+    /// Returns `Ok(BytesN<32>)` with the recorded wasm hash
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found in registry
+    ///
+    /// # Examples
+    /// ```
+    /// let hash = factory.get_token_wasm_hash(&env, token_addr)?;
+    /// assert_eq!(hash, trusted_hash);
+    /// ```
+    pub fn get_token_wasm_hash(env: Env, token_address: Address) -> Result<BytesN<32>, Error> {
+        storage::get_token_info_by_address(&env, &token_address)
+            .map(|info| info.wasm_hash)
+            .ok_or(Error::TokenNotFound)
+    }
+
+    /// Set the token wasm hash used for future deployments (admin only)
+    ///
+    /// Existing tokens keep the hash recorded at their creation.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Current admin address (must authorize)
+    /// * `hash` - Wasm hash of the token contract for new deployments
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    ///
+    /// # Examples
+    /// ```
+    /// factory.set_token_wasm_hash(&env, admin, new_hash)?;
+    /// ```
+    pub fn set_token_wasm_hash(env: Env, admin: Address, hash: BytesN<32>) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_token_wasm_hash(&env, &hash);
+        events::emit_token_wasm_hash_updated(&env, &admin, &hash);
+
+        Ok(())
+    }
+
+    /// Upgrade the factory to new wasm (admin only)
+    ///
+    /// Records `version`, the new hash and the ledger timestamp in the
+    /// version history, emits `upgraded`, then replaces the factory's own
+    /// code. The new wasm must already be uploaded.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::InvalidParameters` - Version label is empty
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+        version: String,
+    ) -> Result<(), Error> {
+        upgrade::upgrade(&env, &admin, new_wasm_hash, version)
+    }
+
+    /// Roll the factory back to the wasm the last upgrade replaced (admin only)
+    ///
+    /// Re-applies the previous wasm hash and appends its version to the
+    /// history again, emitting `rollback`. Registry state is untouched.
+    /// Only the latest upgrade can be undone, and only once.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::NoPreviousVersion` - No upgrade is available to undo
+    pub fn rollback_upgrade(env: Env, admin: Address) -> Result<(), Error> {
+        upgrade::rollback_upgrade(&env, &admin)
+    }
+
+    /// Get the most recently installed version, if any upgrade was recorded
+    pub fn get_current_version(env: Env) -> Option<types::VersionRecord> {
+        upgrade::get_current_version(&env)
+    }
+
+    /// Get up to `limit` (max 100) upgrade records from `start`, oldest first
+    pub fn get_version_history(env: Env, start: u32, limit: u32) -> Vec<types::VersionRecord> {
+        upgrade::get_version_history(&env, start, limit)
+    }
+
+    /// * `symbol` - Token symbol
+    /// * `decimals` - Number of decimal places
+    /// * `initial_supply` - Initial token supply
+    /// * `fee_payment` - Fee amount (must be >= base_fee)
+    /// Toggle clawback capability for a token (creator only)
+    ///
+    /// Allows the token creator to enable or disable clawback functionality.
+    /// When enabled, the creator can burn tokens from any holder's address.
+    /// This setting can be toggled multiple times by the creator.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `admin` - Token creator address (must authorize and match creator)
+    /// * `enabled` - True to enable clawback, false to disable
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenRenounced` - Creator renounced control of the token
+    ///
+    /// # Examples
+    /// ```
+    /// // Enable clawback for emergency situations
+    /// factory.set_clawback(&env, token_addr, creator, true)?;
+    ///
+    /// // Disable clawback for decentralization
+    /// factory.set_clawback(&env, token_addr, creator, false)?;
+    /// ```
+    pub fn set_clawback(
+        env: Env,
+        token_address: Address,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        // Require admin authorization
+        admin.require_auth();
+
+        // Early return if contract is paused (Phase 1 optimization)
+        if storage::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        // Get token info
+        let token_index =
+            storage::get_token_index(&env, &token_address).ok_or(Error::TokenNotFound)?;
+        let mut token_info =
+            storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+
+        // Verify admin is the token creator
+        if token_info.creator != admin {
+            return Err(Error::Unauthorized);
+        }
+        if storage::is_token_renounced(&env, &token_address) {
+            return Err(Error::TokenRenounced);
+        }
+        token_creation::ensure_token_unlocked(&env, &token_address)?;
+
+        // Update clawback setting
+        token_info.clawback_enabled = enabled;
+        storage::set_token_info(&env, token_index, &token_info);
+        storage::set_token_info_by_address(&env, &token_address, &token_info);
+
+        // Emit optimized event
+        events::emit_clawback_toggled(&env, &token_address, &admin, enabled);
+        Ok(())
+    }
+
+    /// Toggle clawback capability across several tokens (creator only)
+    ///
+    /// Applies the same clawback setting to every listed token in a single
+    /// transaction. The caller must be the creator of every token; if any
+    /// token is missing or owned by someone else, nothing is changed.
+    /// Emits one `clwbck_v1` event per token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize and match every token)
+    /// * `token_addresses` - Tokens to update (max 100)
+    /// * `enabled` - True to enable clawback, false to disable
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::InvalidParameters` - Token list is empty
+    /// * `Error::BatchTooLarge` - More than 100 tokens
+    /// * `Error::TokenNotFound` - A token address is not registered
+    /// * `Error::Unauthorized` - Caller is not the creator of every token
+    /// * `Error::TokenRenounced` - Creator renounced control of a token
+    ///
+    /// # Examples
+    /// ```
+    /// // Renounce clawback on the whole lineup at once
+    /// factory.bulk_set_clawback(&env, creator, token_addrs, false)?;
+    /// ```
+    pub fn bulk_set_clawback(
+        env: Env,
+        creator: Address,
+        token_addresses: Vec<Address>,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        if storage::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if token_addresses.is_empty() {
+            return Err(Error::InvalidParameters);
+        }
+        if token_addresses.len() > MAX_BULK_CLAWBACK {
+            return Err(Error::BatchTooLarge);
+        }
+
+        // Validate every token before mutating any of them
+        let mut updated: Vec<(u32, TokenInfo)> = Vec::new(&env);
+        for token_address in token_addresses.iter() {
+            let index =
+                storage::get_token_index(&env, &token_address).ok_or(Error::TokenNotFound)?;
+            let mut token_info =
+                storage::get_token_info(&env, index).ok_or(Error::TokenNotFound)?;
+            if token_info.creator != creator {
+                return Err(Error::Unauthorized);
+            }
+            if storage::is_token_renounced(&env, &token_address) {
+                return Err(Error::TokenRenounced);
+            }
+            token_creation::ensure_token_unlocked(&env, &token_address)?;
+            token_info.clawback_enabled = enabled;
+            updated.push_back((index, token_info));
+        }
+
+        for (token_address, (index, token_info)) in token_addresses.iter().zip(updated.iter()) {
+            storage::set_token_info(&env, index, &token_info);
+            storage::set_token_info_by_address(&env, &token_address, &token_info);
+            events::emit_clawback_toggled(&env, &token_address, &creator, enabled);
+        }
+
+        Ok(())
+    }
+
+    /// Mute or unmute a token's routine events (creator only)
+    ///
+    /// For privacy-sensitive tokens: while muted, transfers and holder
+    /// burns of the token publish no events. Admin and security events
+    /// such as clawbacks and pauses still fire, and so does the `evt_mute`
+    /// event recording each change of this setting.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not registered
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenRenounced` - Creator renounced control of the token
+    pub fn set_token_events_muted(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        muted: bool,
+    ) -> Result<(), Error> {
+        token_creation::set_token_events_muted(&env, &token_address, &creator, muted)
+    }
+
+    /// Restrict who may receive a token to holders of another (creator only)
+    ///
+    /// For token families such as membership-gated assets: while a gate is
+    /// set, transfers and mints of the token to a recipient without a
+    /// nonzero balance of `gate_token` fail with `Error::GateTokenRequired`.
+    /// The gate must be a factory token; `None` removes the gate.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token or gate token not registered
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenRenounced` - Creator renounced control of the token
+    /// * `Error::InvalidParameters` - The token would gate itself
+    pub fn set_token_gate(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        gate_token: Option<Address>,
+    ) -> Result<(), Error> {
+        token_creation::set_token_gate(&env, &token_address, &creator, gate_token)
+    }
+
+    /// Wrap a factory token as a Stellar Asset Contract (creator only)
+    ///
+    /// Deploys a SAC for a credit asset whose code is the token symbol and
+    /// whose issuer is the creator's account. The wrapper is created empty:
+    /// factory balances are not bridged into it, and its supply is whatever
+    /// the creator issues on the classic side. SACs are fixed at 7 decimals,
+    /// so only tokens created with 7 decimals can be wrapped. Wrapping is
+    /// idempotent: a second call returns the wrapper recorded by the first.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `creator` - Token creator address (must authorize and match creator)
+    ///
+    /// # Returns
+    /// Returns `Ok(Address)` with the SAC wrapper address
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::InvalidTokenParams` - Token decimals are not 7 or the symbol
+    ///   is not a valid asset code
+    /// * `Error::InvalidParameters` - Creator is not an account address
+    /// * `Error::SymbolTaken` - A SAC for this symbol and creator already
+    ///   exists
+    ///
+    /// # Examples
+    /// ```
+    /// let sac = factory.wrap_as_sac(&env, token_addr, creator)?;
+    /// assert_eq!(factory.get_sac_wrapper(&env, token_addr), Some(sac));
+    /// ```
+    pub fn wrap_as_sac(env: Env, token_address: Address, creator: Address) -> Result<Address, Error> {
+        sac_bridge::wrap_as_sac(&env, &token_address, &creator)
+    }
+
+    /// Get the SAC wrapper recorded for a factory token
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    ///
+    /// # Returns
+    /// `Some(Address)` if the token has been wrapped, `None` otherwise
+    pub fn get_sac_wrapper(env: Env, token_address: Address) -> Option<Address> {
+        storage::get_sac_wrapper(&env, &token_address)
+    }
+
+    /// Burn tokens from caller's own balance
+    ///
+    /// Allows a token holder to permanently destroy tokens from their
+    /// own balance, reducing the total supply.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Address burning tokens (must authorize)
+    /// * `token_index` - Index of the token to burn
+    /// * `amount` - Amount to burn (must be > 0 and <= balance)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::BurnDisabled` - Token was created non-burnable
+    /// * `Error::InvalidParameters` - Amount is zero or negative, or the
+    ///   caller is the factory contract itself
+    /// * `Error::InsufficientBalance` - Caller balance is less than amount
+    /// * `Error::BelowMinBalance` - Caller would be left with a nonzero
+    ///   balance below the token minimum
+    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    ///
+    /// # Examples
+    /// ```
+    /// // Burn 1000 tokens
+    /// factory.burn(&env, caller, 0, 1_000_0000000)?;
+    /// ```
+    pub fn burn(env: Env, caller: Address, token_index: u32, amount: i128) -> Result<(), Error> {
+        burn::burn(&env, caller, token_index, amount)
+    }
+
+    /// Preview a burn from `holder` without changing any state
+    ///
+    /// Fails with exactly the errors `burn` would for the same holder and
+    /// amount; otherwise returns the token stats after the burn.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not registered
+    /// * `Error::InvalidParameters` - Amount is zero or negative
+    /// * `Error::BurnDisabled` - Token was created non-burnable
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::TokenLocked` - Token is locked
+    /// * `Error::InsufficientBalance` - Holder balance is less than amount
+    /// * `Error::BelowMinBalance` - Holder would be left with a nonzero
+    ///   balance below the token minimum
+    pub fn simulate_burn(
+        env: Env,
+        token_address: Address,
+        holder: Address,
+        amount: i128,
+    ) -> Result<TokenStats, Error> {
+        burn::simulate_burn(&env, &token_address, &holder, amount)
+    }
+
+    /// Batch burn tokens from multiple holders (admin only)
+    ///
+    /// Allows the admin to burn tokens from multiple addresses in a single
+    /// transaction. All burns must succeed or the entire batch fails.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `token_index` - Index of the token to burn
+    /// * `burns` - Vector of (holder_address, amount) tuples (max 100 entries)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::BatchTooLarge` - More than 100 burn entries
+    /// * `Error::InvalidParameters` - Empty batch
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::BurnDisabled` - Token was created non-burnable
+    /// * `Error::BatchEntryFailed` - An entry has an invalid amount, exceeds
+    ///   the holder's balance or overflows the batch total. A `bch_fl_v1`
+    ///   event with the entry index and reason code is emitted first.
+    /// * `Error::InsufficientBalance` - Batch total exceeds supply
+    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    ///
+    /// # Examples
+    /// ```
+    /// let burns = vec![
+    ///     &env,
+    ///     (holder1, 1_000_0000000),
+    ///     (holder2, 2_000_0000000),
+    /// ];
+    /// factory.batch_burn(&env, admin, 0, burns)?;
+    /// ```
+    pub fn batch_burn(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        burns: soroban_sdk::Vec<(Address, i128)>,
+    ) -> Result<(), Error> {
+        burn::batch_burn(&env, admin, token_index, burns)
+    }
+
+    /// Batch burn tokens from multiple holders, best effort (admin only)
+    ///
+    /// Like `batch_burn`, but an entry that fails validation is skipped
+    /// instead of reverting the batch. Entries are processed in order, and
+    /// each failed one emits a `bch_fl_v1` event with its index and reason.
+    /// `bch_bn_v1` counts only the entries that burned.
+    ///
+    /// # Returns
+    /// `(holder, burned)` for every entry, in submission order
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::BatchTooLarge` - More than 100 burn entries
+    /// * `Error::InvalidParameters` - Empty batch
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::BurnDisabled` - Token was created non-burnable
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::TokenLocked` - Token is locked
+    pub fn batch_burn_lenient(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        burns: soroban_sdk::Vec<(Address, i128)>,
+    ) -> Result<soroban_sdk::Vec<(Address, bool)>, Error> {
+        burn::batch_burn_lenient(&env, admin, token_index, burns)
+    }
+
+    /// Burn negligible balances left on a token (creator only)
+    ///
+    /// Burns the whole balance of each listed holder whose balance is below
+    /// `threshold`; holders at or above it are left alone. Requires clawback
+    /// to be enabled on the token. Emits one `dust_swp` event when anything
+    /// was swept.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `creator` - Token creator address (must authorize and match creator)
+    /// * `threshold` - Balances strictly below this are burned
+    /// * `holders` - Holders to check (max 100)
+    ///
+    /// # Returns
+    /// Returns `Ok(i128)` with the total amount burned
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::BatchTooLarge` - More than 100 holders
+    /// * `Error::InvalidParameters` - Empty holder list or non-positive threshold
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::ClawbackDisabled` - Clawback is not enabled on the token
+    /// * `Error::TokenPaused` - Token is paused
+    pub fn sweep_dust(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        threshold: i128,
+        holders: Vec<Address>,
+    ) -> Result<i128, Error> {
+        burn::sweep_dust(&env, token_address, creator, threshold, holders)
+    }
+
+    /// Approve a spender to burn part of the caller's balance
+    ///
+    /// Sets (overwrites) the amount `spender` may burn from `from` through
+    /// `burn_from`. Approving zero revokes the allowance.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `from` - Holder granting the allowance (must authorize)
+    /// * `spender` - Address allowed to burn on the holder's behalf
+    /// * `amount` - New allowance (must be >= 0)
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::InvalidAmount` - Amount is negative
+    ///
+    /// # Examples
+    /// ```
+    /// factory.approve(&env, token_addr, holder, protocol, 500)?;
+    /// ```
+    pub fn approve(
+        env: Env,
+        token_address: Address,
+        from: Address,
+        spender: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        allowance::approve(&env, &token_address, &from, &spender, amount)
+    }
+
+    /// Revoke every allowance an owner has granted on a token
+    ///
+    /// Clears all spenders approved by `owner` in one call, even while the
+    /// contract is paused. Returns how many allowances were revoked.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found
+    pub fn revoke_all_approvals(
+        env: Env,
+        token_address: Address,
+        owner: Address,
+    ) -> Result<u32, Error> {
+        allowance::revoke_all_approvals(&env, &token_address, &owner)
+    }
+
+    /// Get the allowance `spender` has over `from`'s balance
+    pub fn get_allowance(env: Env, token_address: Address, from: Address, spender: Address) -> i128 {
+        storage::get_allowance(&env, &token_address, &from, &spender)
+    }
+
+    /// Burn tokens from a holder's balance using an allowance
+    ///
+    /// Lets an approved spender burn on a holder's behalf. The amount is
+    /// deducted from both the holder's balance and the spender's allowance,
+    /// and the token's supply is reduced.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `spender` - Approved spender (must authorize)
+    /// * `from` - Holder whose tokens are burned
+    /// * `amount` - Amount to burn (must be > 0)
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::InvalidParameters` - Amount is zero or negative
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::BurnDisabled` - Token was created non-burnable
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::AddressFrozen` - Holder is frozen for this token
+    /// * `Error::InsufficientBalance` - Holder balance is less than amount
+    /// * `Error::BelowMinBalance` - Holder would be left with a nonzero
+    ///   balance below the token minimum
+    /// * `Error::InsufficientAllowance` - Allowance is less than amount
+    ///
+    /// # Examples
+    /// ```
+    /// factory.burn_from(&env, token_addr, protocol, holder, 200)?;
+    /// ```
+    pub fn burn_from(
+        env: Env,
+        token_address: Address,
+        spender: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        burn::burn_from(&env, token_address, spender, from, amount)
+    }
+
+    /// Transfer tokens with a payment reference
+    ///
+    /// Moves `amount` of a factory-managed token from `from` to `to` and
+    /// includes `memo` in the emitted event for reconciliation. The memo
+    /// is not stored.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `from` - Sender (must authorize)
+    /// * `to` - Recipient
+    /// * `amount` - Amount to transfer (must be > 0)
+    /// * `memo` - Reference of at most 64 bytes
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::InvalidParameters` - Memo is over 64 bytes, `from == to`, or
+    ///   either side is the factory contract itself
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::BelowMinTransfer` - Amount is below the token's minimum
+    /// * `Error::AddressFrozen` - Sender or recipient is frozen
+    /// * `Error::InsufficientBalance` - Sender balance is less than amount
+    /// * `Error::BelowMinBalance` - Sender or recipient would be left with a
+    ///   nonzero balance below the token minimum
+    /// * `Error::HolderLimitReached` - Recipient would exceed the holder cap
+    pub fn transfer_with_memo(
+        env: Env,
+        token_address: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+        memo: Bytes,
+    ) -> Result<(), Error> {
+        transfer::transfer_with_memo(&env, &token_address, &from, &to, amount, &memo)
+    }
+
+    /// Pay several recipients from one balance in a single transaction
+    ///
+    /// Debits the sum of all amounts from `from` once and credits each
+    /// recipient. Every entry is validated first, so the batch either
+    /// completes in full or changes nothing. Emits one `batch_xfr` event.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `from` - Sender (must authorize)
+    /// * `transfers` - `(recipient, amount)` pairs (max 100)
+    ///
+    /// # Returns
+    /// Returns `Ok(i128)` with the total amount transferred
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::BatchTooLarge` - More than 100 entries
+    /// * `Error::InvalidParameters` - Empty batch, a recipient is `from`,
+    ///   or either side is the factory contract itself
+    /// * `Error::InvalidAmount` - An amount is zero or negative
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::BelowMinTransfer` - An amount is below the token's minimum
+    /// * `Error::AddressFrozen` - Sender or a recipient is frozen
+    /// * `Error::InsufficientBalance` - Sender balance is less than the total
+    /// * `Error::BelowMinBalance` - Sender or a recipient would be left with a
+    ///   nonzero balance below the token minimum
+    /// * `Error::HolderLimitReached` - New recipients would exceed the holder cap
+    pub fn batch_transfer(
+        env: Env,
+        token_address: Address,
+        from: Address,
+        transfers: Vec<(Address, i128)>,
+    ) -> Result<i128, Error> {
+        transfer::batch_transfer(&env, &token_address, &from, &transfers)
+    }
+
+    /// Get the total number of burn operations for a token
+    ///
+    /// Returns the count of all burn operations (both user and admin burns)
+    /// performed on the specified token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_index` - Index of the token
+    ///
+    /// # Returns
+    /// Returns the burn count as a u32
+    ///
+    /// # Examples
+    /// ```
+    /// let burn_count = factory.get_burn_count(&env, 0);
+    /// assert!(burn_count > 0);
+    /// ```
+    pub fn get_burn_count(env: Env, token_index: u32) -> u32 {
+        burn::get_burn_count(&env, token_index)
+    }
+
+    /// Admin-initiated burn from any holder's balance
+    ///
+    /// Allows the admin to burn tokens from any holder's address.
+    /// This is a privileged operation that requires admin authentication.
+    /// Tokens in `ClawbackMode::Redirect` are moved to the redirect address
+    /// instead, leaving the supply unchanged.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `token_index` - Index of the token to burn
+    /// * `holder` - Address holding the tokens to burn
+    /// * `amount` - Amount to burn (must be > 0 and <= holder's balance)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::ContractPaused` - Contract is paused and admin burns are
+    ///   not exempt (see `set_admin_burn_during_pause`)
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::ClawbackDisabled` - The creator has not enabled clawback
+    ///   (see `set_clawback`)
+    /// * `Error::BurnDisabled` - Token is non-burnable and clawback of
+    ///   non-burnable tokens is disabled (see `set_clawback_non_burnable`)
+    /// * `Error::InvalidParameters` - Amount is zero or negative
+    /// * `Error::InsufficientBalance` - Holder balance is less than amount
+    /// * `Error::ClawbackRateLimited` - Holder was clawed back from within
+    ///   the clawback cooldown
+    /// * `Error::HolderLimitReached` - Redirect address would exceed the
+    ///   holder cap
+    /// * `Error::AddressFrozen` - Redirect address is frozen
+    /// * `Error::GateTokenRequired` - Redirect address holds none of the
+    ///   token's gate token
+    /// * `Error::BelowMinBalance` - Redirect address would end below the
+    ///   token's minimum balance
+    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    ///
+    /// # Examples
+    /// ```
+    /// // Admin burns 1000 tokens from a holder
+    /// factory.admin_burn(&env, admin, 0, holder, 1_000_0000000)?;
+    /// ```
+    pub fn admin_burn(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        holder: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        burn::admin_burn(&env, admin, token_index, holder, amount)
+    }
+
+    /// Allow or block `admin_burn` while the contract is paused (admin only)
+    ///
+    /// Off by default. Enabling it lets the admin claw back stolen funds
+    /// during an emergency pause; regular burns and transfers stay blocked.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_admin_burn_during_pause(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_admin_burn_during_pause(&env, enabled);
+        Ok(())
+    }
+
+    /// Check whether `admin_burn` may run while the contract is paused
+    pub fn is_admin_burn_during_pause(env: Env) -> bool {
+        storage::is_admin_burn_during_pause(&env)
+    }
+
+    /// Allow or block `admin_burn` on non-burnable tokens (admin only)
+    ///
+    /// Allowed by default, so clawback keeps working on tokens whose
+    /// holders cannot burn. Disabling it makes non-burnable tokens fully
+    /// burn-proof.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_clawback_non_burnable(
+        env: Env,
+        admin: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_clawback_non_burnable_allowed(&env, allowed);
+        Ok(())
+    }
+
+    /// Check whether `admin_burn` may claw back non-burnable tokens
+    pub fn is_clawback_non_burnable_allowed(env: Env) -> bool {
+        storage::is_clawback_non_burnable_allowed(&env)
+    }
+
+    /// Check whether holders of a token may burn it
+    ///
+    /// Returns `false` for addresses the factory did not create.
+    pub fn is_burnable(env: Env, token_address: Address) -> bool {
+        storage::get_token_info_by_address(&env, &token_address)
+            .is_some_and(|info| info.burnable)
+    }
+
+    /// Set the clawback cooldown (admin only)
+    ///
+    /// `admin_burn` rejects a second clawback of the same token from the
+    /// same holder until `seconds` have passed since the previous one.
+    /// Other holders are unaffected. 0 (the default) disables the limit.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    pub fn set_clawback_cooldown(env: Env, admin: Address, seconds: u64) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_clawback_cooldown(&env, seconds);
+        Ok(())
+    }
+
+    /// Get the clawback cooldown in seconds (0 = no limit)
+    pub fn get_clawback_cooldown(env: Env) -> u64 {
+        storage::get_clawback_cooldown(&env)
+    }
+
+    /// Create several tokens in one atomic call
+    ///
+    /// Validates every token and the combined fee before creating any of
+    /// them, so either the whole batch is created or nothing changes. Each
+    /// token goes to `creator`, who receives its initial supply.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator (must authorize)
+    /// * `tokens` - Parameters of each token to create
+    /// * `total_fee_payment` - Fee offered for the whole batch (must cover
+    ///   the sum of the creation fees)
+    /// * `waiver_code` - Optional promo code, redeemed once per token while
+    ///   it has uses left; each use waives that token's fee
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<Address>)` with the new tokens' addresses, in order
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::CreationDisabled` - Token creation was shut down
+    /// * `Error::InvalidTokenParams` - Empty batch or a token's parameters
+    ///   are invalid
+    /// * `Error::InsufficientFee` - Fee payment below the combined fee
+    /// * `Error::SymbolTaken` - A symbol is already registered in unique mode
+    /// * `Error::CreateRateLimited` - Per-window creation cap reached
+    ///
+    /// # Examples
+    /// ```
+    /// let tokens = factory.batch_create_tokens(&env, creator, specs, total_fee, None)?;
+    /// ```
+    pub fn batch_create_tokens(
+        env: Env,
+        creator: Address,
+        tokens: Vec<TokenCreationParams>,
+        total_fee_payment: i128,
+        waiver_code: Option<Bytes>,
+    ) -> Result<Vec<Address>, Error> {
+        token_creation::batch_create_tokens(&env, creator, tokens, total_fee_payment, waiver_code)
+    }
+
+    /// Create a single token
+    ///
+    /// Creates a token owned by `creator`, who receives the initial supply,
+    /// and collects the creation fee. `NonFungible` tokens are single
+    /// collectibles and must have 0 decimals and an initial supply of 1.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator (must authorize)
+    /// * `spec` - Parameters of the token to create
+    /// * `fee_payment` - Fee offered (must cover the creation fee)
+    /// * `token_kind` - Fungible token or non-fungible collectible
+    /// * `waiver_code` - Optional promo code; a code with uses left waives
+    ///   the creation fee, other codes are ignored
+    ///
+    /// # Returns
+    /// Returns `Ok(Address)` with the new token's address
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::InvalidTokenParams` - Parameters invalid, or invalid for the kind
+    /// * `Error::InvalidParameters` - Metadata URI empty or too long, or the
+    ///   token address collides with the admin, treasury or creator
+    /// * `Error::InsufficientFee` - Fee payment below the creation fee
+    /// * `Error::SymbolTaken` - Symbol already registered in unique mode
+    /// * `Error::SymbolReserved` - Symbol is on the reserved blocklist
+    /// * `Error::CreateRateLimited` - Per-window creation cap reached
+    /// * `Error::InsufficientCreatorBalance` - Creator holds less XLM than
+    ///   the minimum set with `set_min_creator_balance`
+    ///
+    /// # Examples
+    /// ```
+    /// let nft = factory.create_token(&env, creator, spec, base_fee, TokenKind::NonFungible, None)?;
+    /// ```
+    pub fn create_token(
+        env: Env,
+        creator: Address,
+        spec: TokenCreationParams,
+        fee_payment: i128,
+        token_kind: TokenKind,
+        waiver_code: Option<Bytes>,
+    ) -> Result<Address, Error> {
+        token_creation::create_token(&env, creator, spec, fee_payment, token_kind, waiver_code)
+    }
+
+    /// Create a token and immediately renounce all creator powers
+    ///
+    /// Deploys the token and mints the initial supply to the creator like
+    /// `create_token`, then, in the same transaction, finalizes minting,
+    /// freezes metadata and locks clawback off. Holders can still transfer
+    /// and burn. Emits `tok_crt` followed by `renounced`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `spec` - Token parameters
+    /// * `fee_payment` - Creation fee offered
+    ///
+    /// # Returns
+    /// Returns `Ok(Address)` with the created token address
+    ///
+    /// # Errors
+    /// Same as `create_token`
+    pub fn create_token_renounced(
+        env: Env,
+        creator: Address,
+        spec: TokenCreationParams,
+        fee_payment: i128,
+    ) -> Result<Address, Error> {
+        token_creation::create_token_renounced(&env, creator, spec, fee_payment)
+    }
+
+    /// Reserve the next token index for a later `create_token_at`
+    ///
+    /// The index is allocated right away and held for the creator for
+    /// about a day (17,280 ledgers). A reservation that lapses unused
+    /// leaves its index permanently empty; `get_token_info_detailed`
+    /// reports such indices as `Error::TokenNotYetCreated`.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::CreationDisabled` - Creation was shut down
+    pub fn reserve_index(env: Env, creator: Address) -> Result<u32, Error> {
+        token_creation::reserve_index(&env, &creator)
+    }
+
+    /// Create a token at an index reserved with `reserve_index`
+    ///
+    /// Charges and validates exactly like `create_token` for a fungible
+    /// token. The reservation is consumed, so an index can be filled once.
+    ///
+    /// # Errors
+    /// * `Error::IndexNotReserved` - No reservation on the index (never
+    ///   reserved or already used)
+    /// * `Error::Unauthorized` - Index is reserved for another creator
+    /// * `Error::ReservationExpired` - Reservation lapsed
+    /// * Any error from `create_token`
+    pub fn create_token_at(
+        env: Env,
+        creator: Address,
+        reserved_index: u32,
+        spec: TokenCreationParams,
+        fee_payment: i128,
+    ) -> Result<Address, Error> {
+        token_creation::create_token_at(&env, creator, reserved_index, spec, fee_payment)
+    }
+
+    /// Check whether a token's creator has renounced control of it
+    pub fn is_token_renounced(env: Env, token_address: Address) -> bool {
+        storage::is_token_renounced(&env, &token_address)
+    }
+
+    /// Permanently lock a token against every change (admin only)
+    ///
+    /// Once locked, mints, burns, transfers, vesting, clawback settings
+    /// and metadata changes on the token fail with `Error::TokenLocked`.
+    /// Reads keep working and factory-level fee rescue is unaffected.
+    /// Locking cannot be undone.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the current admin
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::TokenLocked` - Token is already locked
+    pub fn lock_token(env: Env, admin: Address, token_address: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if storage::get_token_index(&env, &token_address).is_none() {
+            return Err(Error::TokenNotFound);
+        }
+        token_creation::ensure_token_unlocked(&env, &token_address)?;
+
+        storage::set_token_locked(&env, &token_address);
+        events::emit_token_locked(&env, &token_address, &admin);
+        Ok(())
+    }
+
+    /// Check whether a token has been permanently locked
+    pub fn is_token_locked(env: Env, token_address: Address) -> bool {
+        storage::is_token_locked(&env, &token_address)
+    }
+
+    /// Create or reset a creation fee waiver (admin only)
+    ///
+    /// Stores the sha256 hash of a promo code with the number of token
+    /// creations it can make free. Passing the code to `create_token`
+    /// redeems one use. Creating a waiver for an existing hash replaces
+    /// its remaining uses.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `code_hash` - sha256 of the promo code
+    /// * `uses` - Number of redemptions allowed (must be > 0)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `uses` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// let code_hash = env.crypto().sha256(&Bytes::from_slice(&env, b"LAUNCH50")).into();
+    /// factory.create_waiver(&env, admin, code_hash, 50)?;
+    /// ```
+    pub fn create_waiver(
+        env: Env,
+        admin: Address,
+        code_hash: BytesN<32>,
+        uses: u32,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if uses == 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_waiver_uses(&env, &code_hash, uses);
+        events::emit_waiver_created(&env, &code_hash, uses);
+        Ok(())
+    }
+
+    /// Set the ed25519 key that signs off-chain fee waivers (admin only)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_waiver_signer(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        storage::set_waiver_signer(&env, &public_key);
+        Ok(())
+    }
+
+    /// Redeem a single-use waiver signed off-chain by the waiver signer
+    ///
+    /// The signature must cover `waiver_message` for this creator. Each
+    /// nonce can be redeemed once; a redeemed waiver makes the creator's
+    /// next paid creation free, whether single, batched (one waiver per
+    /// token) or escrowed. A bad signature aborts the call.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - No waiver signer is configured
+    /// * `Error::WaiverExpired` - The ledger time reached `expires_at`
+    /// * `Error::WaiverNonceUsed` - The nonce was already redeemed
+    pub fn redeem_signed_waiver(
+        env: Env,
+        creator: Address,
+        waiver: types::SignedWaiver,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        fees::redeem_signed_waiver(&env, &creator, &waiver)
+    }
+
+    /// Get the bytes a waiver signer must sign for `creator`
+    pub fn waiver_message(env: Env, creator: Address, nonce: u64, expires_at: u64) -> Bytes {
+        fees::waiver_message(&env, &creator, nonce, expires_at)
+    }
+
+    /// Get the remaining uses of a fee waiver
+    ///
+    /// # Returns
+    /// Remaining redemptions, or 0 for unknown or used-up waivers
+    pub fn get_waiver_uses(env: Env, code_hash: BytesN<32>) -> u32 {
+        storage::get_waiver_uses(&env, &code_hash)
+    }
+
+    /// Check whether a token spec would be accepted, without creating it
+    ///
+    /// Runs the same validation `create_token` performs (pause state, name
+    /// and symbol length, decimals, initial supply) and returns the first
+    /// error. Nothing is stored and no fee is charged.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Address that would create the token
+    /// * `name` - Token name
+    /// * `symbol` - Token symbol
+    /// * `decimals` - Number of decimal places
+    /// * `initial_supply` - Initial token supply
+    /// * `metadata_uri` - Optional metadata URI
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the spec would be accepted
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::InvalidTokenParams` - Name, symbol, decimals or supply invalid
+    /// * `Error::InvalidParameters` - Metadata URI empty or too long
+    /// * `Error::GlobalCapExceeded` - Initial supply would exceed the global
+    ///   supply cap
+    /// * `Error::InsufficientCreatorBalance` - Creator holds less XLM than
+    ///   the required minimum
+    ///
+    /// # Examples
+    /// ```
+    /// factory.validate_token_spec(&env, creator, name, symbol, 7, 1_000_000, None)?;
+    /// ```
+    pub fn validate_token_spec(
+        env: Env,
+        creator: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        initial_supply: i128,
+        metadata_uri: Option<String>,
+    ) -> Result<(), Error> {
+        let params = TokenCreationParams {
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            max_supply: None,
+            metadata_uri,
+            display_decimals: None,
+            min_transfer: None,
+            burnable: None,
+            min_balance: None,
+        };
+        token_creation::dry_run_create(&env, &creator, &params, TokenKind::Fungible)
+    }
+
+    /// Preview a token creation: validate the spec and predict the result
+    ///
+    /// Runs the checks `create_token` runs on the full spec and `token_kind`
+    /// and returns the index and address the next `create_token` by anyone
+    /// would get. Creation takes no salt: the address is derived from
+    /// `sha256(token_index)`, so the prediction holds until another token
+    /// is created or an index is reserved. Nothing is stored and no fee is
+    /// charged.
+    ///
+    /// # Errors
+    /// Same as `validate_token_spec`, plus `Error::InvalidTokenParams` when
+    /// the spec does not fit `token_kind`.
+    pub fn simulate_create_token(
+        env: Env,
+        creator: Address,
+        spec: TokenCreationParams,
+        token_kind: TokenKind,
+    ) -> Result<(u32, Address), Error> {
+        token_creation::simulate_create_token(&env, &creator, &spec, token_kind)
+    }
+
+    /// Check a token spec and report every problem at once
+    ///
+    /// Unlike `validate_token_spec`, which stops at the first error, this
+    /// returns one code per problem (`InvalidName`, `InvalidSymbol`,
+    /// `InvalidDecimals`, `InvalidSupply`, `InvalidAmount` for the minimum
+    /// transfer, `InvalidParameters` for the metadata URI, `SymbolTaken`,
+    /// `SymbolReserved`) so every bad field can be shown together. An empty result means the
+    /// spec is valid.
+    pub fn validate_token_spec_full(env: Env, spec: TokenCreationParams) -> Vec<Error> {
+        token_creation::collect_spec_violations(&env, &spec)
+    }
+
+    /// Start a token creation with the fee held in escrow
+    ///
+    /// Transfers the creation fee due (never more than `fee`) from the
+    /// creator into the factory and records the token parameters. The creation is completed with `finalize_create`
+    /// or abandoned with `cancel_create`, which refunds the fee.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator (must authorize)
+    /// * `spec` - Parameters of the token to create
+    /// * `fee` - Fee to escrow (must cover the creation fee; fee-exempt
+    ///   creators owe none)
+    /// * `waiver_code` - Optional promo code, redeemed as in `create_token`;
+    ///   cancelling the escrow does not give the use back
+    ///
+    /// # Returns
+    /// Returns `Ok(u64)` with the escrow id
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::InvalidTokenParams` - Token parameters are invalid
+    /// * `Error::InsufficientFee` - Fee is below the creation fee
+    ///
+    /// # Examples
+    /// ```
+    /// let escrow_id = factory.begin_create(&env, creator, spec, fee, None)?;
+    /// let token = factory.finalize_create(&env, creator, escrow_id)?;
+    /// ```
+    pub fn begin_create(
+        env: Env,
+        creator: Address,
+        spec: TokenCreationParams,
+        fee: i128,
+        waiver_code: Option<Bytes>,
+    ) -> Result<u64, Error> {
+        escrow::begin_create(&env, &creator, spec, fee, waiver_code)
+    }
+
+    /// Create the escrowed token and forward the fee to the treasury
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Creator who opened the escrow (must authorize)
+    /// * `escrow_id` - Escrow returned by `begin_create`
+    ///
+    /// # Returns
+    /// Returns `Ok(Address)` with the created token address
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::EscrowNotFound` - Escrow id does not exist
+    /// * `Error::Unauthorized` - Caller did not open the escrow
+    /// * `Error::InvalidStateTransition` - Escrow already finalized or cancelled
+    pub fn finalize_create(env: Env, creator: Address, escrow_id: u64) -> Result<Address, Error> {
+        escrow::finalize_create(&env, &creator, escrow_id)
+    }
+
+    /// Cancel a pending creation and refund the escrowed fee
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Creator who opened the escrow (must authorize)
+    /// * `escrow_id` - Escrow returned by `begin_create`
+    ///
+    /// # Errors
+    /// * `Error::EscrowNotFound` - Escrow id does not exist
+    /// * `Error::Unauthorized` - Caller did not open the escrow
+    /// * `Error::InvalidStateTransition` - Escrow already finalized or cancelled
+    pub fn cancel_create(env: Env, creator: Address, escrow_id: u64) -> Result<(), Error> {
+        escrow::cancel_create(&env, &creator, escrow_id)
+    }
+
+    /// Get a creation escrow by id
+    pub fn get_create_escrow(env: Env, escrow_id: u64) -> Option<types::CreateEscrow> {
+        storage::get_create_escrow(&env, escrow_id)
+    }
+
+    /// Set metadata for a token
+    /// 
+    /// Allows the token creator to set metadata URI once. The URI must be
+    /// non-empty and within the configured maximum URI length.
+    /// Renounced tokens are rejected with `Error::TokenRenounced`.
+    pub fn set_token_metadata(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        metadata_uri: String,
+    ) -> Result<(), Error> {
+        // Require admin authorization
+        admin.require_auth();
+
+        // Get token info
+        let mut token_info =
+            storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+
+        // Verify admin is the token creator
+        if token_info.creator != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        // Enforce immutability: metadata can only be set once
+        if token_info.metadata_uri.is_some() {
+            return Err(Error::MetadataAlreadySet);
+        }
+        if storage::is_token_renounced(&env, &token_info.address) {
+            return Err(Error::TokenRenounced);
+        }
+        token_creation::ensure_token_unlocked(&env, &token_info.address)?;
+
+        token_creation::validate_metadata_uri(&env, Some(&metadata_uri))?;
+
+        // Set metadata URI
+        token_info.metadata_uri = Some(metadata_uri.clone());
+        storage::set_token_info(&env, token_index, &token_info);
+
+        // Also update by address lookup
+        storage::set_token_info_by_address(&env, &token_info.address, &token_info);
+
+        // Emit metadata set event
+        events::emit_metadata_set(&env, &token_info.address, &admin, &metadata_uri);
+        token_attributes::bump_metadata_version(&env, &token_info.address, &admin)?;
+        fees::charge_op_fee(&env, &admin, types::OpKind::Metadata)?;
+
+        Ok(())
+    }
+
+    /// Set a structured metadata attribute on a token (creator only)
+    ///
+    /// Stores a short key/value pair such as `website` or `twitter` next to
+    /// the metadata URI. Existing keys are overwritten; new keys count
+    /// towards a per-token cap of 16 attributes.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `creator` - Token creator address (must authorize and match creator)
+    /// * `key` - Attribute key (1-32 bytes)
+    /// * `value` - Attribute value (1-256 bytes)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::MetadataFrozen` - Token metadata has been frozen
+    /// * `Error::InvalidParameters` - Key or value length out of bounds
+    /// * `Error::AttributeLimitExceeded` - Token already has 16 attributes
+    ///
+    /// # Examples
+    /// ```
+    /// factory.set_token_attribute(&env, token_addr, creator, "website", "https://nova.example")?;
+    /// ```
+    pub fn set_token_attribute(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        key: String,
+        value: String,
+    ) -> Result<(), Error> {
+        token_attributes::set_token_attribute(&env, &token_address, &creator, &key, &value)
+    }
+
+    /// Get a structured metadata attribute for a token
+    ///
+    /// # Returns
+    /// `Some(String)` if the attribute is set, `None` otherwise
+    pub fn get_token_attribute(env: Env, token_address: Address, key: String) -> Option<String> {
+        storage::get_token_attribute(&env, &token_address, &key)
+    }
+
+    /// Get a token's metadata version
+    ///
+    /// Starts at 0 and increments on every metadata URI or attribute change,
+    /// letting indexers detect stale cached metadata.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found
+    pub fn get_metadata_version(env: Env, token_address: Address) -> Result<u32, Error> {
+        storage::get_token_info_by_address(&env, &token_address)
+            .map(|info| info.metadata_version)
+            .ok_or(Error::TokenNotFound)
+    }
+
+    /// Permanently freeze a token's metadata attributes (creator only)
+    ///
+    /// After freezing, `set_token_attribute` is rejected with
+    /// `Error::MetadataFrozen`. Freezing cannot be undone.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::MetadataFrozen` - Metadata is already frozen
+    pub fn freeze_token_metadata(env: Env, token_address: Address, creator: Address) -> Result<(), Error> {
+        token_attributes::freeze_metadata(&env, &token_address, &creator)
+    }
+
+    /// Check whether a token's metadata has been frozen
+    pub fn is_token_metadata_frozen(env: Env, token_address: Address) -> bool {
+        storage::is_metadata_frozen(&env, &token_address)
+    }
+
+    pub fn pause_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
+        admin.require_auth();
+        if admin != storage::get_admin(&env) {
+            return Err(Error::Unauthorized);
+        }
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        storage::set_token_paused(&env, token_index, true);
+        Ok(())
+    }
+
+    pub fn unpause_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
+        admin.require_auth();
+        if admin != storage::get_admin(&env) {
+            return Err(Error::Unauthorized);
+        }
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        storage::set_token_paused(&env, token_index, false);
+        Ok(())
+    }
+
+    pub fn is_token_paused(env: Env, token_index: u32) -> bool {
+        storage::is_token_paused(&env, token_index)
+    }
+
+    /// Return a compact stats snapshot for a token
+    pub fn get_token_stats(env: Env, token_index: u32) -> Result<TokenStats, Error> {
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+
+        Ok(TokenStats {
+            current_supply: storage::get_token_info(&env, token_index)
+                .map(|i| i.total_supply)
+                .unwrap_or(0),
             total_burned: storage::get_total_burned(&env, token_index),
             burn_count: storage::get_burn_count(&env, token_index),
             is_paused: storage::is_token_paused(&env, token_index),
@@ -918,6 +3327,76 @@ impl TokenFactory {
         })
     }
 
+    /// Return supply, burn, holder and age figures for a token in one call
+    ///
+    /// Saves token detail pages a round-trip per figure; every field
+    /// matches the corresponding individual getter.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn get_token_summary_stats(
+        env: Env,
+        token_index: u32,
+    ) -> Result<types::TokenSummaryStats, Error> {
+        let info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        let now = token_creation::creation_time(&env, info.created_at_source);
+
+        Ok(types::TokenSummaryStats {
+            address: info.address,
+            creator: info.creator,
+            current_supply: info.total_supply,
+            max_supply: info.max_supply,
+            total_burned: storage::get_total_burned(&env, token_index),
+            burn_count: storage::get_burn_count(&env, token_index),
+            holder_count: storage::get_holder_count(&env, token_index),
+            created_at: info.created_at,
+            created_at_source: info.created_at_source,
+            age: now.saturating_sub(info.created_at),
+        })
+    }
+
+    /// Evaluate several reads in one invocation
+    ///
+    /// Lets dashboards fetch state, token info, burn counts and supply
+    /// stats without one call per value. Results are returned in request
+    /// order; a read that fails (e.g. unknown token index) yields
+    /// `ReadResult::Failed` without affecting the others. Nothing is written.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `requests` - Reads to perform (at most 50)
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - More than 50 requests
+    pub fn multicall_reads(
+        env: Env,
+        requests: Vec<types::ReadRequest>,
+    ) -> Result<Vec<types::ReadResult>, Error> {
+        use types::{ReadRequest, ReadResult};
+
+        if requests.len() > MAX_MULTICALL_READS {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut results = Vec::new(&env);
+        for request in requests.iter() {
+            let result = match request {
+                ReadRequest::State => Ok(ReadResult::State(Self::get_state(env.clone()))),
+                ReadRequest::TokenInfo(index) => {
+                    Self::get_token_info(env.clone(), index).map(ReadResult::TokenInfo)
+                }
+                ReadRequest::BurnCount(index) => {
+                    Ok(ReadResult::BurnCount(Self::get_burn_count(env.clone(), index)))
+                }
+                ReadRequest::SupplyInfo(index) => {
+                    Self::get_token_stats(env.clone(), index).map(ReadResult::SupplyInfo)
+                }
+            };
+            results.push_back(result.unwrap_or_else(ReadResult::Failed));
+        }
+        Ok(results)
+    }
+
     /// Return a paginated list of token indices where beneficiary is the creator.
     /// cursor: starting entry index (0 for first page)
     /// limit: max entries to return (capped at 50)
@@ -1186,25 +3665,174 @@ impl TokenFactory {
         pagination::get_tokens_by_creator(&env, &creator, pagination_cursor, limit)
     }
 
-    /// Get the total number of tokens created by an address
+    /// Get the total number of tokens created by an address
+    ///
+    /// Returns the count without fetching the actual token data.
+    /// Useful for displaying total counts in UIs.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Address of the token creator
+    ///
+    /// # Returns
+    /// Returns the number of tokens created by this address
+    ///
+    /// # Examples
+    /// ```
+    /// let count = factory.get_creator_token_count(&env, creator);
+    /// log!("Creator has deployed {} tokens", count);
+    /// ```
+    pub fn get_creator_token_count(env: Env, creator: Address) -> u32 {
+        pagination::get_creator_token_count(&env, &creator)
+    }
+
+    /// List all tokens in index order with cursor-based pagination
+    ///
+    /// Archived tokens are skipped unless `include_archived` is set. Their
+    /// indices are never reused, so a token keeps its index either way.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `cursor` - Index to resume from (None for the first page)
+    /// * `limit` - Page size (default 20, max 100)
+    /// * `include_archived` - Whether to include archived tokens
+    pub fn list_tokens(
+        env: Env,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+        include_archived: bool,
+    ) -> Result<types::PaginatedTokens, Error> {
+        let pagination_cursor = cursor
+            .map(|next_index| PaginationCursor { next_index })
+            .unwrap_or(PaginationCursor {
+                next_index: u32::MAX,
+            });
+        pagination::list_tokens(&env, pagination_cursor, limit, include_archived)
+    }
+
+    /// Export a page of the token registry in compact form
+    ///
+    /// Meant for off-chain backups: each entry keeps only the address,
+    /// creator, symbol, supply and decimals of a token. Archived tokens are
+    /// included. Pages are read by index, so the next page starts at
+    /// `start + limit`; an empty result means the end was reached.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - First token index to export
+    /// * `limit` - Page size (1-100)
+    pub fn export_registry(env: Env, start: u32, limit: u32) -> Vec<types::TokenSummary> {
+        pagination::export_registry(&env, start, limit)
+    }
+
+    /// List token contract addresses by index
+    ///
+    /// A compact alternative to `list_tokens` for clients that only need
+    /// addresses: paged like `export_registry`, so the next page starts at
+    /// `start + limit` and an empty result means the end was reached.
+    /// Archived tokens are included.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - First token index to list
+    /// * `limit` - Page size (1-100)
+    pub fn list_token_addresses(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        pagination::list_token_addresses(&env, start, limit)
+    }
+
+    /// Get a checksum of the token registry
+    ///
+    /// sha256 over the token count and each token's address, supply and
+    /// creator in index order. Observers can compare this single value to
+    /// confirm they see the same registry. The whole registry is read, so
+    /// the cost grows with the number of tokens.
+    pub fn get_registry_checksum(env: Env) -> BytesN<32> {
+        pagination::registry_checksum(&env)
+    }
+
+    /// List indices of tokens created within a time range
+    ///
+    /// Returns tokens whose `created_at` is at least `from_ts` and before
+    /// `to_ts`, in creation order. Lookups go through per-day creation
+    /// buckets, so a range may span at most 366 days.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from_ts` - Start of the range (inclusive)
+    /// * `to_ts` - End of the range (exclusive)
+    /// * `limit` - Maximum number of indices to return (1-100)
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - `from_ts` is after `to_ts` or the
+    ///   range spans more than 366 days
+    pub fn list_tokens_by_time_range(
+        env: Env,
+        from_ts: u64,
+        to_ts: u64,
+        limit: u32,
+    ) -> Result<Vec<u32>, Error> {
+        pagination::list_tokens_by_time_range(&env, from_ts, to_ts, limit)
+    }
+
+    /// Get the number of tokens created by the factory
+    ///
+    /// Archived tokens are only counted when `include_archived` is set.
+    pub fn get_token_count(env: Env, include_archived: bool) -> u32 {
+        pagination::get_token_count(&env, include_archived)
+    }
+
+    /// Get the index the next created token will receive
     ///
-    /// Returns the count without fetching the actual token data.
-    /// Useful for displaying total counts in UIs.
+    /// Indices are never reused: archived tokens and reservations from
+    /// `reserve_index` keep theirs, so this is the raw number of indices
+    /// allocated so far. `create_token_at` fills a reserved index instead.
+    pub fn get_next_token_index(env: Env) -> u32 {
+        storage::get_token_count(&env)
+    }
+
+    /// Archive a token (creator only)
+    ///
+    /// Hides the token from `list_tokens` and `get_token_count` by default
+    /// without freeing its index. Archiving cannot be undone.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `creator` - Address of the token creator
+    /// * `token_address` - The token's contract address
+    /// * `creator` - Token creator (must authorize)
     ///
-    /// # Returns
-    /// Returns the number of tokens created by this address
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenArchived` - Token was already archived
+    pub fn archive_token(env: Env, token_address: Address, creator: Address) -> Result<(), Error> {
+        token_creation::archive_token(&env, &token_address, &creator)
+    }
+
+    /// Close a wound-down token and free its storage (admin only)
     ///
-    /// # Examples
-    /// ```
-    /// let count = factory.get_creator_token_count(&env, creator);
-    /// log!("Creator has deployed {} tokens", count);
-    /// ```
-    pub fn get_creator_token_count(env: Env, creator: Address) -> u32 {
-        pagination::get_creator_token_count(&env, &creator)
+    /// The token must have zero supply. Its registry records, burn
+    /// records, flags and attributes are removed and its index is marked
+    /// archived; the index is never reused. Afterwards the token is
+    /// reported as `Error::TokenNotFound` by address and index lookups and
+    /// as `Error::TokenArchived` by `get_token_info_detailed`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `token_address` - The token's contract address
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token address not found or already closed
+    /// * `Error::SupplyNotZero` - Token still has circulating supply
+    pub fn close_token(env: Env, admin: Address, token_address: Address) -> Result<(), Error> {
+        token_creation::close_token(&env, &admin, &token_address)
+    }
+
+    /// Check whether the token at `token_index` has been archived
+    pub fn is_token_archived(env: Env, token_index: u32) -> bool {
+        storage::is_token_archived(&env, token_index)
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -1232,6 +3860,8 @@ impl TokenFactory {
     /// * `Error::TokenNotFound` - Token doesn't exist
     /// * `Error::InvalidAmount` - Amount is zero or negative
     /// * `Error::MaxSupplyExceeded` - Would exceed max supply cap
+    /// * `Error::MintingFinalized` - Minting of the token has ended
+    /// * `Error::HolderLimitReached` - Recipient would exceed the holder cap
     /// * `Error::ArithmeticError` - Overflow in calculation
     /// * `Error::ContractPaused` - Contract is paused
     ///
@@ -1252,13 +3882,13 @@ impl TokenFactory {
         to: Address,
         amount: i128,
     ) -> Result<(), Error> {
+        creator.require_auth();
+
         // Check if contract is paused
         if storage::is_paused(&env) {
             return Err(Error::ContractPaused);
         }
 
-        creator.require_auth();
-
         // Verify creator owns the token
         let token_info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
 
@@ -1267,7 +3897,8 @@ impl TokenFactory {
         }
 
         // Perform mint with max supply validation
-        mint::mint(&env, token_index, &to, amount)
+        mint::mint(&env, token_index, &to, amount)?;
+        fees::charge_op_fee(&env, &creator, types::OpKind::Mint)
     }
 
     /// Get remaining mintable supply for a token
@@ -1277,22 +3908,413 @@ impl TokenFactory {
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_index` - Index of the token
+    /// * `token_index` - Index of the token
+    ///
+    /// # Returns
+    /// * `Some(amount)` - Remaining mintable amount
+    /// * `None` - Unlimited minting (no max supply set)
+    ///
+    /// # Examples
+    /// ```
+    /// match factory.get_remaining_mintable(&env, 0) {
+    ///     Some(0) => log!("Max supply reached"),
+    ///     Some(amount) => log!("Can mint {} more", amount),
+    ///     None => log!("Unlimited minting"),
+    /// }
+    /// ```
+    pub fn get_remaining_mintable(env: Env, token_index: u32) -> Option<i128> {
+        mint::get_remaining_mintable(&env, token_index)
+    }
+
+    /// Permanently end minting of a token (creator only)
+    ///
+    /// Marks the token's supply as final: `mint` and `batch_mint` are
+    /// rejected with `Error::MintingFinalized` afterwards, while burns keep
+    /// working. Finalizing cannot be undone.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `creator` - Token creator (must authorize)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::MintingFinalized` - Minting was already finalized
+    ///
+    /// # Examples
+    /// ```
+    /// factory.finalize_minting(&env, token_addr, creator)?;
+    /// assert!(factory.is_minting_finalized(&env, token_addr));
+    /// ```
+    pub fn finalize_minting(env: Env, token_address: Address, creator: Address) -> Result<(), Error> {
+        mint::finalize_minting(&env, &token_address, &creator)
+    }
+
+    /// Lock tokens for a beneficiary under a cliff + linear schedule
+    ///
+    /// Moves `terms.total` out of the creator's balance into a vesting
+    /// schedule. Nothing can be claimed before `start + cliff`; the vested
+    /// amount then grows linearly from `start` and is complete at
+    /// `start + duration`. Each beneficiary can have one schedule per token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `creator` - Token creator (must authorize)
+    /// * `beneficiary` - Address the tokens vest to
+    /// * `terms` - Amount to lock (> 0), start timestamp, and cliff and
+    ///   duration in seconds after start (cliff <= duration)
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::InvalidAmount` - `total` is not positive
+    /// * `Error::InvalidParameters` - Invalid schedule or one already exists
+    /// * `Error::InsufficientBalance` - Creator holds less than `terms.total`
+    pub fn create_vesting(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        beneficiary: Address,
+        terms: types::VestingTerms,
+    ) -> Result<(), Error> {
+        vesting::create_vesting(&env, &token_address, &creator, &beneficiary, &terms)
+    }
+
+    /// Claim vested tokens (beneficiary only)
+    ///
+    /// Credits everything vested but not yet claimed to the beneficiary's
+    /// balance and returns the amount released, which is 0 when nothing
+    /// new has vested.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - No schedule for this token and beneficiary
+    pub fn claim_vested(env: Env, token_address: Address, beneficiary: Address) -> Result<i128, Error> {
+        vesting::claim_vested(&env, &token_address, &beneficiary)
+    }
+
+    /// Get how much of a beneficiary's schedule has vested, claimed or not
+    pub fn get_vested_amount(env: Env, token_address: Address, beneficiary: Address) -> i128 {
+        vesting::get_vested_amount(&env, &token_address, &beneficiary)
+    }
+
+    /// Get a beneficiary's vesting schedule for a token
+    pub fn get_vesting(
+        env: Env,
+        token_address: Address,
+        beneficiary: Address,
+    ) -> Option<types::VestingSchedule> {
+        storage::get_vesting(&env, &token_address, &beneficiary)
+    }
+
+    /// Get the precision wallets should display a token at
+    ///
+    /// Returns the token's `display_decimals` override, or its `decimals`
+    /// when none was set. Display-only: balances and supply always use
+    /// `decimals`.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found
+    pub fn get_display_decimals(env: Env, token_address: Address) -> Result<u32, Error> {
+        let info =
+            storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)?;
+        Ok(info.display_decimals.unwrap_or(info.decimals))
+    }
+
+    /// Get a token's total supply in human units
+    ///
+    /// Returns `(whole, fraction, decimals)`: the whole-unit part, the raw
+    /// remainder below one unit, and the token's decimals, so that
+    /// `whole * 10^decimals + fraction` is the raw supply. Uses `decimals`,
+    /// not the display override.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found
+    pub fn get_total_supply_human(
+        env: Env,
+        token_address: Address,
+    ) -> Result<(i128, i128, u32), Error> {
+        // Burns update the index record, so read supply from there
+        let index = storage::get_token_index(&env, &token_address).ok_or(Error::TokenNotFound)?;
+        let info = storage::get_token_info(&env, index).ok_or(Error::TokenNotFound)?;
+        let (whole, fraction) = token_creation::split_units(info.total_supply, info.decimals)?;
+        Ok((whole, fraction, info.decimals))
+    }
+
+    /// Get the creator of a token
+    ///
+    /// Cheaper than `get_token_info_by_address(..).creator`: only the
+    /// creator entry is read, not the whole token record.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found
+    pub fn get_token_creator(env: Env, token_address: Address) -> Result<Address, Error> {
+        storage::get_token_creator(&env, &token_address).ok_or(Error::TokenNotFound)
+    }
+
+    /// Check whether `who` created the token (false for unknown tokens)
+    pub fn is_token_creator(env: Env, token_address: Address, who: Address) -> bool {
+        storage::get_token_creator(&env, &token_address).is_some_and(|creator| creator == who)
+    }
+
+    /// Check whether minting of a token has been finalized
+    pub fn is_minting_finalized(env: Env, token_address: Address) -> bool {
+        storage::is_minting_finalized(&env, &token_address)
+    }
+
+    /// Cap how many addresses may hold a token (creator only)
+    ///
+    /// With a cap set, `mint`, `batch_mint` and `transfer_with_memo` reject
+    /// anything that would give a new address a balance once the cap is
+    /// reached; existing holders can always receive more. `None` removes
+    /// the cap. Only balances changed since holder counting was added are
+    /// counted.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenRenounced` - Creator renounced control of the token
+    /// * `Error::InvalidParameters` - Cap is below the current holder count
+    pub fn set_max_holders(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        max_holders: Option<u32>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        if storage::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let token_index =
+            storage::get_token_index(&env, &token_address).ok_or(Error::TokenNotFound)?;
+        let mut info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        if info.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+        if storage::is_token_renounced(&env, &token_address) {
+            return Err(Error::TokenRenounced);
+        }
+        token_creation::ensure_token_unlocked(&env, &token_address)?;
+        if max_holders.is_some_and(|max| max < storage::get_holder_count(&env, token_index)) {
+            return Err(Error::InvalidParameters);
+        }
+
+        info.max_holders = max_holders;
+        storage::set_token_info(&env, token_index, &info);
+        storage::set_token_info_by_address(&env, &token_address, &info);
+        Ok(())
+    }
+
+    /// Get the number of addresses holding a nonzero balance of a token
+    pub fn get_holder_count(env: Env, token_index: u32) -> u32 {
+        storage::get_holder_count(&env, token_index)
+    }
+
+    /// List a token's holders with their balances
+    ///
+    /// For airdrop and governance snapshots. The factory keeps each token's
+    /// list of nonzero-balance holders as balances change. New holders are
+    /// appended; a holder whose balance reaches zero is replaced by the
+    /// last holder in the list. Pages are read by position, so the next
+    /// page starts at `start + limit`; an empty result means the end was
+    /// reached.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_index` - Index of the token
+    /// * `start` - First position in the holder list
+    /// * `limit` - Page size (1-100)
+    pub fn list_holders(
+        env: Env,
+        token_index: u32,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(Address, i128)> {
+        pagination::list_holders(&env, token_index, start, limit)
+    }
+
+    /// Recount a token's holders from a supplied set (admin only)
+    ///
+    /// Maintenance for a holder count that drifted, e.g. after a bug or a
+    /// migration. The contract cannot enumerate balances cheaply, so the
+    /// caller supplies every candidate holder (from an indexer, say); the
+    /// stored count is overwritten with how many distinct candidates hold a
+    /// nonzero balance, and the holder list behind `list_holders` is rebuilt
+    /// from those same candidates. Holders missing from the set are dropped
+    /// from both.
+    ///
+    /// # Returns
+    /// The corrected holder count
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::BatchTooLarge` - More than 100 candidates
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn recompute_holder_count(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        holders: Vec<Address>,
+    ) -> Result<u32, Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+        if holders.len() > MAX_HOLDER_RECOUNT {
+            return Err(Error::BatchTooLarge);
+        }
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+
+        let mut counted: Vec<Address> = Vec::new(&env);
+        for holder in holders.iter() {
+            if !counted.contains(&holder) && storage::get_balance(&env, token_index, &holder) > 0 {
+                counted.push_back(holder);
+            }
+        }
+
+        storage::rebuild_holders(&env, token_index, &counted);
+        Ok(counted.len())
+    }
+
+    /// Choose where `admin_burn` sends a token's clawed-back tokens (creator only)
+    ///
+    /// `ClawbackMode::Burn` (the default) destroys them; `Redirect(address)`
+    /// moves them to a recovery address and keeps the supply unchanged.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenRenounced` - Creator renounced control of the token
+    /// * `Error::InvalidParameters` - Redirect address is the token itself
+    pub fn set_clawback_mode(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        mode: types::ClawbackMode,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        if storage::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let token_index =
+            storage::get_token_index(&env, &token_address).ok_or(Error::TokenNotFound)?;
+        let mut info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        if info.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+        if storage::is_token_renounced(&env, &token_address) {
+            return Err(Error::TokenRenounced);
+        }
+        token_creation::ensure_token_unlocked(&env, &token_address)?;
+        if mode == types::ClawbackMode::Redirect(token_address.clone()) {
+            return Err(Error::InvalidParameters);
+        }
+
+        info.clawback_mode = mode;
+        storage::set_token_info(&env, token_index, &info);
+        storage::set_token_info_by_address(&env, &token_address, &info);
+        Ok(())
+    }
+
+    /// Set the smallest amount one transfer of a token may move (creator only)
+    ///
+    /// `transfer_with_memo` rejects smaller amounts with
+    /// `Error::BelowMinTransfer`. `None` removes the minimum.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenRenounced` - Creator renounced control of the token
+    /// * `Error::InvalidParameters` - Minimum is zero or negative
+    pub fn set_min_transfer(
+        env: Env,
+        token_address: Address,
+        creator: Address,
+        min_transfer: Option<i128>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        if storage::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let token_index =
+            storage::get_token_index(&env, &token_address).ok_or(Error::TokenNotFound)?;
+        let mut info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        if info.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+        if storage::is_token_renounced(&env, &token_address) {
+            return Err(Error::TokenRenounced);
+        }
+        token_creation::ensure_token_unlocked(&env, &token_address)?;
+        if token_creation::validate_min_transfer(min_transfer).is_err() {
+            return Err(Error::InvalidParameters);
+        }
+
+        info.min_transfer = min_transfer;
+        storage::set_token_info(&env, token_index, &info);
+        storage::set_token_info_by_address(&env, &token_address, &info);
+        Ok(())
+    }
+
+    /// Get mint headroom for a token by address
+    ///
+    /// Returns how many more tokens can be minted before hitting the max
+    /// supply, or `i128::MAX` for tokens without a max supply. Tokens whose
+    /// minting has been finalized have no headroom.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    ///
+    /// # Returns
+    /// Returns `Ok(i128)` with the remaining headroom
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found in registry
+    ///
+    /// # Examples
+    /// ```
+    /// let headroom = factory.get_mint_headroom(&env, token_addr)?;
+    /// ```
+    pub fn get_mint_headroom(env: Env, token_address: Address) -> Result<i128, Error> {
+        mint::get_mint_headroom(&env, &token_address)
+    }
+
+    /// Check whether a mint of `amount` would fit under the token's cap
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `amount` - Prospective mint amount
     ///
     /// # Returns
-    /// * `Some(amount)` - Remaining mintable amount
-    /// * `None` - Unlimited minting (no max supply set)
+    /// `true` if the amount is positive and within the mint headroom,
+    /// `false` otherwise (including for unknown tokens)
     ///
     /// # Examples
     /// ```
-    /// match factory.get_remaining_mintable(&env, 0) {
-    ///     Some(0) => log!("Max supply reached"),
-    ///     Some(amount) => log!("Can mint {} more", amount),
-    ///     None => log!("Unlimited minting"),
-    /// }
+    /// if factory.can_mint(&env, token_addr, amount) { /* ... */ }
     /// ```
-    pub fn get_remaining_mintable(env: Env, token_index: u32) -> Option<i128> {
-        mint::get_remaining_mintable(&env, token_index)
+    pub fn can_mint(env: Env, token_address: Address, amount: i128) -> bool {
+        mint::can_mint(&env, &token_address, amount)
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -1358,6 +4380,8 @@ impl TokenFactory {
     /// * `Error::WithdrawalCapExceeded` - Exceeds daily cap
     /// * `Error::RecipientNotAllowed` - Recipient not in allowlist
     /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::InsufficientBalance` - Exceeds the collected fee balance
+    /// * `Error::InvalidParameters` - Recipient is the factory itself
     ///
     /// # Examples
     /// ```
@@ -1373,6 +4397,82 @@ impl TokenFactory {
         treasury::withdraw_fees(&env, &admin, &recipient, amount)
     }
 
+    /// Get collected fees of an asset held for the treasury
+    ///
+    /// Only fees collected by the factory count; tokens sent to the
+    /// contract directly are not included.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `asset` - Fee asset address
+    ///
+    /// # Returns
+    /// The withdrawable fee balance (0 if none was collected)
+    pub fn get_fee_balance(env: Env, asset: Address) -> i128 {
+        storage::get_fee_balance(&env, &asset)
+    }
+
+    /// Get how much of an asset the factory contract holds
+    ///
+    /// Reads the balance from the asset contract itself, so it includes
+    /// collected fees, pending escrows and anything sent to the factory
+    /// directly. Compare with `get_fee_balance` to check fee accounting.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `asset` - Token contract to query
+    ///
+    /// # Returns
+    /// The factory's balance of `asset`
+    pub fn get_contract_balance(env: Env, asset: Address) -> i128 {
+        soroban_sdk::token::Client::new(&env, &asset).balance(&env.current_contract_address())
+    }
+
+    /// Recover tokens sent to the contract outside of fee collection
+    ///
+    /// Moves `amount` of `asset` to `to`, limited to what the contract holds
+    /// above the collected fee balance and pending creation escrows.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `asset` - Token to recover
+    /// * `to` - Address to receive the tokens
+    /// * `amount` - Amount to recover
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not admin
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::InvalidParameters` - `to` is the factory itself
+    /// * `Error::InsufficientBalance` - Amount exceeds the recoverable balance
+    ///
+    /// # Examples
+    /// ```
+    /// factory.rescue_funds(&env, admin, stray_token, admin, 1_000)?;
+    /// ```
+    pub fn rescue_funds(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        fees::rescue(&env, &asset, &to, amount)?;
+        events::emit_funds_rescued(&env, &asset, &to, amount);
+
+        Ok(())
+    }
+
     /// Add recipient to allowlist
     ///
     /// Allows an address to receive treasury withdrawals.
@@ -2176,6 +5276,126 @@ mod campaign_stateful_fuzz_test;
 #[cfg(test)]
 mod accounting_property_test;
 
+#[cfg(test)]
+mod sac_bridge_test;
+
+#[cfg(test)]
+mod bulk_clawback_test;
+
+#[cfg(test)]
+mod token_wasm_hash_test;
+
+#[cfg(test)]
+mod create_escrow_test;
+
+#[cfg(test)]
+mod token_attributes_test;
+
+#[cfg(test)]
+mod validate_token_spec_test;
+
+#[cfg(test)]
+mod burn_from_test;
+
+#[cfg(test)]
+mod fee_burn_split_test;
+
+#[cfg(test)]
+mod symbol_lookup_test;
+
+#[cfg(test)]
+mod batch_burn_diagnostics_test;
+
+#[cfg(test)]
+mod token_kind_test;
+
+#[cfg(test)]
+mod pause_idempotency_test;
+
+#[cfg(test)]
+mod instance_id_test;
+
+#[cfg(test)]
+mod mint_headroom_test;
+
+#[cfg(test)]
+mod fee_balance_test;
+
+#[cfg(test)]
+mod minting_finalized_test;
+
+#[cfg(test)]
+mod max_uri_length_test;
+
+#[cfg(test)]
+mod event_seq_test;
+
+#[cfg(test)]
+mod address_guard_test;
+
+#[cfg(test)]
+mod fee_waiver_test;
+
+#[cfg(test)]
+mod fuzz_support_test;
+
+#[cfg(test)]
+mod min_decimals_test;
+
+#[cfg(test)]
+mod archive_token_test;
+
+#[cfg(test)]
+mod multicall_reads_test;
+
+#[cfg(test)]
+mod rounding_mode_test;
+
+#[cfg(test)]
+mod emergency_shutdown_test;
+
+#[cfg(test)]
+mod time_range_test;
+
+#[cfg(test)]
+mod auth_order_test;
+
+#[cfg(test)]
+mod token_creator_test;
+
+#[cfg(test)]
+mod vesting_schedule_test;
+
+#[cfg(test)]
+mod fee_xlm_test;
+
+#[cfg(test)]
+mod pause_timeout_test;
+
+#[cfg(test)]
+mod transfer_memo_test;
+
+#[cfg(test)]
+mod display_decimals_test;
+
+#[cfg(test)]
+mod fee_reference_test;
+
+#[cfg(test)]
+mod global_supply_cap_test;
+
+#[cfg(test)]
+mod clawback_cooldown_test;
+
+#[cfg(test)]
+mod export_registry_test;
+
+#[cfg(test)]
+mod swap_admin_treasury_test;
+
+#[cfg(test)]
+mod validate_spec_full_test;
+
 #[cfg(all(test, feature = "legacy-tests"))]
 mod vault_cancellation_test;
 
@@ -2186,3 +5406,174 @@ mod vault_cancellation_test;
 
 // #[cfg(test)]
 // mod vault_fuzz_test;
+
+#[cfg(test)]
+mod time_source_test;
+
+#[cfg(test)]
+mod sweep_dust_test;
+
+#[cfg(test)]
+mod version_history_test;
+
+#[cfg(test)]
+mod admin_fee_exempt_test;
+
+#[cfg(test)]
+mod token_lookup_test;
+
+#[cfg(test)]
+mod create_renounced_test;
+
+#[cfg(test)]
+mod max_holders_test;
+
+#[cfg(test)]
+mod upgrade_rollback_test;
+
+#[cfg(test)]
+mod min_transfer_test;
+
+#[cfg(test)]
+mod global_burn_stats_test;
+
+#[cfg(test)]
+mod webhook_topic_test;
+
+#[cfg(test)]
+mod batch_transfer_test;
+
+#[cfg(test)]
+mod lock_token_test;
+
+#[cfg(test)]
+mod reserved_symbol_test;
+
+#[cfg(test)]
+mod token_infos_by_address_test;
+
+#[cfg(test)]
+mod supply_fee_tiers_test;
+
+#[cfg(test)]
+mod create_rate_limit_test;
+
+#[cfg(test)]
+mod admin_config_test;
+
+#[cfg(test)]
+mod simulate_burn_test;
+
+#[cfg(test)]
+mod clawback_mode_test;
+
+#[cfg(test)]
+mod registry_checksum_test;
+
+#[cfg(test)]
+mod reserve_index_test;
+
+#[cfg(test)]
+mod normalize_symbols_test;
+
+#[cfg(test)]
+mod weighted_supply_test;
+
+#[cfg(test)]
+mod signed_waiver_test;
+
+#[cfg(test)]
+mod next_token_index_test;
+
+#[cfg(test)]
+mod max_raw_supply_test;
+
+#[cfg(test)]
+mod metadata_version_test;
+
+#[cfg(test)]
+mod batch_update_admin_test;
+
+#[cfg(test)]
+mod admin_burn_pause_test;
+
+#[cfg(test)]
+mod decimals_map_test;
+
+#[cfg(test)]
+mod self_custody_test;
+
+#[cfg(test)]
+mod initialization_status_test;
+
+#[cfg(test)]
+mod burnable_test;
+
+#[cfg(test)]
+mod zero_decimal_test;
+
+#[cfg(test)]
+mod token_summary_stats_test;
+
+#[cfg(test)]
+mod min_balance_test;
+
+#[cfg(test)]
+mod close_token_test;
+
+#[cfg(test)]
+mod event_payload_version_test;
+
+#[cfg(test)]
+mod fee_collect_test;
+
+#[cfg(test)]
+mod guardian_test;
+
+#[cfg(test)]
+mod fee_quote_test;
+
+#[cfg(test)]
+mod batch_burn_lenient_test;
+
+#[cfg(test)]
+mod events_muted_test;
+
+#[cfg(test)]
+mod holder_recount_test;
+
+#[cfg(test)]
+mod fees_applied_test;
+
+#[cfg(test)]
+mod token_addresses_test;
+
+#[cfg(test)]
+mod min_creator_balance_test;
+
+#[cfg(test)]
+mod holder_list_test;
+
+#[cfg(test)]
+mod approval_queue_test;
+
+#[cfg(test)]
+mod fee_history_test;
+
+#[cfg(test)]
+mod token_gate_test;
+
+#[cfg(test)]
+mod simulate_create_test;
+
+#[cfg(test)]
+mod op_fee_test;
+
+#[cfg(test)]
+mod health_check_test;
+
+#[cfg(test)]
+mod supply_human_test;
+
+#[cfg(test)]
+mod revoke_approvals_test;
@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+const SUPPLY: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let spec = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&env, "SOLID")
+    };
+    let token = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    Setup {
+        env,
+        client,
+        admin,
+        creator,
+        token,
+    }
+}
+
+fn is_locked<T>(result: Result<T, Result<Error, soroban_sdk::InvokeError>>) -> bool {
+    matches!(result, Err(Ok(Error::TokenLocked)))
+}
+
+#[test]
+fn test_every_mutation_rejected_after_lock() {
+    let s = setup();
+    let holder = Address::generate(&s.env);
+    s.client.set_clawback(&s.token, &s.creator, &true);
+    s.client.lock_token(&s.admin, &s.token);
+    assert!(s.client.is_token_locked(&s.token));
+
+    let text = |v: &str| String::from_str(&s.env, v);
+
+    assert!(is_locked(s.client.try_mint(&s.creator, &0, &holder, &10)));
+    assert!(is_locked(s.client.try_burn(&s.creator, &0, &10)));
+    assert!(is_locked(
+        s.client.try_admin_burn(&s.admin, &0, &s.creator, &10)
+    ));
+    assert!(is_locked(s.client.try_batch_burn(
+        &s.admin,
+        &0,
+        &vec![&s.env, (s.creator.clone(), 10)]
+    )));
+    assert!(is_locked(s.client.try_sweep_dust(
+        &s.token,
+        &s.creator,
+        &100,
+        &vec![&s.env, holder.clone()]
+    )));
+    assert!(is_locked(s.client.try_transfer_with_memo(
+        &s.token,
+        &s.creator,
+        &holder,
+        &10,
+        &Bytes::new(&s.env)
+    )));
+    assert!(is_locked(s.client.try_batch_transfer(
+        &s.token,
+        &s.creator,
+        &vec![&s.env, (holder.clone(), 10)]
+    )));
+    assert!(is_locked(
+        s.client.try_set_clawback(&s.token, &s.creator, &false)
+    ));
+    assert!(is_locked(s.client.try_set_token_metadata(
+        &s.creator,
+        &0,
+        &text("ipfs://meta")
+    )));
+    assert!(is_locked(s.client.try_set_token_attribute(
+        &s.token,
+        &s.creator,
+        &text("website"),
+        &text("https://solid.example")
+    )));
+    assert!(is_locked(s.client.try_create_vesting(
+        &s.token,
+        &s.creator,
+        &holder,
+        &types::VestingTerms {
+            total: 100,
+            start: 0,
+            cliff: 0,
+            duration: 10,
+        },
+    )));
+    assert!(is_locked(s.client.try_set_max_holders(
+        &s.token,
+        &s.creator,
+        &Some(10)
+    )));
+}
+
+#[test]
+fn test_reads_still_work() {
+    let s = setup();
+    s.client.lock_token(&s.admin, &s.token);
+
+    let info = s.client.get_token_info_by_address(&s.token);
+    assert_eq!(info.total_supply, SUPPLY);
+    assert_eq!(s.client.get_token_info(&0).address, s.token);
+    assert_eq!(s.client.get_holder_count(&0), 1);
+}
+
+#[test]
+fn test_lock_cannot_be_repeated_or_cleared() {
+    let s = setup();
+    s.client.lock_token(&s.admin, &s.token);
+
+    assert_eq!(
+        s.client.try_lock_token(&s.admin, &s.token),
+        Err(Ok(Error::TokenLocked))
+    );
+    assert!(s.client.is_token_locked(&s.token));
+}
+
+#[test]
+fn test_only_admin_locks_known_tokens() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_lock_token(&s.creator, &s.token),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_lock_token(&s.admin, &Address::generate(&s.env)),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert!(!s.client.is_token_locked(&s.token));
+}
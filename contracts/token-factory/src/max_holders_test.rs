@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+const SUPPLY: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    contract_id: Address,
+    creator: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let spec = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&env, "CAPH")
+    };
+    let token = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        creator,
+        token,
+    }
+}
+
+fn transfer(s: &Setup, from: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    s.client
+        .try_transfer_with_memo(&s.token, from, to, &amount, &Bytes::new(&s.env))
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_holder_count_tracks_balances() {
+    let s = setup();
+    let holder = Address::generate(&s.env);
+    assert_eq!(s.client.get_holder_count(&0), 1);
+
+    transfer(&s, &s.creator, &holder, 100).unwrap();
+    assert_eq!(s.client.get_holder_count(&0), 2);
+
+    transfer(&s, &holder, &s.creator, 100).unwrap();
+    assert_eq!(s.client.get_holder_count(&0), 1);
+}
+
+#[test]
+fn test_cap_blocks_new_holders_only() {
+    let s = setup();
+    let first = Address::generate(&s.env);
+    let third = Address::generate(&s.env);
+    s.client.set_max_holders(&s.token, &s.creator, &Some(2));
+    assert_eq!(s.client.get_token_info(&0).max_holders, Some(2));
+
+    // Creator and `first` fill the cap
+    transfer(&s, &s.creator, &first, 1_000).unwrap();
+
+    assert_eq!(
+        transfer(&s, &first, &third, 10),
+        Err(Error::HolderLimitReached)
+    );
+    assert_eq!(
+        s.client.try_mint(&s.creator, &0, &third, &10),
+        Err(Ok(Error::HolderLimitReached))
+    );
+
+    // Topping up existing holders still works
+    transfer(&s, &s.creator, &first, 1_000).unwrap();
+    s.client.mint(&s.creator, &0, &first, &10);
+    assert_eq!(s.client.get_holder_count(&0), 2);
+}
+
+#[test]
+fn test_handing_over_whole_balance_keeps_count() {
+    let s = setup();
+    let first = Address::generate(&s.env);
+    let successor = Address::generate(&s.env);
+    s.client.set_max_holders(&s.token, &s.creator, &Some(2));
+    transfer(&s, &s.creator, &first, 1_000).unwrap();
+
+    transfer(&s, &first, &successor, 1_000).unwrap();
+
+    assert_eq!(s.client.get_holder_count(&0), 2);
+}
+
+#[test]
+fn test_batch_mint_counts_distinct_new_holders() {
+    let s = setup();
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    s.client.set_max_holders(&s.token, &s.creator, &Some(2));
+
+    let over = vec![&s.env, (a.clone(), 10), (b.clone(), 10)];
+    let result = s
+        .env
+        .as_contract(&s.contract_id, || mint::batch_mint(&s.env, 0, &over));
+    assert_eq!(result, Err(Error::HolderLimitReached));
+
+    // The same new holder twice only takes one slot
+    let repeat = vec![&s.env, (a.clone(), 10), (a.clone(), 10)];
+    s.env
+        .as_contract(&s.contract_id, || mint::batch_mint(&s.env, 0, &repeat))
+        .unwrap();
+    assert_eq!(s.client.get_holder_count(&0), 2);
+}
+
+#[test]
+fn test_set_max_holders_validation() {
+    let s = setup();
+    let holder = Address::generate(&s.env);
+    transfer(&s, &s.creator, &holder, 100).unwrap();
+
+    assert_eq!(
+        s.client.try_set_max_holders(&s.token, &s.creator, &Some(1)),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.try_set_max_holders(&s.token, &holder, &Some(5)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.client.set_max_holders(&s.token, &s.creator, &Some(2));
+    s.client.set_max_holders(&s.token, &s.creator, &None);
+    transfer(&s, &s.creator, &Address::generate(&s.env), 100).unwrap();
+    assert_eq!(s.client.get_holder_count(&0), 3);
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn create(
+    env: &Env,
+    client: &TokenFactoryClient,
+    creator: &Address,
+    symbol: &str,
+    supply: i128,
+) -> Result<Address, Error> {
+    let params = TokenCreationParams {
+        decimals: 18,
+        initial_supply: supply,
+        ..token_params(env, symbol)
+    };
+    client
+        .try_create_token(creator, &params, &BASE_FEE, &TokenKind::Fungible, &None)
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_default_limit_leaves_mint_headroom() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let limit = client.get_max_raw_supply();
+    assert_eq!(limit, i128::MAX / 2);
+
+    assert_eq!(
+        create(&env, &client, &creator, "OVER", limit + 1),
+        Err(Error::InvalidParameters)
+    );
+    create(&env, &client, &creator, "EDGE", limit).unwrap();
+
+    // Minting up to the rest of the i128 range still cannot overflow
+    let holder = Address::generate(&env);
+    client.mint(&creator, &0, &holder, &limit);
+    assert_eq!(client.get_token_info(&0).total_supply, limit * 2);
+}
+
+#[test]
+fn test_configured_limit() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.set_max_raw_supply(&admin, &1_000_000);
+
+    create(&env, &client, &creator, "NEAR", 999_999).unwrap();
+    create(&env, &client, &creator, "EXACT", 1_000_000).unwrap();
+    assert_eq!(
+        create(&env, &client, &creator, "ABOVE", 1_000_001),
+        Err(Error::InvalidParameters)
+    );
+    assert_eq!(client.get_token_count(&true), 2);
+}
+
+#[test]
+fn test_limit_config_is_validated() {
+    let (env, client, admin) = setup();
+
+    assert_eq!(
+        client.try_set_max_raw_supply(&admin, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_set_max_raw_supply(&Address::generate(&env), &1_000),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE, METADATA_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn params(env: &Env, uri: Option<&str>) -> TokenCreationParams {
+    TokenCreationParams {
+        metadata_uri: uri.map(|uri| String::from_str(env, uri)),
+        ..token_params(env, "URI")
+    }
+}
+
+#[test]
+fn test_default_limit_is_256() {
+    let (_env, client, _admin) = setup();
+
+    assert_eq!(client.get_max_uri_length(), 256);
+}
+
+#[test]
+fn test_uri_above_limit_rejected_and_below_accepted() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.set_max_uri_length(&admin, &16);
+
+    let too_long = client.try_batch_create_tokens(
+        &creator,
+        &vec![&env, params(&env, Some("ipfs://QmTooLongForLimit"))],
+        &(BASE_FEE + METADATA_FEE),
+        &None,
+    );
+    assert_eq!(too_long, Err(Ok(Error::InvalidParameters)));
+
+    let token = client
+        .batch_create_tokens(
+            &creator,
+            &vec![&env, params(&env, Some("ipfs://QmShort"))],
+            &(BASE_FEE + METADATA_FEE),
+            &None,
+        )
+        .get(0)
+        .unwrap();
+    assert_eq!(
+        client.get_token_info_by_address(&token).metadata_uri,
+        Some(String::from_str(&env, "ipfs://QmShort"))
+    );
+}
+
+#[test]
+fn test_limit_applies_to_set_token_metadata() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.batch_create_tokens(&creator, &vec![&env, params(&env, None)], &BASE_FEE, &None);
+    client.set_max_uri_length(&admin, &16);
+
+    let result = client.try_set_token_metadata(
+        &creator,
+        &0,
+        &String::from_str(&env, "ipfs://QmTooLongForLimit"),
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_empty_uri_rejected() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+
+    let result = client.try_create_token(
+        &creator,
+        &params(&env, Some("")),
+        &(BASE_FEE + METADATA_FEE),
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_set_max_uri_length_requires_admin() {
+    let (env, client, _admin) = setup();
+
+    let result = client.try_set_max_uri_length(&Address::generate(&env), &16);
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
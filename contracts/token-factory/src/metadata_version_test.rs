@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, Env, String, TryFromVal, Val};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = token_params(&env, "VER");
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    (env, client, creator, token)
+}
+
+/// Decode the `meta_upd` event from the last invocation as
+/// `(token, updater, version)`
+fn last_metadata_updated(env: &Env) -> (Address, Address, u32) {
+    let (_, topics, data) = env
+        .events()
+        .all()
+        .iter()
+        .rev()
+        .find(|(_, topics, _)| {
+            Symbol::try_from_val(env, &topics.get(0).unwrap()).ok()
+                == Some(symbol_short!("meta_upd"))
+        })
+        .expect("meta_upd event not emitted");
+
+    let token = Address::try_from_val(env, &topics.get(1).unwrap()).unwrap();
+    let payload = soroban_sdk::Vec::<Val>::try_from_val(env, &data).unwrap();
+    let updater = Address::try_from_val(env, &payload.get(1).unwrap()).unwrap();
+    let version = u32::try_from_val(env, &payload.get(2).unwrap()).unwrap();
+    (token, updater, version)
+}
+
+#[test]
+fn test_new_token_starts_at_version_zero() {
+    let (_env, client, _creator, token) = setup();
+    assert_eq!(client.get_metadata_version(&token), 0);
+}
+
+#[test]
+fn test_version_increments_and_emits_on_each_update() {
+    let (env, client, creator, token) = setup();
+    let index = client.get_next_token_index() - 1;
+
+    client.set_token_metadata(
+        &creator,
+        &index,
+        &String::from_str(&env, "ipfs://QmVersioned"),
+    );
+    assert_eq!(
+        last_metadata_updated(&env),
+        (token.clone(), creator.clone(), 1)
+    );
+    assert_eq!(client.get_metadata_version(&token), 1);
+
+    client.set_token_attribute(
+        &token,
+        &creator,
+        &String::from_str(&env, "website"),
+        &String::from_str(&env, "https://nova.example"),
+    );
+    assert_eq!(
+        last_metadata_updated(&env),
+        (token.clone(), creator.clone(), 2)
+    );
+    assert_eq!(client.get_metadata_version(&token), 2);
+
+    // Index-keyed record stays in sync with the address-keyed one
+    assert_eq!(client.get_token_info(&index).metadata_version, 2);
+}
+
+#[test]
+fn test_rejected_update_does_not_bump_version() {
+    let (env, client, _creator, token) = setup();
+    let stranger = Address::generate(&env);
+
+    let result = client.try_set_token_attribute(
+        &token,
+        &stranger,
+        &String::from_str(&env, "website"),
+        &String::from_str(&env, "https://nova.example"),
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_metadata_version(&token), 0);
+}
+
+#[test]
+fn test_unknown_token_version_fails() {
+    let (env, client, _creator, _token) = setup();
+    let result = client.try_get_metadata_version(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::TokenNotFound)));
+}
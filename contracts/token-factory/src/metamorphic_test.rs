@@ -117,6 +117,8 @@ proptest! {
             &Some(base_fee),
             &Some(metadata_fee),
             &None,
+            &None,
+            &None,
         ).unwrap();
         
         let state_a = ContractState::capture(&client_a);
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+const MIN_BALANCE: i128 = 100;
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    holder: Address,
+    token: Address,
+    index: u32,
+}
+
+fn params(env: &Env, min_balance: Option<i128>) -> TokenCreationParams {
+    TokenCreationParams {
+        min_balance,
+        ..token_params(env, "DUST")
+    }
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let token = client.create_token(
+        &creator,
+        &params(&env, Some(MIN_BALANCE)),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    let index = client.get_next_token_index() - 1;
+
+    let holder = Address::generate(&env);
+    client.transfer_with_memo(&token, &creator, &holder, &1_000, &Bytes::new(&env));
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        creator,
+        holder,
+        token,
+        index,
+    }
+}
+
+fn balance(s: &Setup, who: &Address) -> i128 {
+    s.env.as_contract(&s.contract_id, || {
+        storage::get_balance(&s.env, s.index, who)
+    })
+}
+
+fn transfer(s: &Setup, to: &Address, amount: i128) -> Result<(), Error> {
+    match s
+        .client
+        .try_transfer_with_memo(&s.token, &s.holder, to, &amount, &Bytes::new(&s.env))
+    {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+#[test]
+fn test_min_balance_recorded_at_creation() {
+    let s = setup();
+    assert_eq!(
+        s.client.get_token_info(&s.index).min_balance,
+        Some(MIN_BALANCE)
+    );
+}
+
+#[test]
+fn test_transfer_leaving_dust_rejected() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    assert_eq!(transfer(&s, &recipient, 950), Err(Error::BelowMinBalance));
+    assert_eq!(balance(&s, &s.holder), 1_000);
+}
+
+#[test]
+fn test_transfer_creating_dust_recipient_rejected() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    assert_eq!(transfer(&s, &recipient, 50), Err(Error::BelowMinBalance));
+    assert_eq!(balance(&s, &recipient), 0);
+}
+
+#[test]
+fn test_transfer_to_zero_or_above_minimum_allowed() {
+    let s = setup();
+    let first = Address::generate(&s.env);
+    let second = Address::generate(&s.env);
+
+    assert_eq!(transfer(&s, &first, 400), Ok(()));
+    assert_eq!(balance(&s, &s.holder), 600);
+
+    assert_eq!(transfer(&s, &second, 600), Ok(()));
+    assert_eq!(balance(&s, &s.holder), 0);
+    assert_eq!(balance(&s, &second), 600);
+}
+
+#[test]
+fn test_batch_transfer_sums_repeated_recipients() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    s.client.batch_transfer(
+        &s.token,
+        &s.holder,
+        &vec![&s.env, (recipient.clone(), 60), (recipient.clone(), 60)],
+    );
+    assert_eq!(balance(&s, &recipient), 120);
+
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_batch_transfer(&s.token, &s.holder, &vec![&s.env, (other, 50)]),
+        Err(Ok(Error::BelowMinBalance))
+    );
+}
+
+#[test]
+fn test_burn_respects_min_balance() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_burn(&s.holder, &s.index, &950),
+        Err(Ok(Error::BelowMinBalance))
+    );
+    s.client.burn(&s.holder, &s.index, &500);
+    s.client.burn(&s.holder, &s.index, &500);
+    assert_eq!(balance(&s, &s.holder), 0);
+}
+
+#[test]
+fn test_non_positive_min_balance_rejected() {
+    let s = setup();
+    let result = s.client.try_create_token(
+        &s.creator,
+        &params(&s.env, Some(0)),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidTokenParams)));
+}
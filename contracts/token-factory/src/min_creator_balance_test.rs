@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{vec, Address, Env, String};
+
+const MIN_BALANCE: i128 = 100_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    native: Address,
+    native_minter: StellarAssetClient<'static>,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    // Stands in for the native XLM asset contract
+    let native = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let native_minter = StellarAssetClient::new(&env, &native);
+
+    Setup {
+        env,
+        client,
+        admin,
+        native,
+        native_minter,
+    }
+}
+
+fn params(env: &Env) -> TokenCreationParams {
+    TokenCreationParams {
+        name: String::from_str(env, "Gated Token"),
+        ..token_params(env, "GATE")
+    }
+}
+
+fn create(s: &Setup, creator: &Address) -> Result<Address, Error> {
+    match s.client.try_create_token(
+        creator,
+        &params(&s.env),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    ) {
+        Ok(Ok(address)) => Ok(address),
+        Err(Ok(error)) => Err(error),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_underfunded_creator_rejected() {
+    let s = setup();
+    s.client
+        .set_min_creator_balance(&s.admin, &s.native, &MIN_BALANCE);
+    let creator = Address::generate(&s.env);
+    s.native_minter.mint(&creator, &(MIN_BALANCE - 1));
+
+    assert_eq!(create(&s, &creator), Err(Error::InsufficientCreatorBalance));
+    assert_eq!(
+        s.client
+            .try_batch_create_tokens(&creator, &vec![&s.env, params(&s.env)], &BASE_FEE, &None),
+        Err(Ok(Error::InsufficientCreatorBalance))
+    );
+    assert_eq!(
+        s.client.try_validate_token_spec(
+            &creator,
+            &String::from_str(&s.env, "Gated Token"),
+            &String::from_str(&s.env, "GATE"),
+            &7,
+            &1_000_000,
+            &None,
+        ),
+        Err(Ok(Error::InsufficientCreatorBalance))
+    );
+}
+
+#[test]
+fn test_funded_creator_succeeds() {
+    let s = setup();
+    s.client
+        .set_min_creator_balance(&s.admin, &s.native, &MIN_BALANCE);
+    let creator = Address::generate(&s.env);
+    s.native_minter.mint(&creator, &MIN_BALANCE);
+
+    assert!(create(&s, &creator).is_ok());
+}
+
+#[test]
+fn test_disabled_check_allows_anyone() {
+    let s = setup();
+    assert_eq!(s.client.get_min_creator_balance(), None);
+    assert!(create(&s, &Address::generate(&s.env)).is_ok());
+
+    s.client
+        .set_min_creator_balance(&s.admin, &s.native, &MIN_BALANCE);
+    s.client.set_min_creator_balance(&s.admin, &s.native, &0);
+
+    assert_eq!(s.client.get_min_creator_balance(), None);
+    assert!(create(&s, &Address::generate(&s.env)).is_ok());
+}
+
+#[test]
+fn test_setter_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_set_min_creator_balance(&Address::generate(&s.env), &s.native, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_set_min_creator_balance(&s.admin, &s.native, &-1),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
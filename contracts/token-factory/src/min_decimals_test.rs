@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn spec(env: &Env, decimals: u32) -> TokenCreationParams {
+    TokenCreationParams {
+        decimals,
+        ..token_params(env, "USDX")
+    }
+}
+
+fn try_create(env: &Env, client: &TokenFactoryClient, decimals: u32) -> Result<Address, Error> {
+    client
+        .try_create_token(
+            &Address::generate(env),
+            &spec(env, decimals),
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_min_decimals_defaults_to_zero() {
+    let (env, client, _admin) = setup();
+
+    assert_eq!(client.get_min_decimals(), 0);
+    assert!(try_create(&env, &client, 0).is_ok());
+}
+
+#[test]
+fn test_decimals_below_floor_rejected() {
+    let (env, client, admin) = setup();
+    client.set_min_decimals(&admin, &2);
+
+    assert_eq!(try_create(&env, &client, 1), Err(Error::InvalidParameters));
+    assert!(try_create(&env, &client, 2).is_ok());
+}
+
+#[test]
+fn test_floor_composes_with_ceiling() {
+    let (env, client, admin) = setup();
+    client.set_min_decimals(&admin, &2);
+
+    assert!(try_create(&env, &client, 18).is_ok());
+    assert_eq!(
+        try_create(&env, &client, 19),
+        Err(Error::InvalidTokenParams)
+    );
+}
+
+#[test]
+fn test_floor_applies_to_batch_creation() {
+    let (env, client, admin) = setup();
+    client.set_min_decimals(&admin, &2);
+
+    let result = client.try_batch_create_tokens(
+        &Address::generate(&env),
+        &vec![&env, spec(&env, 2), spec(&env, 1)],
+        &(2 * BASE_FEE),
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_floor_above_ceiling_rejected() {
+    let (_env, client, admin) = setup();
+
+    assert_eq!(
+        client.try_set_min_decimals(&admin, &19),
+        Err(Ok(Error::InvalidParameters))
+    );
+    client.set_min_decimals(&admin, &18);
+    assert_eq!(client.get_min_decimals(), 18);
+}
+
+#[test]
+fn test_set_min_decimals_requires_admin() {
+    let (env, client, _admin) = setup();
+
+    let result = client.try_set_min_decimals(&Address::generate(&env), &2);
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_min_decimals(), 0);
+}
@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, Env, String};
+
+const MIN: i128 = 1_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    token: Address,
+}
+
+fn spec(env: &Env, min_transfer: Option<i128>) -> TokenCreationParams {
+    TokenCreationParams {
+        min_transfer,
+        ..token_params(env, "NODST")
+    }
+}
+
+fn setup() -> Setup {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let token = client.create_token(
+        &creator,
+        &spec(&env, Some(MIN)),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    Setup {
+        env,
+        client,
+        creator,
+        token,
+    }
+}
+
+fn transfer(s: &Setup, to: &Address, amount: i128) -> Result<(), Error> {
+    s.client
+        .try_transfer_with_memo(&s.token, &s.creator, to, &amount, &Bytes::new(&s.env))
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_minimum_set_at_creation() {
+    let s = setup();
+
+    assert_eq!(s.client.get_token_info(&0).min_transfer, Some(MIN));
+}
+
+#[test]
+fn test_below_minimum_rejected() {
+    let s = setup();
+    let to = Address::generate(&s.env);
+
+    assert_eq!(transfer(&s, &to, MIN - 1), Err(Error::BelowMinTransfer));
+}
+
+#[test]
+fn test_exact_minimum_accepted() {
+    let s = setup();
+    let to = Address::generate(&s.env);
+
+    transfer(&s, &to, MIN).unwrap();
+}
+
+#[test]
+fn test_creator_adjusts_minimum() {
+    let s = setup();
+    let to = Address::generate(&s.env);
+
+    s.client
+        .set_min_transfer(&s.token, &s.creator, &Some(MIN * 2));
+    assert_eq!(transfer(&s, &to, MIN), Err(Error::BelowMinTransfer));
+
+    s.client.set_min_transfer(&s.token, &s.creator, &None);
+    transfer(&s, &to, 1).unwrap();
+}
+
+#[test]
+fn test_non_creator_cannot_set_minimum() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_set_min_transfer(&s.token, &Address::generate(&s.env), &Some(1)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.client.get_token_info(&0).min_transfer, Some(MIN));
+}
+
+#[test]
+fn test_non_positive_minimum_rejected() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_set_min_transfer(&s.token, &s.creator, &Some(0)),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.try_create_token(
+            &s.creator,
+            &spec(&s.env, Some(-1)),
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        ),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+}
@@ -1,5 +1,5 @@
 use crate::storage;
-use crate::types::Error;
+use crate::types::{Error, TokenInfo};
 use soroban_sdk::{Address, Env};
 
 /// Validate max supply constraints
@@ -91,7 +91,11 @@ pub fn validate_max_supply_at_creation(
 /// * `Ok(())` - Mint successful
 /// * `Err(Error::TokenNotFound)` - Token doesn't exist
 /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::MintingFinalized)` - Minting of the token has ended
+/// * `Err(Error::GateTokenRequired)` - Recipient holds none of the gate token
+/// * `Err(Error::HolderLimitReached)` - Recipient would exceed the holder cap
 /// * `Err(Error::MaxSupplyExceeded)` - Would exceed max supply
+/// * `Err(Error::GlobalCapExceeded)` - Would exceed the global supply cap
 /// * `Err(Error::ArithmeticError)` - Overflow in calculation
 pub fn mint(env: &Env, token_index: u32, to: &Address, amount: i128) -> Result<(), Error> {
     // Validate amount
@@ -102,8 +106,18 @@ pub fn mint(env: &Env, token_index: u32, to: &Address, amount: i128) -> Result<(
     // Get token info
     let mut token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
 
+    if storage::is_minting_finalized(env, &token_info.address) {
+        return Err(Error::MintingFinalized);
+    }
+    crate::token_creation::ensure_token_unlocked(env, &token_info.address)?;
+    crate::token_creation::ensure_gate_holder(env, &token_info, to)?;
+
+    let new_holders = u32::from(storage::get_balance(env, token_index, to) == 0);
+    ensure_holder_capacity(env, &token_info, token_index, new_holders)?;
+
     // Validate max supply constraint
     validate_max_supply(token_info.total_supply, amount, token_info.max_supply)?;
+    issue_global_supply(env, amount)?;
 
     // Update total supply with overflow check
     token_info.total_supply = token_info
@@ -151,18 +165,29 @@ pub fn batch_mint(
     let mut token_info = storage::get_token_info(env, token_index)
         .ok_or(Error::TokenNotFound)?;
 
+    if storage::is_minting_finalized(env, &token_info.address) {
+        return Err(Error::MintingFinalized);
+    }
+    crate::token_creation::ensure_token_unlocked(env, &token_info.address)?;
+
     // Validate upfront to preserve atomic/event-noise guarantees.
     let mut total_mint: i128 = 0;
+    let mut new_holders: soroban_sdk::Vec<Address> = soroban_sdk::Vec::new(env);
     for (to, amount) in mints.iter() {
-        let _ = to;
         if amount <= 0 {
             return Err(Error::InvalidAmount);
         }
+        crate::token_creation::ensure_gate_holder(env, &token_info, &to)?;
         total_mint = total_mint
             .checked_add(amount)
             .ok_or(Error::ArithmeticError)?;
+        if storage::get_balance(env, token_index, &to) == 0 && !new_holders.contains(&to) {
+            new_holders.push_back(to);
+        }
     }
+    ensure_holder_capacity(env, &token_info, token_index, new_holders.len())?;
     validate_max_supply(token_info.total_supply, total_mint, token_info.max_supply)?;
+    issue_global_supply(env, total_mint)?;
 
     // Apply mutations in deterministic order and emit per-recipient mint events.
     for (to, amount) in mints.iter() {
@@ -179,7 +204,8 @@ pub fn batch_mint(
         .ok_or(Error::ArithmeticError)?;
     storage::set_token_info(env, token_index, &token_info);
 
-    env.events().publish(
+    crate::events::publish(
+        env,
         (soroban_sdk::symbol_short!("btch_mnt"), token_index),
         (mints.len(), total_mint),
     );
@@ -187,6 +213,85 @@ pub fn batch_mint(
     Ok(())
 }
 
+/// Reject an operation that would add `new_holders` past the holder cap
+///
+/// # Returns
+/// * `Ok(())` - The token is uncapped or has room
+/// * `Err(Error::HolderLimitReached)` - The cap would be exceeded
+pub fn ensure_holder_capacity(
+    env: &Env,
+    token_info: &TokenInfo,
+    token_index: u32,
+    new_holders: u32,
+) -> Result<(), Error> {
+    let Some(max_holders) = token_info.max_holders else {
+        return Ok(());
+    };
+    if new_holders == 0 {
+        return Ok(());
+    }
+    let holders = storage::get_holder_count(env, token_index).saturating_add(new_holders);
+    if holders > max_holders {
+        return Err(Error::HolderLimitReached);
+    }
+    Ok(())
+}
+
+/// Count newly issued tokens towards the factory-wide supply
+///
+/// Called for initial supplies at creation and for every mint, so the
+/// global cap bounds the combined supply of all factory tokens.
+///
+/// # Returns
+/// * `Ok(())` - Supply recorded
+/// * `Err(Error::GlobalCapExceeded)` - Would exceed the global supply cap
+/// * `Err(Error::ArithmeticError)` - Overflow in calculation
+pub fn issue_global_supply(env: &Env, amount: i128) -> Result<(), Error> {
+    let supply = check_global_supply(env, amount)?;
+    storage::set_global_supply(env, supply);
+    Ok(())
+}
+
+/// Check that `amount` more supply fits under the global cap
+///
+/// Returns the factory-wide supply after issuing `amount`, without
+/// recording it.
+///
+/// # Returns
+/// * `Ok(i128)` - Supply after issuing
+/// * `Err(Error::GlobalCapExceeded)` - Would exceed the global supply cap
+/// * `Err(Error::ArithmeticError)` - Overflow in calculation
+pub fn check_global_supply(env: &Env, amount: i128) -> Result<i128, Error> {
+    let supply = storage::get_global_supply(env)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    if let Some(cap) = storage::get_global_supply_cap(env) {
+        if supply > cap {
+            return Err(Error::GlobalCapExceeded);
+        }
+    }
+    Ok(supply)
+}
+
+/// Remove burned tokens from the factory-wide supply
+///
+/// Supply issued before the global total was tracked is not counted, so
+/// the total stops at zero rather than going negative.
+pub fn retire_global_supply(env: &Env, amount: i128) {
+    let supply = storage::get_global_supply(env).saturating_sub(amount).max(0);
+    storage::set_global_supply(env, supply);
+}
+
+/// Get how much more can be issued under the global supply cap
+///
+/// Returns `i128::MAX` when no cap is set.
+pub fn get_global_headroom(env: &Env) -> i128 {
+    match storage::get_global_supply_cap(env) {
+        Some(cap) => cap.saturating_sub(storage::get_global_supply(env)).max(0),
+        None => i128::MAX,
+    }
+}
+
 /// Get remaining mintable supply
 ///
 /// Returns how many more tokens can be minted before hitting the max supply.
@@ -207,6 +312,85 @@ pub fn get_remaining_mintable(env: &Env, token_index: u32) -> Option<i128> {
         .map(|max| max.saturating_sub(token_info.total_supply).max(0))
 }
 
+/// Permanently end minting of a token (creator only)
+///
+/// Burns and balance changes are unaffected; only `mint` and `batch_mint`
+/// are rejected afterwards. There is no way to re-enable minting.
+///
+/// # Returns
+/// * `Ok(())` - Minting finalized
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::MintingFinalized)` - Minting was already finalized
+pub fn finalize_minting(env: &Env, token_address: &Address, creator: &Address) -> Result<(), Error> {
+    creator.require_auth();
+
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let info = storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
+    if info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if storage::is_minting_finalized(env, token_address) {
+        return Err(Error::MintingFinalized);
+    }
+
+    storage::set_minting_finalized(env, token_address);
+
+    // The index-keyed record carries the supply after mints
+    let total_supply = storage::get_token_index(env, token_address)
+        .and_then(|index| storage::get_token_info(env, index))
+        .map_or(info.total_supply, |current| current.total_supply);
+    crate::events::emit_minting_finalized(env, token_address, creator, total_supply);
+
+    Ok(())
+}
+
+/// Get how much more of a token can be minted, by token address
+///
+/// Reads the index-keyed token record, which mints keep current, and falls
+/// back to the address-keyed record for tokens without an index.
+///
+/// The factory-wide supply cap limits the headroom of every token.
+///
+/// # Returns
+/// * `Ok(amount)` - `max_supply - total_supply` (never negative), or the
+///   global headroom if lower
+/// * `Ok(i128::MAX)` - Neither the token nor the factory has a cap
+/// * `Ok(0)` - Minting of the token has been finalized
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+pub fn get_mint_headroom(env: &Env, token_address: &Address) -> Result<i128, Error> {
+    let token_info = storage::get_token_index(env, token_address)
+        .and_then(|index| storage::get_token_info(env, index))
+        .or_else(|| storage::get_token_info_by_address(env, token_address))
+        .ok_or(Error::TokenNotFound)?;
+
+    if storage::is_minting_finalized(env, token_address) {
+        return Ok(0);
+    }
+
+    let token_headroom = match token_info.max_supply {
+        Some(max) => max.saturating_sub(token_info.total_supply).max(0),
+        None => i128::MAX,
+    };
+    Ok(token_headroom.min(get_global_headroom(env)))
+}
+
+/// Check whether minting `amount` of a token would stay within its cap
+///
+/// Returns `false` for unknown tokens and non-positive amounts, which
+/// `mint` rejects as well.
+pub fn can_mint(env: &Env, token_address: &Address, amount: i128) -> bool {
+    if amount <= 0 {
+        return false;
+    }
+    get_mint_headroom(env, token_address).is_ok_and(|headroom| amount <= headroom)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +484,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -338,6 +534,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -376,6 +584,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -414,6 +634,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -447,6 +679,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -480,6 +724,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -512,6 +768,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -543,6 +811,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -574,6 +854,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
 
         env.as_contract(&contract_id, || {
@@ -608,6 +900,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
         env.as_contract(&contract_id, || {
             storage::set_token_info(&env, 0, &token_info);
@@ -653,6 +957,18 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+            token_kind: crate::types::TokenKind::Fungible,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         };
         env.as_contract(&contract_id, || {
             storage::set_token_info(&env, 0, &token_info);
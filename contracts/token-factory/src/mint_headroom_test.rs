@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client)
+}
+
+fn register_token(
+    env: &Env,
+    contract_id: &Address,
+    creator: &Address,
+    max_supply: Option<i128>,
+) -> Address {
+    let token_address = Address::generate(env);
+    let info = TokenInfo {
+        address: token_address.clone(),
+        creator: creator.clone(),
+        name: String::from_str(env, "Capped Token"),
+        symbol: String::from_str(env, "CAP"),
+        decimals: 7,
+        total_supply: 1_000,
+        initial_supply: 1_000,
+        max_supply,
+        total_burned: 0,
+        burn_count: 0,
+        metadata_uri: None,
+        created_at: env.ledger().timestamp(),
+        is_paused: false,
+        clawback_enabled: false,
+        freeze_enabled: false,
+        wasm_hash: BytesN::from_array(env, &[0; 32]),
+        token_kind: TokenKind::Fungible,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
+    };
+    env.as_contract(contract_id, || {
+        let index = storage::increment_token_count(env).unwrap() - 1;
+        storage::set_token_info(env, index, &info);
+        storage::set_token_info_by_address(env, &token_address, &info);
+    });
+    token_address
+}
+
+#[test]
+fn test_headroom_tracks_mints_against_cap() {
+    let (env, contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let token = register_token(&env, &contract_id, &creator, Some(10_000));
+
+    assert_eq!(client.get_mint_headroom(&token), 9_000);
+
+    client.mint(&creator, &0, &Address::generate(&env), &4_000);
+
+    assert_eq!(client.get_mint_headroom(&token), 5_000);
+    assert!(client.can_mint(&token, &5_000));
+    assert!(!client.can_mint(&token, &5_001));
+}
+
+#[test]
+fn test_uncapped_token_has_unbounded_headroom() {
+    let (env, contract_id, client) = setup();
+    let token = register_token(&env, &contract_id, &Address::generate(&env), None);
+
+    assert_eq!(client.get_mint_headroom(&token), i128::MAX);
+    assert!(client.can_mint(&token, &1_000_000_000));
+}
+
+#[test]
+fn test_can_mint_rejects_non_positive_and_unknown() {
+    let (env, contract_id, client) = setup();
+    let token = register_token(&env, &contract_id, &Address::generate(&env), Some(10_000));
+
+    assert!(!client.can_mint(&token, &0));
+    assert!(!client.can_mint(&Address::generate(&env), &1));
+    assert_eq!(
+        client.try_get_mint_headroom(&Address::generate(&env)),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
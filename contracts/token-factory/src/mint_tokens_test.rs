@@ -43,6 +43,16 @@ fn create_token_for_minting(
         metadata_uri: None,
         created_at: env.ledger().timestamp(),
         clawback_enabled: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
     };
 
     let token_index = crate::storage::get_token_count(env);
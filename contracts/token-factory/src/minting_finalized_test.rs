@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+const INITIAL_SUPPLY: i128 = 1_000_000;
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        initial_supply: INITIAL_SUPPLY,
+        ..token_params(&env, "FIN")
+    };
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    (env, contract_id, client, token, creator)
+}
+
+#[test]
+fn test_finalize_blocks_further_mints() {
+    let (env, contract_id, client, token, creator) = setup();
+    let recipient = Address::generate(&env);
+
+    client.mint(&creator, &0, &recipient, &500);
+    assert!(!client.is_minting_finalized(&token));
+
+    client.finalize_minting(&token, &creator);
+
+    assert!(client.is_minting_finalized(&token));
+    assert_eq!(
+        client.try_mint(&creator, &0, &recipient, &1),
+        Err(Ok(Error::MintingFinalized))
+    );
+    let batch = env.as_contract(&contract_id, || {
+        mint::batch_mint(&env, 0, &vec![&env, (recipient.clone(), 1_i128)])
+    });
+    assert_eq!(batch, Err(Error::MintingFinalized));
+    assert_eq!(client.get_mint_headroom(&token), 0);
+    assert_eq!(client.get_token_info(&0).total_supply, INITIAL_SUPPLY + 500);
+}
+
+#[test]
+fn test_burns_still_work_after_finalize() {
+    let (_env, _contract_id, client, token, creator) = setup();
+
+    client.finalize_minting(&token, &creator);
+    client.burn(&creator, &0, &100);
+
+    assert_eq!(client.get_token_info(&0).total_supply, INITIAL_SUPPLY - 100);
+}
+
+#[test]
+fn test_finalize_cannot_be_repeated_or_cleared() {
+    let (_env, _contract_id, client, token, creator) = setup();
+
+    client.finalize_minting(&token, &creator);
+
+    assert_eq!(
+        client.try_finalize_minting(&token, &creator),
+        Err(Ok(Error::MintingFinalized))
+    );
+    assert!(client.is_minting_finalized(&token));
+}
+
+#[test]
+fn test_finalize_requires_creator() {
+    let (env, _contract_id, client, token, _creator) = setup();
+
+    let result = client.try_finalize_minting(&token, &Address::generate(&env));
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert!(!client.is_minting_finalized(&token));
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{vec, Address, Env, String};
+use types::{ReadRequest, ReadResult};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let params = |symbol| token_params(&env, symbol);
+    let creator = Address::generate(&env);
+    let tokens = client.batch_create_tokens(
+        &creator,
+        &vec![&env, params("DSHA"), params("DSHB")],
+        &(2 * BASE_FEE),
+        &None,
+    );
+    client.set_clawback(&tokens.get(1).unwrap(), &creator, &true);
+
+    (env, contract_id, client, admin)
+}
+
+#[test]
+fn test_mixed_reads_match_individual_calls() {
+    let (env, contract_id, client, admin) = setup();
+    let holder = Address::generate(&env);
+    env.as_contract(&contract_id, || storage::set_balance(&env, 1, &holder, 500));
+    client.admin_burn(&admin, &1, &holder, &200);
+
+    let results = client.multicall_reads(&vec![
+        &env,
+        ReadRequest::State,
+        ReadRequest::TokenInfo(0),
+        ReadRequest::BurnCount(1),
+        ReadRequest::SupplyInfo(1),
+        ReadRequest::TokenInfo(1),
+    ]);
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            ReadResult::State(client.get_state()),
+            ReadResult::TokenInfo(client.get_token_info(&0)),
+            ReadResult::BurnCount(client.get_burn_count(&1)),
+            ReadResult::SupplyInfo(client.get_token_stats(&1)),
+            ReadResult::TokenInfo(client.get_token_info(&1)),
+        ]
+    );
+    assert_eq!(client.get_burn_count(&1), 1);
+}
+
+#[test]
+fn test_failed_read_is_reported_in_place() {
+    let (env, _contract_id, client, _admin) = setup();
+
+    let results = client.multicall_reads(&vec![
+        &env,
+        ReadRequest::TokenInfo(7),
+        ReadRequest::BurnCount(0),
+        ReadRequest::SupplyInfo(7),
+    ]);
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            ReadResult::Failed(Error::TokenNotFound),
+            ReadResult::BurnCount(0),
+            ReadResult::Failed(Error::TokenNotFound),
+        ]
+    );
+}
+
+#[test]
+fn test_multicall_does_not_emit_or_write() {
+    let (env, _contract_id, client, _admin) = setup();
+    let seq = client.get_current_event_seq();
+
+    client.multicall_reads(&vec![&env, ReadRequest::State, ReadRequest::TokenInfo(0)]);
+
+    assert_eq!(env.events().all().len(), 0);
+    assert_eq!(client.get_current_event_seq(), seq);
+}
+
+#[test]
+fn test_too_many_reads_rejected() {
+    let (env, _contract_id, client, _admin) = setup();
+    let mut requests = vec![&env];
+    for _ in 0..51 {
+        requests.push_back(ReadRequest::State);
+    }
+
+    assert_eq!(
+        client.try_multicall_reads(&requests),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    requests.pop_back();
+    assert_eq!(client.multicall_reads(&requests).len(), 50);
+}
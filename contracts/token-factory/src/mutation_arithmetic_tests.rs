@@ -205,7 +205,7 @@ fn test_batch_update_admin_rejects_negative_base_fee() {
     client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
     
     // Attempt batch update with negative base fee - should fail
-    client.batch_update_admin(&admin, Some(-1), None, None);
+    client.batch_update_admin(&admin, Some(-1), None, None, None, None);
 }
 
 /// Test: batch_update_admin rejects negative metadata fee
@@ -225,7 +225,7 @@ fn test_batch_update_admin_rejects_negative_metadata_fee() {
     client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
     
     // Attempt batch update with negative metadata fee - should fail
-    client.batch_update_admin(&admin, None, Some(-1), None);
+    client.batch_update_admin(&admin, None, Some(-1), None, None, None);
 }
 
 /// Test: batch_burn validates total doesn't overflow
@@ -397,5 +397,5 @@ fn test_batch_update_admin_requires_at_least_one_param() {
     client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
     
     // Attempt batch update with all None - should fail with InvalidParameters (#9)
-    client.batch_update_admin(&admin, None, None, None);
+    client.batch_update_admin(&admin, None, None, None, None, None);
 }
@@ -127,7 +127,7 @@ fn test_batch_update_admin_requires_auth_no_mock() {
     env.mock_all_auths_allowing_non_root_auth();
     
     // This should panic because auth is not provided
-    client.batch_update_admin(&admin, Some(100_000_000), None, None);
+    client.batch_update_admin(&admin, Some(100_000_000), None, None, None, None);
 }
 
 /// Test: set_clawback requires authentication
@@ -283,7 +283,7 @@ fn test_batch_update_admin_rejects_wrong_admin() {
     client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
     
     // Attempt batch update with wrong admin - should fail with Unauthorized (#2)
-    client.batch_update_admin(&wrong_admin, Some(100_000_000), None, None);
+    client.batch_update_admin(&wrong_admin, Some(100_000_000), None, None, None, None);
 }
 
 /// Test: set_clawback rejects non-creator
@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, creator: &Address, symbol: &str) -> Address {
+    let params = token_params(env, symbol);
+    client.create_token(creator, &params, &BASE_FEE, &TokenKind::Fungible, &None)
+}
+
+#[test]
+fn test_prediction_matches_created_index() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+    assert_eq!(client.get_next_token_index(), 0);
+    assert_eq!(client.get_next_token_index(), client.get_token_count(&true));
+
+    let predicted = client.get_next_token_index();
+    let token = create(&env, &client, &creator, "ONE");
+
+    assert_eq!(client.get_token_info(&predicted).address, token);
+    assert_eq!(client.get_next_token_index(), predicted + 1);
+}
+
+#[test]
+fn test_archived_and_reserved_indices_are_skipped() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    let token = create(&env, &client, &creator, "OLD");
+    client.archive_token(&token, &creator);
+    assert_eq!(client.get_token_count(&false), 0);
+    assert_eq!(client.get_next_token_index(), 1);
+
+    let reserved = client.reserve_index(&creator);
+    assert_eq!(reserved, 1);
+    assert_eq!(client.get_next_token_index(), 2);
+
+    let predicted = client.get_next_token_index();
+    let token = create(&env, &client, &creator, "NEW");
+    assert_eq!(client.get_token_info(&predicted).address, token);
+}
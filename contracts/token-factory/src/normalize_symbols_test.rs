@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, symbol: &str) -> Result<Address, Error> {
+    let creator = Address::generate(env);
+    let params = token_params(env, symbol);
+    client
+        .try_create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None)
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_symbol_stored_uppercase() {
+    let (env, client, admin) = setup();
+    client.set_normalize_symbols(&admin, &true);
+    assert!(client.is_normalize_symbols_enabled());
+
+    create(&env, &client, "abc").unwrap();
+    assert_eq!(
+        client.get_token_info(&0).symbol,
+        String::from_str(&env, "ABC")
+    );
+
+    create(&env, &client, "mIx-9").unwrap();
+    assert_eq!(
+        client.get_token_info(&1).symbol,
+        String::from_str(&env, "MIX-9")
+    );
+}
+
+#[test]
+fn test_symbol_kept_as_given_by_default() {
+    let (env, client, _admin) = setup();
+
+    create(&env, &client, "abc").unwrap();
+    assert_eq!(
+        client.get_token_info(&0).symbol,
+        String::from_str(&env, "abc")
+    );
+}
+
+#[test]
+fn test_unique_mode_is_case_insensitive() {
+    let (env, client, admin) = setup();
+    client.set_normalize_symbols(&admin, &true);
+    client.set_unique_symbols(&admin, &true);
+
+    let token = create(&env, &client, "ABC").unwrap();
+    assert_eq!(create(&env, &client, "abc"), Err(Error::SymbolTaken));
+
+    let found = client.get_token_by_symbol(&String::from_str(&env, "aBc"));
+    assert_eq!(found.address, token);
+}
+
+#[test]
+fn test_only_admin_sets_normalization() {
+    let (env, client, _admin) = setup();
+
+    assert_eq!(
+        client.try_set_normalize_symbols(&Address::generate(&env), &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(!client.is_normalize_symbols_enabled());
+}
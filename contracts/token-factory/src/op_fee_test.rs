@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+use types::OpKind;
+
+const MINT_FEE: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    fee_token: TokenClient<'static>,
+    fee_asset: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let fee_asset = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.set_fee_token(&admin, &fee_asset);
+
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset).mint(&creator, &1_000_000_000);
+
+    let spec = token_params(&env, "OPF");
+    let token = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    let fee_token = TokenClient::new(&env, &fee_asset);
+    Setup {
+        env,
+        client,
+        admin,
+        creator,
+        fee_token,
+        fee_asset,
+        token,
+    }
+}
+
+#[test]
+fn test_mint_fee_goes_to_treasury() {
+    let s = setup();
+    s.client.set_op_fee(&s.admin, &OpKind::Mint, &MINT_FEE);
+    assert_eq!(s.client.get_op_fee(&OpKind::Mint), MINT_FEE);
+
+    let paid_before = s.fee_token.balance(&s.creator);
+    let treasury_before = s.client.get_fee_totals().to_treasury;
+
+    s.client
+        .mint(&s.creator, &0, &Address::generate(&s.env), &500);
+
+    assert_eq!(s.fee_token.balance(&s.creator), paid_before - MINT_FEE);
+    assert_eq!(
+        s.client.get_fee_totals().to_treasury,
+        treasury_before + MINT_FEE
+    );
+    assert_eq!(s.client.get_fee_balance(&s.fee_asset), BASE_FEE + MINT_FEE);
+}
+
+#[test]
+fn test_zero_fee_operations_charge_nothing() {
+    let s = setup();
+    s.client.set_op_fee(&s.admin, &OpKind::Mint, &MINT_FEE);
+    let paid_before = s.fee_token.balance(&s.creator);
+
+    let holder = Address::generate(&s.env);
+    s.client
+        .transfer_with_memo(&s.token, &s.creator, &holder, &100, &Bytes::new(&s.env));
+    s.client.batch_transfer(
+        &s.token,
+        &s.creator,
+        &vec![&s.env, (holder.clone(), 10_i128)],
+    );
+    s.client.set_token_attribute(
+        &s.token,
+        &s.creator,
+        &String::from_str(&s.env, "website"),
+        &String::from_str(&s.env, "https://nova.example"),
+    );
+
+    assert_eq!(s.fee_token.balance(&s.creator), paid_before);
+    assert_eq!(s.client.get_op_fee(&OpKind::Transfer), 0);
+}
+
+#[test]
+fn test_transfer_and_metadata_fees_charge_caller() {
+    let s = setup();
+    s.client.set_op_fee(&s.admin, &OpKind::Transfer, &200);
+    s.client.set_op_fee(&s.admin, &OpKind::Metadata, &300);
+    let paid_before = s.fee_token.balance(&s.creator);
+
+    let holder = Address::generate(&s.env);
+    s.client
+        .transfer_with_memo(&s.token, &s.creator, &holder, &100, &Bytes::new(&s.env));
+    // A batch is one operation
+    s.client.batch_transfer(
+        &s.token,
+        &s.creator,
+        &vec![
+            &s.env,
+            (holder.clone(), 10_i128),
+            (Address::generate(&s.env), 10_i128),
+        ],
+    );
+    s.client
+        .set_token_metadata(&s.creator, &0, &String::from_str(&s.env, "ipfs://op-fee"));
+
+    assert_eq!(s.fee_token.balance(&s.creator), paid_before - 200 * 2 - 300);
+}
+
+#[test]
+fn test_set_op_fee_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_set_op_fee(&Address::generate(&s.env), &OpKind::Mint, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_set_op_fee(&s.admin, &OpKind::Mint, &-1),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    s.client.set_op_fee(&s.admin, &OpKind::Mint, &MINT_FEE);
+    s.client.set_op_fee(&s.admin, &OpKind::Mint, &0);
+    assert_eq!(s.client.get_op_fee(&OpKind::Mint), 0);
+}
@@ -1,11 +1,14 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
 
 use crate::storage;
-use crate::types::{Error, PaginatedTokens, PaginationCursor, TokenInfo};
+use crate::types::{Error, PaginatedTokens, PaginationCursor, TokenInfo, TokenSummary};
 
 const MAX_PAGE_SIZE: u32 = 100;
 const DEFAULT_PAGE_SIZE: u32 = 20;
 const NO_CURSOR: u32 = u32::MAX;
+/// Most creation time buckets (days) a range query may span
+const MAX_TIME_RANGE_BUCKETS: u64 = 366;
 
 pub fn get_tokens_by_creator(
     env: &Env,
@@ -73,6 +76,207 @@ pub fn get_creator_token_count(env: &Env, creator: &Address) -> u32 {
     storage::get_creator_token_count(env, creator)
 }
 
+/// List tokens in index order, skipping archived ones unless asked
+///
+/// Indices are never reused, so the cursor is simply the next token index.
+pub fn list_tokens(
+    env: &Env,
+    cursor: PaginationCursor,
+    limit: Option<u32>,
+    include_archived: bool,
+) -> Result<PaginatedTokens, Error> {
+    let page_size = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let token_count = storage::get_token_count(env);
+
+    let mut index = if cursor.next_index == NO_CURSOR {
+        0
+    } else {
+        cursor.next_index
+    };
+
+    let mut tokens = Vec::new(env);
+    while tokens.len() < page_size && index < token_count {
+        if include_archived || !storage::is_token_archived(env, index) {
+            if let Some(token_info) = storage::get_token_info(env, index) {
+                tokens.push_back(token_info);
+            }
+        }
+        index += 1;
+    }
+
+    let has_more = index < token_count;
+    Ok(PaginatedTokens {
+        tokens,
+        has_more,
+        cursor: PaginationCursor {
+            next_index: if has_more { index } else { NO_CURSOR },
+        },
+    })
+}
+
+/// Compact summaries of tokens `start..start + limit` in index order
+///
+/// Archived tokens are included: the export is a backup of the whole
+/// registry. `limit` is clamped to 1-100.
+pub fn export_registry(env: &Env, start: u32, limit: u32) -> Vec<TokenSummary> {
+    let end = start
+        .saturating_add(limit.clamp(1, MAX_PAGE_SIZE))
+        .min(storage::get_token_count(env));
+
+    let mut summaries = Vec::new(env);
+    for index in start..end {
+        if let Some(info) = storage::get_token_info(env, index) {
+            summaries.push_back(TokenSummary {
+                address: info.address,
+                creator: info.creator,
+                symbol: info.symbol,
+                total_supply: info.total_supply,
+                decimals: info.decimals,
+            });
+        }
+    }
+    summaries
+}
+
+/// Addresses of tokens `start..start + limit` in index order
+///
+/// Bounds match `export_registry`: archived tokens are included, `limit`
+/// is clamped to 1-100, and indices without a token (reserved or closed)
+/// are skipped.
+pub fn list_token_addresses(env: &Env, start: u32, limit: u32) -> Vec<Address> {
+    let end = start
+        .saturating_add(limit.clamp(1, MAX_PAGE_SIZE))
+        .min(storage::get_token_count(env));
+
+    let mut addresses = Vec::new(env);
+    for index in start..end {
+        if let Some(info) = storage::get_token_info(env, index) {
+            addresses.push_back(info.address);
+        }
+    }
+    addresses
+}
+
+/// Holders `start..start + limit` of a token with their balances
+///
+/// New holders are appended to the list. An address whose balance drops
+/// to zero leaves it, and the last holder moves into its position, so
+/// pages can change while a listing is in progress. `limit` is clamped to
+/// 1-100.
+pub fn list_holders(env: &Env, token_index: u32, start: u32, limit: u32) -> Vec<(Address, i128)> {
+    let end = start
+        .saturating_add(limit.clamp(1, MAX_PAGE_SIZE))
+        .min(storage::get_holder_list_len(env, token_index));
+
+    let mut page = Vec::new(env);
+    for position in start..end {
+        if let Some(holder) = storage::get_holder_at(env, token_index, position) {
+            let balance = storage::get_balance(env, token_index, &holder);
+            page.push_back((holder, balance));
+        }
+    }
+    page
+}
+
+/// sha256 over the token count and every token's address, supply and creator
+///
+/// Tokens are hashed in index order with fixed-width supplies and XDR
+/// addresses, so equal registries always produce the same digest. Reads
+/// every token, so the cost grows with the registry.
+pub fn registry_checksum(env: &Env) -> BytesN<32> {
+    let count = storage::get_token_count(env);
+    let mut data = Bytes::from_array(env, &count.to_be_bytes());
+    for index in 0..count {
+        if let Some(info) = storage::get_token_info(env, index) {
+            data.extend_from_array(&index.to_be_bytes());
+            data.append(&info.address.to_xdr(env));
+            data.extend_from_array(&info.total_supply.to_be_bytes());
+            data.append(&info.creator.to_xdr(env));
+        }
+    }
+    env.crypto().sha256(&data).into()
+}
+
+/// Decimals every supply is scaled to by `weighted_supply`
+pub const WEIGHTED_SUPPLY_DECIMALS: u32 = 7;
+
+/// Sum of every token's supply scaled to `WEIGHTED_SUPPLY_DECIMALS`
+///
+/// Tokens with fewer decimals are scaled up exactly. Tokens with more are
+/// scaled down and truncated, so amounts below one base unit are dropped
+/// per token. Archived tokens are included.
+pub fn weighted_supply(env: &Env) -> Result<i128, Error> {
+    let mut total: i128 = 0;
+    for index in 0..storage::get_token_count(env) {
+        let Some(info) = storage::get_token_info(env, index) else {
+            continue;
+        };
+        let scaled = if info.decimals <= WEIGHTED_SUPPLY_DECIMALS {
+            10i128
+                .checked_pow(WEIGHTED_SUPPLY_DECIMALS - info.decimals)
+                .and_then(|factor| info.total_supply.checked_mul(factor))
+        } else {
+            10i128
+                .checked_pow(info.decimals - WEIGHTED_SUPPLY_DECIMALS)
+                .map(|divisor| info.total_supply / divisor)
+        }
+        .ok_or(Error::ArithmeticError)?;
+        total = total.checked_add(scaled).ok_or(Error::ArithmeticError)?;
+    }
+    Ok(total)
+}
+
+/// Indices of tokens created in `[from_ts, to_ts)`, oldest bucket first
+///
+/// Reads the per-day creation buckets, so the work is bounded by the
+/// number of days spanned (at most 366) and by `limit`.
+pub fn list_tokens_by_time_range(
+    env: &Env,
+    from_ts: u64,
+    to_ts: u64,
+    limit: u32,
+) -> Result<Vec<u32>, Error> {
+    if from_ts > to_ts {
+        return Err(Error::InvalidParameters);
+    }
+
+    let mut indices = Vec::new(env);
+    if from_ts == to_ts {
+        return Ok(indices);
+    }
+
+    let first_bucket = from_ts / storage::TIME_BUCKET_SECONDS;
+    let last_bucket = (to_ts - 1) / storage::TIME_BUCKET_SECONDS;
+    if last_bucket - first_bucket >= MAX_TIME_RANGE_BUCKETS {
+        return Err(Error::InvalidParameters);
+    }
+
+    let page_size = limit.clamp(1, MAX_PAGE_SIZE);
+    for bucket in first_bucket..=last_bucket {
+        for index in storage::get_time_bucket_tokens(env, bucket).iter() {
+            if indices.len() == page_size {
+                return Ok(indices);
+            }
+            let in_range = storage::get_token_info(env, index)
+                .is_some_and(|info| info.created_at >= from_ts && info.created_at < to_ts);
+            if in_range {
+                indices.push_back(index);
+            }
+        }
+    }
+    Ok(indices)
+}
+
+/// Number of tokens created, optionally counting archived ones
+pub fn get_token_count(env: &Env, include_archived: bool) -> u32 {
+    let total = storage::get_token_count(env);
+    if include_archived {
+        total
+    } else {
+        total.saturating_sub(storage::get_archived_count(env))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +291,9 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         let contract_id = install_contract(&env);
+        // Registering hundreds of tokens in a single frame exceeds the
+        // default budget
+        env.cost_estimate().budget().reset_unlimited();
         env.as_contract(&contract_id, || {
             for i in 0..token_count {
                 let token_info = TokenInfo {
@@ -105,6 +312,18 @@ mod tests {
                     clawback_enabled: false,
                     is_paused: false,
                     freeze_enabled: false,
+                    wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+                    token_kind: crate::types::TokenKind::Fungible,
+                    display_decimals: None,
+                    created_at_source: crate::types::TimeSource::Timestamp,
+                    max_holders: None,
+                    min_transfer: None,
+                    clawback_mode: crate::types::ClawbackMode::Burn,
+                    metadata_version: 0,
+                    burnable: true,
+                    min_balance: None,
+                    events_muted: false,
+                    gate_token: None,
                 };
                 storage::set_token_info(&env, i, &token_info);
             }
@@ -288,6 +507,18 @@ mod tests {
                     clawback_enabled: false,
                     is_paused: false,
                     freeze_enabled: false,
+                    wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+                    token_kind: crate::types::TokenKind::Fungible,
+                    display_decimals: None,
+                    created_at_source: crate::types::TimeSource::Timestamp,
+                    max_holders: None,
+                    min_transfer: None,
+                    clawback_mode: crate::types::ClawbackMode::Burn,
+                    metadata_version: 0,
+                    burnable: true,
+                    min_balance: None,
+                    events_muted: false,
+                    gate_token: None,
                 };
                 storage::set_token_info(&env, i, &token_info);
             }
@@ -308,6 +539,18 @@ mod tests {
                     clawback_enabled: false,
                     is_paused: false,
                     freeze_enabled: false,
+                    wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+                    token_kind: crate::types::TokenKind::Fungible,
+                    display_decimals: None,
+                    created_at_source: crate::types::TimeSource::Timestamp,
+                    max_holders: None,
+                    min_transfer: None,
+                    clawback_mode: crate::types::ClawbackMode::Burn,
+                    metadata_version: 0,
+                    burnable: true,
+                    min_balance: None,
+                    events_muted: false,
+                    gate_token: None,
                 };
                 storage::set_token_info(&env, i, &token_info);
             }
@@ -0,0 +1,65 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol, Val};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+/// Count events named `name` emitted by the last invocation
+fn count_events(env: &Env, name: Symbol) -> u32 {
+    let name: Val = name.into_val(env);
+    let mut count = 0;
+    for (_, topics, _) in env.events().all().iter() {
+        if let Some(topic) = topics.get(0) {
+            if topic.shallow_eq(&name) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn test_pause_reports_state_change() {
+    let (env, client, admin) = setup();
+
+    assert!(client.pause(&admin));
+    assert_eq!(count_events(&env, symbol_short!("pause_v1")), 1);
+    assert!(client.is_paused());
+
+    assert!(!client.pause(&admin));
+    assert_eq!(count_events(&env, symbol_short!("pause_v1")), 0);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_unpause_reports_state_change() {
+    let (env, client, admin) = setup();
+    client.pause(&admin);
+
+    assert!(client.unpause(&admin));
+    assert_eq!(count_events(&env, symbol_short!("unpaus_v1")), 1);
+    assert!(!client.is_paused());
+
+    assert!(!client.unpause(&admin));
+    assert_eq!(count_events(&env, symbol_short!("unpaus_v1")), 0);
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_redundant_pause_still_requires_admin() {
+    let (env, client, admin) = setup();
+    client.pause(&admin);
+
+    let result = client.try_pause(&Address::generate(&env));
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String};
+use types::{ConfigKey, DataKey};
+
+const START_LEDGER: u32 = 100;
+const UNTIL_LEDGER: u32 = 150;
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+    env.ledger().set_sequence_number(START_LEDGER);
+
+    (env, contract_id, client, admin)
+}
+
+fn create(
+    env: &Env,
+    client: &TokenFactoryClient,
+) -> Result<Vec<Address>, Result<Error, soroban_sdk::InvokeError>> {
+    let params = token_params(env, "TMO");
+    client
+        .try_batch_create_tokens(
+            &Address::generate(env),
+            &vec![env, params],
+            &BASE_FEE,
+            &None,
+        )
+        .map(|created| created.unwrap())
+}
+
+fn raw_pause_flag(env: &Env, contract_id: &Address) -> (bool, Option<u32>) {
+    env.as_contract(contract_id, || {
+        let storage = env.storage().instance();
+        (
+            storage.get(&DataKey::Paused).unwrap_or(false),
+            storage.get(&ConfigKey::PauseExpiry),
+        )
+    })
+}
+
+#[test]
+fn test_operations_blocked_before_expiry() {
+    let (env, _contract_id, client, admin) = setup();
+
+    client.pause_with_timeout(&admin, &UNTIL_LEDGER);
+    assert!(client.is_paused());
+    assert_eq!(client.get_pause_expiry(), Some(UNTIL_LEDGER));
+    assert_eq!(create(&env, &client), Err(Ok(Error::ContractPaused)));
+
+    env.ledger().set_sequence_number(UNTIL_LEDGER - 1);
+    assert!(client.is_paused());
+    assert_eq!(create(&env, &client), Err(Ok(Error::ContractPaused)));
+}
+
+#[test]
+fn test_operations_resume_after_expiry() {
+    let (env, contract_id, client, admin) = setup();
+    client.pause_with_timeout(&admin, &UNTIL_LEDGER);
+
+    env.ledger().set_sequence_number(UNTIL_LEDGER);
+
+    assert_eq!(
+        raw_pause_flag(&env, &contract_id),
+        (true, Some(UNTIL_LEDGER))
+    );
+    assert!(create(&env, &client).is_ok());
+    assert_eq!(raw_pause_flag(&env, &contract_id), (false, None));
+    assert!(!client.is_paused());
+    assert_eq!(client.get_pause_expiry(), None);
+}
+
+#[test]
+fn test_manual_unpause_clears_expiry() {
+    let (env, contract_id, client, admin) = setup();
+    client.pause_with_timeout(&admin, &UNTIL_LEDGER);
+
+    assert!(client.unpause(&admin));
+
+    assert_eq!(raw_pause_flag(&env, &contract_id), (false, None));
+    assert_eq!(client.get_pause_expiry(), None);
+}
+
+#[test]
+fn test_plain_pause_does_not_expire() {
+    let (env, _contract_id, client, admin) = setup();
+    client.pause(&admin);
+
+    env.ledger().set_sequence_number(UNTIL_LEDGER * 10);
+
+    assert!(client.is_paused());
+    assert_eq!(client.get_pause_expiry(), None);
+}
+
+#[test]
+fn test_timeout_must_be_in_future() {
+    let (_env, _contract_id, client, admin) = setup();
+
+    assert_eq!(
+        client.try_pause_with_timeout(&admin, &START_LEDGER),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_pause_with_timeout_requires_admin() {
+    let (env, _contract_id, client, _admin) = setup();
+
+    assert_eq!(
+        client.try_pause_with_timeout(&Address::generate(&env), &UNTIL_LEDGER),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_repeated_pause_clears_expiry() {
+    let (env, contract_id, client, admin) = setup();
+    client.pause_with_timeout(&admin, &UNTIL_LEDGER);
+
+    assert!(!client.pause(&admin));
+
+    assert_eq!(raw_pause_flag(&env, &contract_id), (true, None));
+    env.ledger().set_sequence_number(UNTIL_LEDGER);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_getters_leave_expired_pause_in_storage() {
+    let (env, contract_id, client, admin) = setup();
+    client.pause_with_timeout(&admin, &UNTIL_LEDGER);
+
+    env.ledger().set_sequence_number(UNTIL_LEDGER);
+
+    assert!(!client.is_paused());
+    assert_eq!(client.get_pause_expiry(), None);
+    assert!(!client.health_check().paused);
+    assert!(!client.get_state().paused);
+    assert!(!client.get_admin_config().paused);
+    assert_eq!(
+        raw_pause_flag(&env, &contract_id),
+        (true, Some(UNTIL_LEDGER))
+    );
+}
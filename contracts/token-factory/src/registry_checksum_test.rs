@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    for symbol in ["ONE", "TWO"] {
+        let params = TokenCreationParams {
+            name: String::from_str(&env, "Checked Token"),
+            ..token_params(&env, symbol)
+        };
+        client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+    }
+
+    (env, client, creator)
+}
+
+#[test]
+fn test_reads_leave_checksum_unchanged() {
+    let (_env, client, _creator) = setup();
+    let checksum = client.get_registry_checksum();
+
+    client.get_token_info(&0);
+    client.export_registry(&0, &10);
+
+    assert_eq!(client.get_registry_checksum(), checksum);
+}
+
+#[test]
+fn test_burn_changes_checksum() {
+    let (_env, client, creator) = setup();
+    let checksum = client.get_registry_checksum();
+
+    client.burn(&creator, &1, &1);
+
+    assert_ne!(client.get_registry_checksum(), checksum);
+}
+
+#[test]
+fn test_new_token_changes_checksum() {
+    let (env, client, creator) = setup();
+    let checksum = client.get_registry_checksum();
+
+    let params = TokenCreationParams {
+        name: String::from_str(&env, "Checked Token"),
+        ..token_params(&env, "THREE")
+    };
+    client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    assert_ne!(client.get_registry_checksum(), checksum);
+}
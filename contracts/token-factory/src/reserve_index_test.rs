@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+#[test]
+fn test_token_lands_at_reserved_index() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let index = client.reserve_index(&creator);
+    assert_eq!(index, 0);
+
+    // Tokens created meanwhile take the following indices
+    client.create_token(
+        &other,
+        &token_params(&env, "NEXT"),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    assert_eq!(
+        client.get_token_info(&1).symbol,
+        String::from_str(&env, "NEXT")
+    );
+    assert_eq!(
+        client.try_get_token_info_detailed(&index),
+        Err(Ok(Error::TokenNotYetCreated))
+    );
+
+    let token = client.create_token_at(&creator, &index, &token_params(&env, "RSVD"), &BASE_FEE);
+
+    let info = client.get_token_info(&index);
+    assert_eq!(info.address, token);
+    assert_eq!(info.creator, creator);
+    assert_eq!(info.symbol, String::from_str(&env, "RSVD"));
+    assert_eq!(client.get_token_count(&true), 2);
+}
+
+#[test]
+fn test_reservation_used_once() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    let index = client.reserve_index(&creator);
+    client.create_token_at(&creator, &index, &token_params(&env, "ONCE"), &BASE_FEE);
+
+    assert_eq!(
+        client.try_create_token_at(&creator, &index, &token_params(&env, "TWICE"), &BASE_FEE),
+        Err(Ok(Error::IndexNotReserved))
+    );
+}
+
+#[test]
+fn test_unreserved_index_rejected() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    assert_eq!(
+        client.try_create_token_at(&creator, &5, &token_params(&env, "NONE"), &BASE_FEE),
+        Err(Ok(Error::IndexNotReserved))
+    );
+    assert_eq!(client.get_token_count(&true), 0);
+}
+
+#[test]
+fn test_reservation_belongs_to_creator() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+    let index = client.reserve_index(&creator);
+
+    assert_eq!(
+        client.try_create_token_at(
+            &Address::generate(&env),
+            &index,
+            &token_params(&env, "THEFT"),
+            &BASE_FEE
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_reservation_expires() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+    let index = client.reserve_index(&creator);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += token_creation::INDEX_RESERVATION_LEDGERS;
+    });
+
+    assert_eq!(
+        client.try_create_token_at(&creator, &index, &token_params(&env, "LATE"), &BASE_FEE),
+        Err(Ok(Error::ReservationExpired))
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn spec(env: &Env, symbol: &str) -> TokenCreationParams {
+    TokenCreationParams {
+        name: String::from_str(env, "Stable Coin"),
+        ..token_params(env, symbol)
+    }
+}
+
+#[test]
+fn test_reserved_symbol_blocks_creation_until_removed() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let usdc = String::from_str(&env, "USDC");
+
+    client.add_reserved_symbol(&admin, &usdc);
+    assert!(client.is_symbol_reserved(&usdc));
+
+    let result = client.try_create_token(
+        &creator,
+        &spec(&env, "USDC"),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::SymbolReserved)));
+    assert_eq!(client.get_token_count(&true), 0);
+
+    client.remove_reserved_symbol(&admin, &usdc);
+    assert!(!client.is_symbol_reserved(&usdc));
+
+    client.create_token(
+        &creator,
+        &spec(&env, "USDC"),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    assert_eq!(client.get_token_count(&true), 1);
+}
+
+#[test]
+fn test_other_symbols_unaffected() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+
+    client.add_reserved_symbol(&admin, &String::from_str(&env, "XLM"));
+
+    client.create_token(
+        &creator,
+        &spec(&env, "XLMX"),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    assert_eq!(client.get_token_count(&true), 1);
+}
+
+#[test]
+fn test_reserved_symbol_reported_by_validation() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.add_reserved_symbol(&admin, &String::from_str(&env, "USDC"));
+
+    assert_eq!(
+        client.try_validate_token_spec(
+            &creator,
+            &String::from_str(&env, "Stable Coin"),
+            &String::from_str(&env, "USDC"),
+            &7,
+            &1_000_000,
+            &None,
+        ),
+        Err(Ok(Error::SymbolReserved))
+    );
+    assert!(client
+        .validate_token_spec_full(&spec(&env, "USDC"))
+        .contains(Error::SymbolReserved));
+}
+
+#[test]
+fn test_reserved_symbol_requires_admin() {
+    let (env, client, admin) = setup();
+    let outsider = Address::generate(&env);
+    let usdc = String::from_str(&env, "USDC");
+
+    assert_eq!(
+        client.try_add_reserved_symbol(&outsider, &usdc),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_remove_reserved_symbol(&outsider, &usdc),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_add_reserved_symbol(&admin, &String::from_str(&env, "")),
+        Err(Ok(Error::InvalidSymbol))
+    );
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let owner = Address::generate(&env);
+    let params = token_params(&env, "RVK");
+    let token = client
+        .batch_create_tokens(&owner, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    (env, client, admin, token, owner)
+}
+
+#[test]
+fn test_revoke_all_clears_every_spender() {
+    let (env, client, _admin, token, owner) = setup();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.approve(&token, &owner, &first, &500);
+    client.approve(&token, &owner, &second, &300);
+
+    assert_eq!(client.revoke_all_approvals(&token, &owner), 2);
+
+    for spender in [&first, &second] {
+        assert_eq!(client.get_allowance(&token, &owner, spender), 0);
+        assert_eq!(
+            client.try_burn_from(&token, spender, &owner, &1),
+            Err(Ok(Error::InsufficientAllowance))
+        );
+    }
+    assert_eq!(client.revoke_all_approvals(&token, &owner), 0);
+}
+
+#[test]
+fn test_spender_list_follows_allowances() {
+    let (env, client, _admin, token, owner) = setup();
+    let used_up = Address::generate(&env);
+    let zeroed = Address::generate(&env);
+    let live = Address::generate(&env);
+
+    client.approve(&token, &owner, &used_up, &100);
+    client.approve(&token, &owner, &zeroed, &100);
+    client.approve(&token, &owner, &live, &100);
+    client.approve(&token, &owner, &live, &200);
+    client.burn_from(&token, &used_up, &owner, &100);
+    client.approve(&token, &owner, &zeroed, &0);
+
+    // Only the spender with an allowance left is revoked
+    assert_eq!(client.revoke_all_approvals(&token, &owner), 1);
+    assert_eq!(client.get_allowance(&token, &owner, &live), 0);
+}
+
+#[test]
+fn test_revoke_all_works_while_paused() {
+    let (env, client, admin, token, owner) = setup();
+    let spender = Address::generate(&env);
+    client.approve(&token, &owner, &spender, &500);
+
+    client.pause(&admin);
+
+    assert_eq!(client.revoke_all_approvals(&token, &owner), 1);
+    assert_eq!(client.get_allowance(&token, &owner, &spender), 0);
+    assert_eq!(
+        client.try_revoke_all_approvals(&Address::generate(&env), &owner),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
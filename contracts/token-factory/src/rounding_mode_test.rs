@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::token_params;
+use proptest::prelude::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+use types::RoundingMode;
+
+const DENOMINATOR: i128 = fees::BPS_DENOMINATOR as i128;
+
+/// Reference share computation, written independently of `apply_bps`
+fn reference_bps(amount: i128, bps: u32, mode: RoundingMode) -> i128 {
+    let scaled = amount * bps as i128;
+    match mode {
+        RoundingMode::Floor => scaled / DENOMINATOR,
+        RoundingMode::Ceil => (scaled + DENOMINATOR - 1) / DENOMINATOR,
+        RoundingMode::Nearest => (2 * scaled + DENOMINATOR) / (2 * DENOMINATOR),
+    }
+}
+
+fn mode_strategy() -> impl Strategy<Value = RoundingMode> {
+    prop_oneof![
+        Just(RoundingMode::Floor),
+        Just(RoundingMode::Ceil),
+        Just(RoundingMode::Nearest),
+    ]
+}
+
+proptest! {
+    #![proptest_config(fuzz_support::config(256))]
+
+    #[test]
+    fn prop_apply_bps_matches_reference(
+        amount in 0i128..1_000_000_000_000_000,
+        bps in 0u32..=10_000,
+        mode in mode_strategy(),
+    ) {
+        let share = fees::apply_bps(amount, bps, mode).unwrap();
+        prop_assert_eq!(share, reference_bps(amount, bps, mode));
+    }
+
+    #[test]
+    fn prop_split_conserves_fee(
+        amount in 0i128..1_000_000_000_000_000,
+        bps in 0u32..=10_000,
+        mode in mode_strategy(),
+    ) {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TokenFactory);
+        let (to_treasury, burned) = env.as_contract(&contract_id, || {
+            storage::set_fee_burn_bps(&env, bps);
+            storage::set_rounding_mode(&env, mode);
+            fees::split(&env, amount).unwrap()
+        });
+
+        prop_assert_eq!(to_treasury + burned, amount);
+        prop_assert!(burned >= 0 && burned <= amount);
+    }
+}
+
+#[test]
+fn test_rounding_of_half_share() {
+    // 5000 bps of 10_001 is 5000.5
+    assert_eq!(
+        fees::apply_bps(10_001, 5_000, RoundingMode::Floor),
+        Ok(5_000)
+    );
+    assert_eq!(
+        fees::apply_bps(10_001, 5_000, RoundingMode::Ceil),
+        Ok(5_001)
+    );
+    assert_eq!(
+        fees::apply_bps(10_001, 5_000, RoundingMode::Nearest),
+        Ok(5_001)
+    );
+    // 3333 bps of 10_001 is 3333.33...
+    assert_eq!(
+        fees::apply_bps(10_001, 3_333, RoundingMode::Nearest),
+        Ok(3_333)
+    );
+    assert_eq!(
+        fees::apply_bps(10_001, 3_333, RoundingMode::Ceil),
+        Ok(3_334)
+    );
+}
+
+#[test]
+fn test_apply_bps_overflow() {
+    assert_eq!(
+        fees::apply_bps(i128::MAX, 2, RoundingMode::Floor),
+        Err(Error::ArithmeticError)
+    );
+}
+
+fn collect_one_fee(mode: Option<RoundingMode>) -> types::FeeTotals {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &10_001, &0);
+    client.set_fee_burn_bps(&admin, &5_000);
+    if let Some(mode) = mode {
+        client.set_rounding_mode(&admin, &mode);
+    }
+
+    let params = token_params(&env, "RND");
+    client.batch_create_tokens(
+        &Address::generate(&env),
+        &vec![&env, params],
+        &10_001,
+        &None,
+    );
+
+    client.get_fee_totals()
+}
+
+#[test]
+fn test_floor_is_default() {
+    let totals = collect_one_fee(None);
+
+    assert_eq!(totals.burned, 5_000);
+    assert_eq!(totals.to_treasury, 5_001);
+}
+
+#[test]
+fn test_fee_split_follows_rounding_mode() {
+    for (mode, burned) in [
+        (RoundingMode::Floor, 5_000),
+        (RoundingMode::Ceil, 5_001),
+        (RoundingMode::Nearest, 5_001),
+    ] {
+        let totals = collect_one_fee(Some(mode));
+        assert_eq!(totals.burned, burned);
+        assert_eq!(totals.burned + totals.to_treasury, 10_001);
+    }
+}
+
+#[test]
+fn test_set_rounding_mode_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &1, &0);
+
+    let result = client.try_set_rounding_mode(&Address::generate(&env), &RoundingMode::Ceil);
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_rounding_mode(), RoundingMode::Floor);
+}
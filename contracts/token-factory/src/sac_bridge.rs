@@ -0,0 +1,121 @@
+//! Stellar Asset Contract bridging
+//!
+//! Lets a token creator deploy a native Stellar Asset Contract (SAC) for a
+//! credit asset whose code is the token symbol and whose issuer is the
+//! creator's account, and records it against the factory token.
+//!
+//! The wrapper starts empty. Factory balances are not minted into it or
+//! redeemed from it: it is a separate classic asset that the creator, as
+//! issuer, controls. The factory only guarantees the symbol, decimals and
+//! issuer match and that each token maps to one wrapper.
+//!
+//! SACs always report 7 decimals, so only tokens created with 7 decimals can
+//! be wrapped; anything else would display balances at the wrong scale.
+
+use crate::events;
+use crate::storage;
+use crate::types::Error;
+use soroban_sdk::address_payload::AddressPayload;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Bytes, Env, String};
+
+/// Decimals used by every Stellar Asset Contract
+pub const SAC_DECIMALS: u32 = 7;
+
+const ASSET_TYPE_CREDIT_ALPHANUM4: u32 = 1;
+const ASSET_TYPE_CREDIT_ALPHANUM12: u32 = 2;
+const PUBLIC_KEY_TYPE_ED25519: u32 = 0;
+
+/// Deploy (or return the existing) SAC wrapper for a factory token
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token_address` - Factory token to wrap
+/// * `creator` - Token creator (must authorize and be a classic account)
+///
+/// # Returns
+/// * `Ok(Address)` - The SAC wrapper address
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InvalidTokenParams)` - Decimals or symbol not SAC-compatible
+/// * `Err(Error::InvalidParameters)` - Creator is not an account address
+/// * `Err(Error::SymbolTaken)` - A SAC for this symbol and creator already
+///   exists, deployed for another token or outside the factory
+pub fn wrap_as_sac(env: &Env, token_address: &Address, creator: &Address) -> Result<Address, Error> {
+    creator.require_auth();
+
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let info = storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
+    if info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Idempotent: a token maps to exactly one wrapper
+    if let Some(existing) = storage::get_sac_wrapper(env, token_address) {
+        return Ok(existing);
+    }
+
+    if info.decimals != SAC_DECIMALS {
+        return Err(Error::InvalidTokenParams);
+    }
+
+    let asset = serialize_asset(env, &info.symbol, creator)?;
+    let deployer = env.deployer().with_stellar_asset(asset);
+    let wrapper = deployer.deployed_address();
+    // Deploying over an existing SAC traps, so report it instead
+    if storage::get_sac_wrapped_token(env, &wrapper).is_some()
+        || TokenClient::new(env, &wrapper).try_decimals().is_ok()
+    {
+        return Err(Error::SymbolTaken);
+    }
+    deployer.deploy();
+
+    storage::set_sac_wrapper(env, token_address, &wrapper);
+    events::emit_sac_wrapped(env, token_address, creator, &wrapper);
+
+    Ok(wrapper)
+}
+
+/// Build the XDR-encoded `Asset` for a token symbol issued by `issuer`
+///
+/// Symbols of up to 4 characters use `CREDIT_ALPHANUM4`, longer symbols
+/// (up to 12) use `CREDIT_ALPHANUM12`. Asset codes must be ASCII
+/// alphanumeric.
+fn serialize_asset(
+    env: &Env,
+    symbol: &String,
+    issuer: &Address,
+) -> Result<Bytes, Error> {
+    let len = symbol.len() as usize;
+    if len == 0 || len > 12 {
+        return Err(Error::InvalidTokenParams);
+    }
+
+    let mut code = [0u8; 12];
+    symbol.copy_into_slice(&mut code[..len]);
+    if !code[..len].iter().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(Error::InvalidTokenParams);
+    }
+
+    let issuer_key = match AddressPayload::from_address(issuer) {
+        Some(AddressPayload::AccountIdPublicKeyEd25519(key)) => key,
+        _ => return Err(Error::InvalidParameters),
+    };
+
+    let mut asset = Bytes::new(env);
+    if len <= 4 {
+        asset.extend_from_array(&ASSET_TYPE_CREDIT_ALPHANUM4.to_be_bytes());
+        asset.extend_from_slice(&code[..4]);
+    } else {
+        asset.extend_from_array(&ASSET_TYPE_CREDIT_ALPHANUM12.to_be_bytes());
+        asset.extend_from_slice(&code);
+    }
+    asset.extend_from_array(&PUBLIC_KEY_TYPE_ED25519.to_be_bytes());
+    asset.append(&Bytes::from(issuer_key));
+
+    Ok(asset)
+}
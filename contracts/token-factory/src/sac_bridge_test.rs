@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::address_payload::AddressPayload;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn account_address(env: &Env, seed: u8) -> Address {
+    AddressPayload::AccountIdPublicKeyEd25519(BytesN::from_array(env, &[seed; 32])).to_address(env)
+}
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client)
+}
+
+fn register_token(
+    env: &Env,
+    contract_id: &Address,
+    creator: &Address,
+    symbol: &str,
+    decimals: u32,
+) -> Address {
+    let token_address = Address::generate(env);
+    let info = TokenInfo {
+        address: token_address.clone(),
+        creator: creator.clone(),
+        name: String::from_str(env, "Bridge Token"),
+        symbol: String::from_str(env, symbol),
+        decimals,
+        total_supply: 1_000_000_0000000,
+        initial_supply: 1_000_000_0000000,
+        max_supply: None,
+        total_burned: 0,
+        burn_count: 0,
+        metadata_uri: None,
+        created_at: env.ledger().timestamp(),
+        is_paused: false,
+        clawback_enabled: false,
+        freeze_enabled: false,
+        wasm_hash: BytesN::from_array(env, &[0; 32]),
+        token_kind: TokenKind::Fungible,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
+    };
+    env.as_contract(contract_id, || {
+        storage::set_token_info_by_address(env, &token_address, &info);
+    });
+    token_address
+}
+
+#[test]
+fn test_wrap_as_sac_records_mapping() {
+    let (env, contract_id, client) = setup();
+    let creator = account_address(&env, 1);
+    let token = register_token(&env, &contract_id, &creator, "NOVA", 7);
+
+    assert_eq!(client.get_sac_wrapper(&token), None);
+
+    let wrapper = client.wrap_as_sac(&token, &creator);
+
+    assert_eq!(client.get_sac_wrapper(&token), Some(wrapper.clone()));
+    let sac = soroban_sdk::token::TokenClient::new(&env, &wrapper);
+    assert_eq!(sac.decimals(), 7);
+    assert_eq!(sac.symbol(), String::from_str(&env, "NOVA"));
+}
+
+#[test]
+fn test_wrap_as_sac_long_symbol() {
+    let (env, contract_id, client) = setup();
+    let creator = account_address(&env, 2);
+    let token = register_token(&env, &contract_id, &creator, "NOVALAUNCH", 7);
+
+    let wrapper = client.wrap_as_sac(&token, &creator);
+
+    let sac = soroban_sdk::token::TokenClient::new(&env, &wrapper);
+    assert_eq!(sac.symbol(), String::from_str(&env, "NOVALAUNCH"));
+}
+
+#[test]
+fn test_wrap_as_sac_is_idempotent() {
+    let (env, contract_id, client) = setup();
+    let creator = account_address(&env, 3);
+    let token = register_token(&env, &contract_id, &creator, "NOVA", 7);
+
+    let first = client.wrap_as_sac(&token, &creator);
+    let second = client.wrap_as_sac(&token, &creator);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_wrap_as_sac_rejects_non_creator() {
+    let (env, contract_id, client) = setup();
+    let creator = account_address(&env, 4);
+    let other = account_address(&env, 5);
+    let token = register_token(&env, &contract_id, &creator, "NOVA", 7);
+
+    let result = client.try_wrap_as_sac(&token, &other);
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_sac_wrapper(&token), None);
+}
+
+#[test]
+fn test_wrap_as_sac_rejects_non_sac_decimals() {
+    let (env, contract_id, client) = setup();
+    let creator = account_address(&env, 6);
+    let token = register_token(&env, &contract_id, &creator, "NOVA", 18);
+
+    let result = client.try_wrap_as_sac(&token, &creator);
+
+    assert_eq!(result, Err(Ok(Error::InvalidTokenParams)));
+}
+
+#[test]
+fn test_wrap_as_sac_unknown_token() {
+    let (env, _contract_id, client) = setup();
+    let creator = account_address(&env, 7);
+
+    let result = client.try_wrap_as_sac(&Address::generate(&env), &creator);
+
+    assert_eq!(result, Err(Ok(Error::TokenNotFound)));
+}
+
+#[test]
+fn test_wrap_as_sac_rejects_taken_asset() {
+    let (env, contract_id, client) = setup();
+    let creator = account_address(&env, 8);
+    let first = register_token(&env, &contract_id, &creator, "NOVA", 7);
+    let second = register_token(&env, &contract_id, &creator, "NOVA", 7);
+    client.wrap_as_sac(&first, &creator);
+
+    let result = client.try_wrap_as_sac(&second, &creator);
+
+    assert_eq!(result, Err(Ok(Error::SymbolTaken)));
+    assert_eq!(client.get_sac_wrapper(&second), None);
+}
+
+#[test]
+fn test_wrap_as_sac_rejects_asset_deployed_elsewhere() {
+    let (env, contract_id, client) = setup();
+    let creator = account_address(&env, 9);
+    let first = register_token(&env, &contract_id, &creator, "NOVA", 7);
+    let second = register_token(&env, &contract_id, &creator, "NOVA", 7);
+    let wrapper = client.wrap_as_sac(&first, &creator);
+    // Forget the wrapper so only the deployed SAC remains
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&types::TokenKey::SacWrappedToken(wrapper));
+    });
+
+    let result = client.try_wrap_as_sac(&second, &creator);
+
+    assert_eq!(result, Err(Ok(Error::SymbolTaken)));
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    treasury: Address,
+    holder: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        treasury,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = token_params(&env, "CSTD");
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    let holder = Address::generate(&env);
+    client.transfer_with_memo(&token, &creator, &holder, &10_000, &Bytes::new(&env));
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        treasury,
+        holder,
+        token,
+    }
+}
+
+fn balance(s: &Setup, who: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, 0, who))
+}
+
+#[test]
+fn test_transfer_to_factory_rejected() {
+    let s = setup();
+    let result = s.client.try_transfer_with_memo(
+        &s.token,
+        &s.holder,
+        &s.contract_id,
+        &100,
+        &Bytes::new(&s.env),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    let result = s.client.try_batch_transfer(
+        &s.token,
+        &s.holder,
+        &vec![&s.env, (s.contract_id.clone(), 100)],
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    assert_eq!(balance(&s, &s.holder), 10_000);
+}
+
+#[test]
+fn test_burn_by_factory_rejected() {
+    let s = setup();
+    s.env.as_contract(&s.contract_id, || {
+        storage::set_balance(&s.env, 0, &s.contract_id, 500)
+    });
+
+    assert_eq!(
+        s.client.try_burn(&s.contract_id, &0, &100),
+        Err(Ok(Error::InvalidParameters))
+    );
+    let result = s.client.try_transfer_with_memo(
+        &s.token,
+        &s.contract_id,
+        &s.holder,
+        &100,
+        &Bytes::new(&s.env),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    assert_eq!(balance(&s, &s.contract_id), 500);
+}
+
+#[test]
+fn test_treasury_is_an_ordinary_holder() {
+    let s = setup();
+
+    s.client.transfer_with_memo(
+        &s.token,
+        &s.holder,
+        &s.treasury,
+        &1_000,
+        &Bytes::new(&s.env),
+    );
+    s.client.burn(&s.treasury, &0, &400);
+
+    assert_eq!(balance(&s, &s.treasury), 600);
+    assert_eq!(balance(&s, &s.holder), 9_000);
+}
@@ -45,6 +45,16 @@ fn create_token_without_metadata(
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
         
         };
 
@@ -65,7 +75,7 @@ fn test_set_metadata_success() {
     let metadata_uri = String::from_str(&env, "ipfs://QmTest1234567890");
 
     // Set metadata should succeed
-    client.set_metadata(&token_index, &creator, &metadata_uri);
+    client.set_metadata(&token_index, &creator, &metadata_uri, &None);
 
     // Verify metadata was set
     let token_info = client.get_token_info(&token_index);
@@ -82,10 +92,10 @@ fn test_set_metadata_immutability() {
     let new_uri = String::from_str(&env, "ipfs://QmNew456");
 
     // Set metadata first time - should succeed
-    client.set_metadata(&token_index, &creator, &initial_uri);
+    client.set_metadata(&token_index, &creator, &initial_uri, &None);
 
     // Attempt to change metadata - should fail
-    let result = client.try_set_metadata(&token_index, &creator, &new_uri);
+    let result = client.try_set_metadata(&token_index, &creator, &new_uri, &None);
     assert!(result.is_err());
 
     // Verify original metadata remains unchanged
@@ -103,7 +113,7 @@ fn test_set_metadata_unauthorized() {
     let metadata_uri = String::from_str(&env, "ipfs://QmTest1234567890");
 
     // Attempt to set metadata by non-creator should fail
-    let result = client.try_set_metadata(&token_index, &unauthorized_user, &metadata_uri);
+    let result = client.try_set_metadata(&token_index, &unauthorized_user, &metadata_uri, &None);
     assert!(result.is_err());
 
     // Verify metadata was not set
@@ -119,7 +129,7 @@ fn test_set_metadata_token_not_found() {
     let metadata_uri = String::from_str(&env, "ipfs://QmTest1234567890");
 
     // Attempt to set metadata for non-existent token
-    let result = client.try_set_metadata(&999, &creator, &metadata_uri);
+    let result = client.try_set_metadata(&999, &creator, &metadata_uri, &None);
     assert!(result.is_err());
 }
 
@@ -135,7 +145,7 @@ fn test_set_metadata_when_paused() {
     let metadata_uri = String::from_str(&env, "ipfs://QmTest1234567890");
 
     // Attempt to set metadata while paused should fail
-    let result = client.try_set_metadata(&token_index, &creator, &metadata_uri);
+    let result = client.try_set_metadata(&token_index, &creator, &metadata_uri, &None);
     assert!(result.is_err());
 
     // Verify metadata was not set
@@ -160,7 +170,7 @@ fn test_set_metadata_various_uri_formats() {
         let metadata_uri = String::from_str(&env, uri_str);
 
         // Set metadata should succeed for all formats
-        client.set_metadata(&token_index, &creator, &metadata_uri);
+        client.set_metadata(&token_index, &creator, &metadata_uri, &None);
 
         // Verify metadata was set correctly
         let token_info = client.get_token_info(&token_index);
@@ -177,7 +187,7 @@ fn test_set_metadata_event_emission() {
     let metadata_uri = String::from_str(&env, "ipfs://QmTest1234567890");
 
     // Set metadata
-    client.set_metadata(&token_index, &creator, &metadata_uri);
+    client.set_metadata(&token_index, &creator, &metadata_uri, &None);
 
     // Note: Event verification would require accessing env.events() which may not be available
     // in all test contexts. This test verifies the operation completes successfully.
@@ -196,7 +206,7 @@ fn test_set_metadata_updates_both_lookups() {
     let token_address = token_info_before.address.clone();
 
     // Set metadata
-    client.set_metadata(&token_index, &creator, &metadata_uri);
+    client.set_metadata(&token_index, &creator, &metadata_uri, &None);
 
     // Verify metadata is accessible via index lookup
     let token_info_by_index = client.get_token_info(&token_index);
@@ -216,7 +226,7 @@ fn test_set_metadata_empty_string() {
     let empty_uri = String::from_str(&env, "");
 
     // Setting empty string should succeed (validation is caller's responsibility)
-    client.set_metadata(&token_index, &creator, &empty_uri);
+    client.set_metadata(&token_index, &creator, &empty_uri, &None);
 
     // Verify empty metadata was set
     let token_info = client.get_token_info(&token_index);
@@ -238,9 +248,9 @@ fn test_set_metadata_multiple_tokens() {
     let uri3 = String::from_str(&env, "ipfs://QmToken3");
 
     // Set metadata for each token
-    client.set_metadata(&token1, &creator, &uri1);
-    client.set_metadata(&token2, &creator, &uri2);
-    client.set_metadata(&token3, &creator, &uri3);
+    client.set_metadata(&token1, &creator, &uri1, &None);
+    client.set_metadata(&token2, &creator, &uri2, &None);
+    client.set_metadata(&token3, &creator, &uri3, &None);
 
     // Verify each token has correct metadata
     assert_eq!(client.get_token_info(&token1).metadata_uri, Some(uri1));
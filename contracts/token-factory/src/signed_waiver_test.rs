@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, BytesN, Env, String};
+use types::SignedWaiver;
+
+const NOW: u64 = 1_700_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    signer: SigningKey,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+    client.set_waiver_signer(&admin, &public_key);
+
+    Setup {
+        creator: Address::generate(&env),
+        env,
+        client,
+        signer,
+    }
+}
+
+fn sign(s: &Setup, creator: &Address, nonce: u64, expires_at: u64) -> SignedWaiver {
+    let message: std::vec::Vec<u8> = s
+        .client
+        .waiver_message(creator, &nonce, &expires_at)
+        .iter()
+        .collect();
+    SignedWaiver {
+        nonce,
+        expires_at,
+        signature: BytesN::from_array(&s.env, &s.signer.sign(&message).to_bytes()),
+    }
+}
+
+fn spec(s: &Setup, symbol: &str) -> TokenCreationParams {
+    token_params(&s.env, symbol)
+}
+
+fn create(s: &Setup, symbol: &str, fee: i128) -> Result<Address, Error> {
+    s.client
+        .try_create_token(
+            &s.creator,
+            &spec(s, symbol),
+            &fee,
+            &TokenKind::Fungible,
+            &None,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_signed_waiver_makes_next_creation_free() {
+    let s = setup();
+    assert_eq!(create(&s, "PAID", 0), Err(Error::InsufficientFee));
+
+    s.client
+        .redeem_signed_waiver(&s.creator, &sign(&s, &s.creator, 1, NOW + 60));
+    create(&s, "FREE", 0).unwrap();
+
+    // The waiver covers a single creation
+    assert_eq!(create(&s, "AGAIN", 0), Err(Error::InsufficientFee));
+}
+
+#[test]
+fn test_replayed_nonce_rejected() {
+    let s = setup();
+    let waiver = sign(&s, &s.creator, 42, NOW + 60);
+    s.client.redeem_signed_waiver(&s.creator, &waiver);
+
+    assert_eq!(
+        s.client.try_redeem_signed_waiver(&s.creator, &waiver),
+        Err(Ok(Error::WaiverNonceUsed))
+    );
+}
+
+#[test]
+fn test_expired_waiver_rejected() {
+    let s = setup();
+    let waiver = sign(&s, &s.creator, 1, NOW + 60);
+    s.env.ledger().with_mut(|li| li.timestamp = NOW + 60);
+
+    assert_eq!(
+        s.client.try_redeem_signed_waiver(&s.creator, &waiver),
+        Err(Ok(Error::WaiverExpired))
+    );
+    assert_eq!(create(&s, "LATE", 0), Err(Error::InsufficientFee));
+}
+
+#[test]
+fn test_waiver_bound_to_creator() {
+    let s = setup();
+    let other = Address::generate(&s.env);
+    let waiver = sign(&s, &other, 1, NOW + 60);
+
+    assert!(s
+        .client
+        .try_redeem_signed_waiver(&s.creator, &waiver)
+        .is_err());
+    assert_eq!(create(&s, "STOLEN", 0), Err(Error::InsufficientFee));
+}
+
+#[test]
+fn test_batch_takes_one_credit_per_token() {
+    let s = setup();
+    s.client
+        .redeem_signed_waiver(&s.creator, &sign(&s, &s.creator, 1, NOW + 60));
+
+    // One credit cannot cover two tokens
+    let tokens = vec![&s.env, spec(&s, "ONE"), spec(&s, "TWO")];
+    assert_eq!(
+        s.client
+            .try_batch_create_tokens(&s.creator, &tokens, &0, &None),
+        Err(Ok(Error::InsufficientFee))
+    );
+
+    s.client
+        .redeem_signed_waiver(&s.creator, &sign(&s, &s.creator, 2, NOW + 60));
+    s.client.batch_create_tokens(&s.creator, &tokens, &0, &None);
+    assert_eq!(s.client.get_token_count(&true), 2);
+
+    assert_eq!(create(&s, "AGAIN", 0), Err(Error::InsufficientFee));
+}
+
+#[test]
+fn test_escrow_takes_credit() {
+    let s = setup();
+    s.client
+        .redeem_signed_waiver(&s.creator, &sign(&s, &s.creator, 1, NOW + 60));
+
+    let escrow_id = s
+        .client
+        .begin_create(&s.creator, &spec(&s, "ESC"), &0, &None);
+    s.client.finalize_create(&s.creator, &escrow_id);
+    assert_eq!(s.client.get_token_count(&true), 1);
+
+    assert_eq!(
+        s.client
+            .try_begin_create(&s.creator, &spec(&s, "ESC2"), &0, &None),
+        Err(Ok(Error::InsufficientFee))
+    );
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+const SUPPLY: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&env, "PREV")
+    };
+    let token = client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    Setup {
+        env,
+        client,
+        creator,
+        token,
+    }
+}
+
+#[test]
+fn test_preview_matches_actual_burn() {
+    let s = setup();
+    s.client.burn(&s.creator, &0, &10_000);
+
+    let preview = s.client.simulate_burn(&s.token, &s.creator, &25_000);
+    assert_eq!(s.client.get_token_stats(&0).current_supply, SUPPLY - 10_000);
+
+    s.client.burn(&s.creator, &0, &25_000);
+    assert_eq!(preview, s.client.get_token_stats(&0));
+    assert_eq!(preview.current_supply, SUPPLY - 35_000);
+    assert_eq!(preview.total_burned, 35_000);
+    assert_eq!(preview.burn_count, 2);
+}
+
+#[test]
+fn test_over_balance_preview_fails_without_state_change() {
+    let s = setup();
+    let before = s.client.get_token_stats(&0);
+
+    assert_eq!(
+        s.client
+            .try_simulate_burn(&s.token, &s.creator, &(SUPPLY + 1)),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        s.client.try_burn(&s.creator, &0, &(SUPPLY + 1)),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(s.client.get_token_stats(&0), before);
+}
+
+#[test]
+fn test_preview_mirrors_burn_errors() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+
+    for amount in [0, -5] {
+        assert_eq!(
+            s.client.try_simulate_burn(&s.token, &s.creator, &amount),
+            Err(Ok(Error::InvalidParameters))
+        );
+        assert_eq!(
+            s.client.try_burn(&s.creator, &0, &amount),
+            Err(Ok(Error::InvalidParameters))
+        );
+    }
+    assert_eq!(
+        s.client.try_simulate_burn(&s.token, &stranger, &1),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        s.client.try_simulate_burn(&stranger, &s.creator, &1),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    (env, client, admin, creator)
+}
+
+fn spec(env: &Env, name: &str) -> TokenCreationParams {
+    TokenCreationParams {
+        name: String::from_str(env, name),
+        ..token_params(env, "SIM")
+    }
+}
+
+#[test]
+fn test_prediction_matches_created_token() {
+    let (env, client, _admin, creator) = setup();
+
+    for _ in 0..2 {
+        let spec = spec(&env, "Simulated");
+        let (index, address) = client.simulate_create_token(&creator, &spec, &TokenKind::Fungible);
+
+        let created = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+        assert_eq!(address, created);
+        assert_eq!(client.get_token_info(&index).address, created);
+    }
+    assert_eq!(client.get_token_count(&true), 2);
+}
+
+#[test]
+fn test_simulation_creates_nothing() {
+    let (env, client, _admin, creator) = setup();
+
+    client.simulate_create_token(&creator, &spec(&env, "Simulated"), &TokenKind::Fungible);
+
+    assert_eq!(client.get_token_count(&true), 0);
+}
+
+#[test]
+fn test_invalid_spec_fails_like_creation() {
+    let (env, client, admin, creator) = setup();
+
+    let bad = spec(&env, "");
+    let simulated = client
+        .try_simulate_create_token(&creator, &bad, &TokenKind::Fungible)
+        .err();
+    let created = client
+        .try_create_token(&creator, &bad, &BASE_FEE, &TokenKind::Fungible, &None)
+        .err();
+    assert!(simulated.is_some());
+    assert_eq!(simulated, created);
+
+    client.pause(&admin);
+    let good = spec(&env, "Simulated");
+    assert_eq!(
+        client.try_simulate_create_token(&creator, &good, &TokenKind::Fungible),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_create_token(&creator, &good, &BASE_FEE, &TokenKind::Fungible, &None),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+#[test]
+fn test_cap_exceeding_spec_fails_like_creation() {
+    let (env, client, admin, creator) = setup();
+
+    client.set_global_supply_cap(&admin, &Some(1_500_000));
+    client.create_token(
+        &creator,
+        &spec(&env, "First"),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    let over = spec(&env, "Second");
+    let simulated = client.try_simulate_create_token(&creator, &over, &TokenKind::Fungible);
+    let created = client.try_create_token(&creator, &over, &BASE_FEE, &TokenKind::Fungible, &None);
+    assert_eq!(simulated, Err(Ok(Error::GlobalCapExceeded)));
+    assert_eq!(created, Err(Ok(Error::GlobalCapExceeded)));
+}
+
+#[test]
+fn test_token_kind_checked_like_creation() {
+    let (env, client, _admin, creator) = setup();
+
+    // Non-fungible tokens need zero decimals
+    let spec = spec(&env, "Collectible");
+    let simulated = client.try_simulate_create_token(&creator, &spec, &TokenKind::NonFungible);
+    let created =
+        client.try_create_token(&creator, &spec, &BASE_FEE, &TokenKind::NonFungible, &None);
+    assert_eq!(simulated.err(), Some(Ok(Error::InvalidTokenParams)));
+    assert_eq!(created.err(), Some(Ok(Error::InvalidTokenParams)));
+}
@@ -66,6 +66,16 @@ fn create_test_token(
         metadata_uri: None,
         created_at: env.ledger().timestamp(),
         clawback_enabled: true,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
     };
 
     let token_index = crate::storage::get_token_count(env);
@@ -431,7 +441,7 @@ fn soak_test_mixed_operations() {
                 // Set metadata (once per token)
                 if !metadata_set.contains_key(&token_index) {
                     let uri = String::from_str(&env, &format!("ipfs://token{}", token_index));
-                    client.set_metadata(&token_index, creator, &uri);
+                    client.set_metadata(&token_index, creator, &uri, &None);
                     metadata_set.insert(token_index, Some(uri));
                 }
             }
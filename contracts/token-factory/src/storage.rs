@@ -1,6 +1,10 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
 
-use crate::types::{BuybackCampaign, DataKey, Error, FactoryState, TokenInfo};
+use crate::types::{
+    AdminConfig, BuybackCampaign, ConfigKey, CreateEscrow, DataKey, Error, FactoryState, FeeKey,
+    FeeTotals, IndexReservation, RoundingMode, TimeSource, TokenInfo, TokenKey, VersionRecord,
+    VestingSchedule,
+};
 
 // ============================================================
 // Storage Functions - Burn Tracking
@@ -85,13 +89,48 @@ pub fn get_token_info(env: &Env, index: u32) -> Option<TokenInfo> {
 }
 
 pub fn set_token_info(env: &Env, index: u32, info: &TokenInfo) {
+    let is_new_token = !env.storage().instance().has(&DataKey::Token(index));
     env.storage().instance().set(&DataKey::Token(index), info);
 
-    // Index by creator for pagination
-    add_creator_token(env, &info.creator, index);
+    // Register only on first write; later writes are state updates
+    if is_new_token {
+        // Index by creator for pagination
+        add_creator_token(env, &info.creator, index);
 
-    // Emit token registered event
-    crate::events::emit_token_registered(env, &info.address, &info.creator);
+        // Reverse lookup from token address to index
+        env.storage()
+            .instance()
+            .set(&TokenKey::TokenIndex(info.address.clone()), &index);
+
+        // Creator on its own so ownership checks skip the full record
+        env.storage()
+            .persistent()
+            .set(&TokenKey::TokenCreator(info.address.clone()), &info.creator);
+
+        // Index by creation time for range queries
+        add_time_bucket_token(env, info.created_at, index);
+
+        // Emit token registered event
+        crate::events::emit_token_registered(env, &info.address, &info.creator);
+    }
+}
+
+/// Get the creator of a token by its address
+///
+/// Reads the creator entry written at registration and falls back to the
+/// full token record for tokens registered without one.
+pub fn get_token_creator(env: &Env, token_address: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::TokenCreator(token_address.clone()))
+        .or_else(|| get_token_info_by_address(env, token_address).map(|info| info.creator))
+}
+
+/// Get the registry index of a token by its address
+pub fn get_token_index(env: &Env, token_address: &Address) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&TokenKey::TokenIndex(token_address.clone()))
 }
 
 pub fn increment_token_count(env: &Env) -> Result<u32, Error> {
@@ -109,7 +148,35 @@ pub fn get_factory_state(env: &Env) -> FactoryState {
         treasury: get_treasury(env),
         base_fee: get_base_fee(env),
         metadata_fee: get_metadata_fee(env),
-        paused: is_paused(env),
+        paused: is_pause_active(env),
+    }
+}
+
+/// Gather every admin-tunable parameter
+pub fn get_admin_config(env: &Env) -> AdminConfig {
+    let paused = is_pause_active(env);
+    AdminConfig {
+        base_fee: get_base_fee(env),
+        metadata_fee: get_metadata_fee(env),
+        fee_burn_bps: get_fee_burn_bps(env),
+        rounding_mode: get_rounding_mode(env),
+        admin_fee_exempt: is_admin_fee_exempt(env),
+        max_uri_length: get_max_uri_length(env),
+        min_decimals: get_min_decimals(env),
+        max_raw_supply: get_max_raw_supply(env),
+        global_supply_cap: get_global_supply_cap(env),
+        window_create_cap: get_window_create_cap(env),
+        min_creator_balance: get_min_creator_balance(env).map_or(0, |(_, min)| min),
+        action_quorum: get_action_quorum(env),
+        clawback_cooldown: get_clawback_cooldown(env),
+        admin_burn_during_pause: is_admin_burn_during_pause(env),
+        clawback_non_burnable: is_clawback_non_burnable_allowed(env),
+        unique_symbols: is_unique_symbols_enabled(env),
+        normalize_symbols: is_normalize_symbols_enabled(env),
+        allowlist_enabled: get_treasury_policy(env).allowlist_enabled,
+        paused,
+        pause_expiry: if paused { get_pause_expiry(env) } else { None },
+        creation_disabled: is_creation_disabled(env),
     }
 }
 
@@ -594,10 +661,112 @@ pub fn get_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
 }
 
 pub fn set_balance(env: &Env, token_index: u32, holder: &Address, balance: i128) {
-    env.storage().persistent().set(
-        &crate::types::DataKey::Balance(token_index, holder.clone()),
-        &balance,
-    );
+    let had_balance = get_balance(env, token_index, holder) > 0;
+    let key = crate::types::DataKey::Balance(token_index, holder.clone());
+    // Emptied balances are removed so a drained token leaves no entries
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &balance);
+    }
+
+    // Keep the holder count and list in step with zero/nonzero transitions
+    let count = get_holder_count(env, token_index);
+    match (had_balance, balance > 0) {
+        (false, true) => {
+            set_holder_count(env, token_index, count.saturating_add(1));
+            push_holder(env, token_index, holder);
+        }
+        (true, false) => {
+            set_holder_count(env, token_index, count.saturating_sub(1));
+            swap_remove_holder(env, token_index, holder);
+        }
+        _ => {}
+    }
+}
+
+// The holder list keeps one entry per holder (`HolderAt`) plus a reverse
+// map (`HolderPos`), so adding or removing a holder touches a constant
+// number of small entries however many holders a token has.
+
+/// Get the length of a token's holder list
+pub fn get_holder_list_len(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::HolderListLen(token_index))
+        .unwrap_or(0)
+}
+
+/// Get the holder at `position` of a token's holder list
+pub fn get_holder_at(env: &Env, token_index: u32, position: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::HolderAt(token_index, position))
+}
+
+fn push_holder(env: &Env, token_index: u32, holder: &Address) {
+    let persistent = env.storage().persistent();
+    let pos_key = TokenKey::HolderPos(token_index, holder.clone());
+    if persistent.has(&pos_key) {
+        return;
+    }
+    let len = get_holder_list_len(env, token_index);
+    persistent.set(&TokenKey::HolderAt(token_index, len), holder);
+    persistent.set(&pos_key, &len);
+    persistent.set(&TokenKey::HolderListLen(token_index), &(len + 1));
+}
+
+/// Remove a holder by moving the last holder into its slot
+fn swap_remove_holder(env: &Env, token_index: u32, holder: &Address) {
+    let persistent = env.storage().persistent();
+    let pos_key = TokenKey::HolderPos(token_index, holder.clone());
+    let Some(position) = persistent.get::<_, u32>(&pos_key) else {
+        return;
+    };
+    let last = get_holder_list_len(env, token_index).saturating_sub(1);
+    if position != last {
+        if let Some(moved) = get_holder_at(env, token_index, last) {
+            persistent.set(&TokenKey::HolderAt(token_index, position), &moved);
+            persistent.set(&TokenKey::HolderPos(token_index, moved), &position);
+        }
+    }
+    persistent.remove(&TokenKey::HolderAt(token_index, last));
+    persistent.remove(&pos_key);
+    persistent.set(&TokenKey::HolderListLen(token_index), &last);
+}
+
+/// Replace a token's holder list and count with exactly `holders`
+///
+/// Walks the old list to drop its entries, so the cost grows with it.
+pub fn rebuild_holders(env: &Env, token_index: u32, holders: &Vec<Address>) {
+    let persistent = env.storage().persistent();
+    for position in 0..get_holder_list_len(env, token_index) {
+        if let Some(holder) = get_holder_at(env, token_index, position) {
+            persistent.remove(&TokenKey::HolderPos(token_index, holder));
+        }
+        persistent.remove(&TokenKey::HolderAt(token_index, position));
+    }
+    persistent.set(&TokenKey::HolderListLen(token_index), &0u32);
+
+    for holder in holders.iter() {
+        push_holder(env, token_index, &holder);
+    }
+    set_holder_count(env, token_index, holders.len());
+}
+
+/// Get the number of addresses holding a nonzero balance of a token
+pub fn get_holder_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::HolderCount(token_index))
+        .unwrap_or(0)
+}
+
+/// Overwrite the holder count; `set_balance` keeps it in step otherwise
+pub fn set_holder_count(env: &Env, token_index: u32, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::HolderCount(token_index), &count);
 }
 
 pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
@@ -649,15 +818,57 @@ pub fn add_total_burned(env: &Env, token_index: u32, amount: i128) {
         .set(&crate::types::DataKey::TotalBurned(token_index), &updated);
 }
 // Pause management
+/// Whether the contract is paused
+///
+/// A pause with an expiry ends once the ledger sequence reaches it; the
+/// flag and expiry are cleared the first time that is observed. Getters
+/// use `is_pause_active`, which never writes.
 pub fn is_paused(env: &Env) -> bool {
-    env.storage()
+    if is_pause_active(env) {
+        return true;
+    }
+    if env
+        .storage()
         .instance()
         .get(&DataKey::Paused)
         .unwrap_or(false)
+    {
+        set_paused(env, false);
+    }
+    false
+}
+
+/// Whether the contract is paused, without clearing an expired pause
+pub fn is_pause_active(env: &Env) -> bool {
+    let paused = env
+        .storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false);
+    if !paused {
+        return false;
+    }
+
+    match get_pause_expiry(env) {
+        Some(until_ledger) => env.ledger().sequence() < until_ledger,
+        None => true,
+    }
 }
 
+/// Set the pause flag, dropping any pause expiry
 pub fn set_paused(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::Paused, &paused);
+    env.storage().instance().remove(&ConfigKey::PauseExpiry);
+}
+
+pub fn get_pause_expiry(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&ConfigKey::PauseExpiry)
+}
+
+pub fn set_pause_expiry(env: &Env, until_ledger: u32) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::PauseExpiry, &until_ledger);
 }
 
 // Token lookup by address
@@ -1388,3 +1599,1076 @@ pub fn decrement_active_campaign_count(env: &Env) -> Result<u32, Error> {
     set_active_campaign_count(env, new_count);
     Ok(new_count)
 }
+
+// ============================================================
+// Storage Functions - SAC Wrappers
+// ============================================================
+
+/// Get the Stellar Asset Contract wrapper recorded for a factory token
+pub fn get_sac_wrapper(env: &Env, token_address: &Address) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenKey::SacWrapper(token_address.clone()))
+}
+
+/// Record the Stellar Asset Contract wrapper for a factory token
+pub fn set_sac_wrapper(env: &Env, token_address: &Address, wrapper: &Address) {
+    env.storage()
+        .instance()
+        .set(&TokenKey::SacWrapper(token_address.clone()), wrapper);
+    env.storage()
+        .instance()
+        .set(&TokenKey::SacWrappedToken(wrapper.clone()), token_address);
+}
+
+/// Get the factory token a Stellar Asset Contract wrapper was deployed for
+pub fn get_sac_wrapped_token(env: &Env, wrapper: &Address) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenKey::SacWrappedToken(wrapper.clone()))
+}
+
+// ============================================================
+// Storage Functions - Token Wasm Provenance
+// ============================================================
+
+/// Get the token wasm hash recorded for new deployments
+///
+/// Defaults to all zeros until the admin configures a hash.
+pub fn get_token_wasm_hash(env: &Env) -> BytesN<32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenWasmHash)
+        .unwrap_or(BytesN::from_array(env, &[0; 32]))
+}
+
+/// Set the token wasm hash recorded for new deployments
+pub fn set_token_wasm_hash(env: &Env, hash: &BytesN<32>) {
+    env.storage().instance().set(&DataKey::TokenWasmHash, hash);
+}
+
+// ============================================================
+// Storage Functions - Fee Asset & Creation Escrow
+// ============================================================
+
+/// Get the asset fees are paid in, if one is configured
+pub fn get_fee_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::FeeToken)
+}
+
+/// Set the asset fees are paid in
+pub fn set_fee_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&DataKey::FeeToken, token);
+}
+
+/// Get the share of each collected fee that is burned, in basis points
+pub fn get_fee_burn_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeBurnBps)
+        .unwrap_or(0)
+}
+
+/// Set the share of each collected fee that is burned, in basis points
+pub fn set_fee_burn_bps(env: &Env, bps: u32) {
+    env.storage().instance().set(&DataKey::FeeBurnBps, &bps);
+}
+
+/// Get the rounding mode for basis-point shares (`Floor` if unset)
+pub fn get_rounding_mode(env: &Env) -> RoundingMode {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::RoundingMode)
+        .unwrap_or_default()
+}
+
+/// Set the rounding mode for basis-point shares
+pub fn set_rounding_mode(env: &Env, mode: RoundingMode) {
+    env.storage().instance().set(&ConfigKey::RoundingMode, &mode);
+}
+
+/// Get running totals of fees sent to the treasury and burned
+pub fn get_fee_totals(env: &Env) -> FeeTotals {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeTotals)
+        .unwrap_or_default()
+}
+
+/// Set running totals of fees sent to the treasury and burned
+pub fn set_fee_totals(env: &Env, totals: &FeeTotals) {
+    env.storage().instance().set(&DataKey::FeeTotals, totals);
+}
+
+/// Get collected fees of an asset held by the factory for the treasury
+pub fn get_fee_balance(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::FeeBalance(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Set collected fees of an asset held by the factory for the treasury
+pub fn set_fee_balance(env: &Env, asset: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&FeeKey::FeeBalance(asset.clone()), &amount);
+}
+
+/// Get fees of an asset held in pending creation escrows
+pub fn get_escrowed_fees(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::EscrowedFees(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Set fees of an asset held in pending creation escrows
+pub fn set_escrowed_fees(env: &Env, asset: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&FeeKey::EscrowedFees(asset.clone()), &amount);
+}
+
+/// Get the remaining uses of a fee waiver (0 if unknown or used up)
+pub fn get_waiver_uses(env: &Env, code_hash: &BytesN<32>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::Waiver(code_hash.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the remaining uses of a fee waiver
+pub fn set_waiver_uses(env: &Env, code_hash: &BytesN<32>, uses: u32) {
+    env.storage()
+        .persistent()
+        .set(&FeeKey::Waiver(code_hash.clone()), &uses);
+}
+
+/// Get the fee charged for an operation kind (0 when unset)
+pub fn get_op_fee(env: &Env, kind: crate::types::OpKind) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::OpFee(kind))
+        .unwrap_or(0)
+}
+
+/// Set the fee charged for an operation kind; 0 removes it
+pub fn set_op_fee(env: &Env, kind: crate::types::OpKind, fee: i128) {
+    let key = FeeKey::OpFee(kind);
+    if fee == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &fee);
+    }
+}
+
+/// Get the ed25519 key that signs off-chain fee waivers
+pub fn get_waiver_signer(env: &Env) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&FeeKey::WaiverSigner)
+}
+
+pub fn set_waiver_signer(env: &Env, public_key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&FeeKey::WaiverSigner, public_key);
+}
+
+/// Check whether a signed waiver nonce was already redeemed
+pub fn is_waiver_nonce_used(env: &Env, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&FeeKey::UsedWaiverNonce(nonce))
+}
+
+pub fn set_waiver_nonce_used(env: &Env, nonce: u64) {
+    env.storage()
+        .persistent()
+        .set(&FeeKey::UsedWaiverNonce(nonce), &true);
+}
+
+/// Get the number of redeemed signed waivers a creator has not spent yet
+pub fn get_signed_waiver_credits(env: &Env, creator: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::SignedWaiverCredit(creator.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_signed_waiver_credits(env: &Env, creator: &Address, credits: u32) {
+    let key = FeeKey::SignedWaiverCredit(creator.clone());
+    if credits == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &credits);
+    }
+}
+
+/// Whether the admin's own deployments skip the creation fee (default: true)
+pub fn is_admin_fee_exempt(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AdminFeeExempt)
+        .unwrap_or(true)
+}
+
+pub fn set_admin_fee_exempt(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::AdminFeeExempt, &enabled);
+}
+
+/// Whether `account` holds the fee exemption role
+pub fn is_fee_exempt(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&FeeKey::FeeExempt(account.clone()))
+}
+
+/// Grant or revoke the fee exemption role
+pub fn set_fee_exempt(env: &Env, account: &Address, exempt: bool) {
+    let key = FeeKey::FeeExempt(account.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Get the number of creation escrows ever opened
+pub fn get_create_escrow_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreateEscrowCount)
+        .unwrap_or(0_u64)
+}
+
+/// Increment the creation escrow counter and return the new escrow id
+pub fn increment_create_escrow_count(env: &Env) -> Result<u64, Error> {
+    let id = get_create_escrow_count(env)
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage().instance().set(&DataKey::CreateEscrowCount, &id);
+    Ok(id)
+}
+
+/// Get a creation escrow by id
+pub fn get_create_escrow(env: &Env, escrow_id: u64) -> Option<CreateEscrow> {
+    env.storage().persistent().get(&DataKey::CreateEscrow(escrow_id))
+}
+
+/// Persist a creation escrow
+pub fn set_create_escrow(env: &Env, escrow: &CreateEscrow) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreateEscrow(escrow.id), escrow);
+}
+
+// ============================================================
+// Storage Functions - Token Attributes
+// ============================================================
+
+/// Get a structured metadata attribute for a token
+pub fn get_token_attribute(env: &Env, token_address: &Address, key: &String) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::TokenAttribute(token_address.clone(), key.clone()))
+}
+
+/// Set a structured metadata attribute for a token
+///
+/// New keys are also appended to the token's key list so the attributes
+/// can be removed when the token is closed.
+pub fn set_token_attribute(env: &Env, token_address: &Address, key: &String, value: &String) {
+    if get_token_attribute(env, token_address, key).is_none() {
+        let mut keys = get_token_attribute_keys(env, token_address);
+        keys.push_back(key.clone());
+        env.storage()
+            .persistent()
+            .set(&TokenKey::TokenAttributeKeys(token_address.clone()), &keys);
+    }
+    env.storage()
+        .persistent()
+        .set(&TokenKey::TokenAttribute(token_address.clone(), key.clone()), value);
+}
+
+/// Get the keys of every attribute stored for a token
+pub fn get_token_attribute_keys(env: &Env, token_address: &Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::TokenAttributeKeys(token_address.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get the number of distinct attributes stored for a token
+pub fn get_token_attribute_count(env: &Env, token_address: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::TokenAttributeCount(token_address.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the number of distinct attributes stored for a token
+pub fn set_token_attribute_count(env: &Env, token_address: &Address, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::TokenAttributeCount(token_address.clone()), &count);
+}
+
+/// Check whether a token's metadata has been frozen by its creator
+pub fn is_metadata_frozen(env: &Env, token_address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::MetadataFrozen(token_address.clone()))
+        .unwrap_or(false)
+}
+
+/// Mark a token's metadata as frozen
+pub fn set_metadata_frozen(env: &Env, token_address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::MetadataFrozen(token_address.clone()), &true);
+}
+
+/// Check whether minting of a token has been permanently ended
+pub fn is_minting_finalized(env: &Env, token_address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::MintingFinalized(token_address.clone()))
+        .unwrap_or(false)
+}
+
+/// Permanently end minting of a token
+pub fn set_minting_finalized(env: &Env, token_address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::MintingFinalized(token_address.clone()), &true);
+}
+
+/// Check whether a token was permanently locked by the admin
+pub fn is_token_locked(env: &Env, token_address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::Locked(token_address.clone()))
+        .unwrap_or(false)
+}
+
+/// Permanently lock a token; there is no way to unlock it
+pub fn set_token_locked(env: &Env, token_address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::Locked(token_address.clone()), &true);
+}
+
+/// Get the reservation held on a token index
+pub fn get_index_reservation(env: &Env, index: u32) -> Option<IndexReservation> {
+    env.storage().persistent().get(&TokenKey::ReservedIndex(index))
+}
+
+pub fn set_index_reservation(env: &Env, index: u32, reservation: &IndexReservation) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::ReservedIndex(index), reservation);
+}
+
+pub fn remove_index_reservation(env: &Env, index: u32) {
+    env.storage()
+        .persistent()
+        .remove(&TokenKey::ReservedIndex(index));
+}
+
+/// Check whether the creator has given up all powers over a token
+pub fn is_token_renounced(env: &Env, token_address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::Renounced(token_address.clone()))
+        .unwrap_or(false)
+}
+
+/// Mark a token as renounced by its creator
+pub fn set_token_renounced(env: &Env, token_address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::Renounced(token_address.clone()), &true);
+}
+
+// ============================================================
+// Storage Functions - Creation Time Buckets
+// ============================================================
+
+/// Width of a creation time bucket, in seconds (one day)
+pub const TIME_BUCKET_SECONDS: u64 = 86_400;
+
+/// Get the indices of tokens created in the bucket starting at `bucket`
+pub fn get_time_bucket_tokens(env: &Env, bucket: u64) -> soroban_sdk::Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::TimeBucket(bucket))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+/// Record a token in the bucket covering `created_at`
+pub fn add_time_bucket_token(env: &Env, created_at: u64, token_index: u32) {
+    let bucket = created_at / TIME_BUCKET_SECONDS;
+    let mut tokens = get_time_bucket_tokens(env, bucket);
+    tokens.push_back(token_index);
+    env.storage()
+        .persistent()
+        .set(&TokenKey::TimeBucket(bucket), &tokens);
+}
+
+// ============================================================
+// Storage Functions - Vesting
+// ============================================================
+
+/// Get the vesting schedule of `beneficiary` for a token
+pub fn get_vesting(
+    env: &Env,
+    token_address: &Address,
+    beneficiary: &Address,
+) -> Option<VestingSchedule> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::Vesting(token_address.clone(), beneficiary.clone()))
+}
+
+/// Persist a vesting schedule
+pub fn set_vesting(env: &Env, schedule: &VestingSchedule) {
+    env.storage().persistent().set(
+        &TokenKey::Vesting(schedule.token_address.clone(), schedule.beneficiary.clone()),
+        schedule,
+    );
+}
+
+// ============================================================
+// Storage Functions - Clawback Cooldown
+// ============================================================
+
+/// Get the minimum seconds between clawbacks from one holder (0 = none)
+pub fn get_clawback_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::ClawbackCooldown)
+        .unwrap_or(0)
+}
+
+pub fn set_clawback_cooldown(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::ClawbackCooldown, &seconds);
+}
+
+/// Get the most tokens that may be created per ledger window (0 = no cap)
+pub fn get_window_create_cap(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::WindowCreateCap)
+        .unwrap_or(0)
+}
+
+pub fn set_window_create_cap(env: &Env, cap: u32) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::WindowCreateCap, &cap);
+}
+
+/// Get `(window, creations)` for the last window a token was created in
+pub fn get_create_window(env: &Env) -> (u32, u32) {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::CreateWindow)
+        .unwrap_or((0, 0))
+}
+
+pub fn set_create_window(env: &Env, window: u32, count: u32) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::CreateWindow, &(window, count));
+}
+
+/// Get the `(supply_threshold, extra_fee)` creation fee tiers
+pub fn get_supply_fee_tiers(env: &Env) -> Vec<(i128, i128)> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::SupplyFeeTiers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_supply_fee_tiers(env: &Env, tiers: &Vec<(i128, i128)>) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::SupplyFeeTiers, tiers);
+}
+
+/// Whether token creation also emits the lightweight `tok_ping` event
+pub fn is_webhook_topic_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::WebhookTopicEnabled)
+        .unwrap_or(false)
+}
+
+pub fn set_webhook_topic_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::WebhookTopicEnabled, &enabled);
+}
+
+/// Get the clock used to stamp token creation (default: Timestamp)
+pub fn get_time_source(env: &Env) -> TimeSource {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::TimeSource)
+        .unwrap_or(TimeSource::Timestamp)
+}
+
+pub fn set_time_source(env: &Env, source: TimeSource) {
+    env.storage().instance().set(&ConfigKey::TimeSource, &source);
+}
+
+/// Get when tokens were last clawed back from `holder`
+pub fn get_last_clawback(env: &Env, token_address: &Address, holder: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::LastClawback(token_address.clone(), holder.clone()))
+}
+
+pub fn set_last_clawback(env: &Env, token_address: &Address, holder: &Address, timestamp: u64) {
+    env.storage().persistent().set(
+        &TokenKey::LastClawback(token_address.clone(), holder.clone()),
+        &timestamp,
+    );
+}
+
+// ============================================================
+// Storage Functions - Archived Tokens
+// ============================================================
+
+/// Check whether the token at `index` has been archived
+pub fn is_token_archived(env: &Env, index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::Archived(index))
+        .unwrap_or(false)
+}
+
+/// Archive the token at `index` and count it as archived
+pub fn set_token_archived(env: &Env, index: u32) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::Archived(index), &true);
+    let archived = get_archived_count(env).saturating_add(1);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::ArchivedCount, &archived);
+}
+
+/// Get the number of archived tokens
+pub fn get_archived_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::ArchivedCount)
+        .unwrap_or(0)
+}
+
+/// Remove every per-token entry of a closed token
+///
+/// Drops the registry records, address lookups, burn records, holder
+/// count, pause and lifecycle flags and attributes. Balances are not
+/// touched: a token can only be closed at zero supply, and emptied
+/// balances are already removed by `set_balance`. The `Archived` flag
+/// is kept so the index keeps reporting as archived.
+pub fn remove_token_records(env: &Env, index: u32, token_address: &Address) {
+    let instance = env.storage().instance();
+    instance.remove(&DataKey::Token(index));
+    instance.remove(&DataKey::TokenByAddress(token_address.clone()));
+    instance.remove(&TokenKey::TokenIndex(token_address.clone()));
+    instance.remove(&DataKey::TokenPaused(index));
+
+    let persistent = env.storage().persistent();
+    persistent.remove(&TokenKey::TokenCreator(token_address.clone()));
+    persistent.remove(&DataKey::BurnCount(index));
+    persistent.remove(&DataKey::TotalBurned(index));
+    persistent.remove(&TokenKey::HolderCount(index));
+    persistent.remove(&TokenKey::HolderListLen(index));
+    persistent.remove(&TokenKey::MetadataFrozen(token_address.clone()));
+    persistent.remove(&TokenKey::MintingFinalized(token_address.clone()));
+    persistent.remove(&TokenKey::Locked(token_address.clone()));
+    persistent.remove(&TokenKey::Renounced(token_address.clone()));
+
+    for key in get_token_attribute_keys(env, token_address).iter() {
+        persistent.remove(&TokenKey::TokenAttribute(token_address.clone(), key));
+    }
+    persistent.remove(&TokenKey::TokenAttributeKeys(token_address.clone()));
+    persistent.remove(&TokenKey::TokenAttributeCount(token_address.clone()));
+}
+
+// ============================================================
+// Storage Functions - Version History
+// ============================================================
+
+/// Get the number of recorded upgrades
+pub fn get_version_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::VersionCount)
+        .unwrap_or(0)
+}
+
+/// Get the upgrade record at `index` (0 = oldest)
+pub fn get_version_record(env: &Env, index: u32) -> Option<VersionRecord> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::VersionHistory(index))
+}
+
+/// Append an upgrade record; earlier records are never rewritten
+pub fn push_version_record(env: &Env, record: &VersionRecord) -> Result<(), Error> {
+    let index = get_version_count(env);
+    let count = index.checked_add(1).ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::VersionHistory(index), record);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::VersionCount, &count);
+    Ok(())
+}
+
+/// Get the number of fee changes ever recorded
+pub fn get_fee_history_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&FeeKey::FeeHistoryCount)
+        .unwrap_or(0)
+}
+
+pub fn set_fee_history_count(env: &Env, count: u64) {
+    env.storage()
+        .instance()
+        .set(&FeeKey::FeeHistoryCount, &count);
+}
+
+pub fn get_fee_record(env: &Env, slot: u32) -> Option<crate::types::FeeRecord> {
+    env.storage().persistent().get(&FeeKey::FeeHistory(slot))
+}
+
+pub fn set_fee_record(env: &Env, slot: u32, record: &crate::types::FeeRecord) {
+    env.storage()
+        .persistent()
+        .set(&FeeKey::FeeHistory(slot), record);
+}
+
+/// Get the version an upgrade replaced, if it can still be rolled back to
+pub fn get_previous_version(env: &Env) -> Option<VersionRecord> {
+    env.storage().instance().get(&ConfigKey::PreviousWasm)
+}
+
+pub fn set_previous_version(env: &Env, record: &VersionRecord) {
+    env.storage().instance().set(&ConfigKey::PreviousWasm, record);
+}
+
+pub fn clear_previous_version(env: &Env) {
+    env.storage().instance().remove(&ConfigKey::PreviousWasm);
+}
+
+// ============================================================
+// Storage Functions - Allowances
+// ============================================================
+
+/// Get how much `spender` may burn from `from`'s balance of a token
+pub fn get_allowance(env: &Env, token_address: &Address, from: &Address, spender: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::Allowance(
+            token_address.clone(),
+            from.clone(),
+            spender.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Set how much `spender` may burn from `from`'s balance of a token
+pub fn set_allowance(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) {
+    let key = TokenKey::Allowance(token_address.clone(), from.clone(), spender.clone());
+    let mut spenders = get_owner_spenders(env, token_address, from);
+    let listed = spenders.first_index_of(spender);
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        if let Some(position) = listed {
+            spenders.remove(position);
+            set_owner_spenders(env, token_address, from, &spenders);
+        }
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        if listed.is_none() {
+            spenders.push_back(spender.clone());
+            set_owner_spenders(env, token_address, from, &spenders);
+        }
+    }
+}
+
+/// Get every spender holding a nonzero allowance over `from`'s balance
+///
+/// Kept in step by `set_allowance`.
+pub fn get_owner_spenders(env: &Env, token_address: &Address, from: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::OwnerSpenders(token_address.clone(), from.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_owner_spenders(env: &Env, token_address: &Address, from: &Address, spenders: &Vec<Address>) {
+    let key = TokenKey::OwnerSpenders(token_address.clone(), from.clone());
+    if spenders.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, spenders);
+    }
+}
+
+// ============================================================
+// Storage Functions - Symbol Registry
+// ============================================================
+
+/// Check whether token symbols must be unique across the factory
+pub fn is_unique_symbols_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::UniqueSymbols)
+        .unwrap_or(false)
+}
+
+/// Enable or disable unique token symbols
+pub fn set_unique_symbols_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::UniqueSymbols, &enabled);
+}
+
+/// Default largest initial supply, leaving half the range for mints
+pub const DEFAULT_MAX_RAW_SUPPLY: i128 = i128::MAX / 2;
+
+/// Get the largest raw initial supply a new token may have
+pub fn get_max_raw_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxRawSupply)
+        .unwrap_or(DEFAULT_MAX_RAW_SUPPLY)
+}
+
+pub fn set_max_raw_supply(env: &Env, max: i128) {
+    env.storage().instance().set(&ConfigKey::MaxRawSupply, &max);
+}
+
+/// Check whether symbols are uppercased at creation
+pub fn is_normalize_symbols_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::NormalizeSymbols)
+        .unwrap_or(false)
+}
+
+pub fn set_normalize_symbols_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::NormalizeSymbols, &enabled);
+}
+
+/// Check whether `admin_burn` may run while the contract is paused
+pub fn is_admin_burn_during_pause(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AdminBurnDuringPause)
+        .unwrap_or(false)
+}
+
+pub fn set_admin_burn_during_pause(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::AdminBurnDuringPause, &enabled);
+}
+
+/// Check whether `admin_burn` may claw back non-burnable tokens
+pub fn is_clawback_non_burnable_allowed(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::ClawbackNonBurnable)
+        .unwrap_or(true)
+}
+
+pub fn set_clawback_non_burnable_allowed(env: &Env, allowed: bool) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::ClawbackNonBurnable, &allowed);
+}
+
+/// Get the guardian that must co-sign admin transfers, if any
+pub fn get_guardian(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::Guardian)
+}
+
+pub fn set_guardian(env: &Env, guardian: &Address) {
+    env.storage().instance().set(&ConfigKey::Guardian, guardian);
+}
+
+pub fn remove_guardian(env: &Env) {
+    env.storage().instance().remove(&ConfigKey::Guardian);
+}
+
+/// Get the native asset contract and balance creators must hold, if set
+pub fn get_min_creator_balance(env: &Env) -> Option<(Address, i128)> {
+    env.storage().instance().get(&ConfigKey::MinCreatorBalance)
+}
+
+pub fn set_min_creator_balance(env: &Env, native_asset: &Address, min_balance: i128) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MinCreatorBalance, &(native_asset.clone(), min_balance));
+}
+
+pub fn remove_min_creator_balance(env: &Env) {
+    env.storage().instance().remove(&ConfigKey::MinCreatorBalance);
+}
+
+/// Get the number of approvals a queued admin action needs (default 1)
+pub fn get_action_quorum(env: &Env) -> u32 {
+    env.storage().instance().get(&ConfigKey::Quorum).unwrap_or(1)
+}
+
+pub fn set_action_quorum(env: &Env, quorum: u32) {
+    env.storage().instance().set(&ConfigKey::Quorum, &quorum);
+}
+
+/// Check whether an account holds the approver role
+pub fn is_approver(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ConfigKey::Approver(account.clone()))
+}
+
+/// Grant or revoke the approver role
+pub fn set_approver(env: &Env, account: &Address, enabled: bool) {
+    let key = ConfigKey::Approver(account.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Allocate the next admin action request id
+pub fn next_action_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&ConfigKey::ActionCount)
+        .unwrap_or(0);
+    env.storage().instance().set(&ConfigKey::ActionCount, &(id + 1));
+    id
+}
+
+pub fn get_action_request(env: &Env, id: u64) -> Option<crate::types::ActionRequest> {
+    env.storage().persistent().get(&ConfigKey::ActionRequest(id))
+}
+
+pub fn set_action_request(env: &Env, request: &crate::types::ActionRequest) {
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::ActionRequest(request.id), request);
+}
+
+/// Require the guardian's authorization when one is set
+pub fn require_guardian_auth(env: &Env) {
+    if let Some(guardian) = get_guardian(env) {
+        guardian.require_auth();
+    }
+}
+
+/// Get the token registered under a symbol
+pub fn get_symbol_owner(env: &Env, symbol: &String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::SymbolRegistry(symbol.clone()))
+}
+
+/// Check whether a symbol is on the reserved blocklist
+pub fn is_symbol_reserved(env: &Env, symbol: &String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&TokenKey::ReservedSymbol(symbol.clone()))
+}
+
+/// Add or remove a symbol from the reserved blocklist
+pub fn set_symbol_reserved(env: &Env, symbol: &String, reserved: bool) {
+    let key = TokenKey::ReservedSymbol(symbol.clone());
+    if reserved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Register a token under a symbol
+pub fn set_symbol_owner(env: &Env, symbol: &String, token_address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&TokenKey::SymbolRegistry(symbol.clone()), token_address);
+}
+
+// ============================================================
+// Storage Functions - Instance Id
+// ============================================================
+
+/// Instance id used when the factory was initialized without one
+pub const DEFAULT_INSTANCE_ID: Symbol = symbol_short!("default");
+
+/// Get the instance id tagged onto every event
+pub fn get_instance_id(env: &Env) -> Symbol {
+    env.storage()
+        .instance()
+        .get(&DataKey::InstanceId)
+        .unwrap_or(DEFAULT_INSTANCE_ID)
+}
+
+/// Set the instance id tagged onto every event
+pub fn set_instance_id(env: &Env, instance_id: &Symbol) {
+    env.storage().instance().set(&DataKey::InstanceId, instance_id);
+}
+
+// ============================================================
+// Storage Functions - Metadata URI Limits
+// ============================================================
+
+/// Metadata URI length limit used until the admin sets one
+pub const DEFAULT_MAX_URI_LENGTH: u32 = 256;
+
+/// Get the maximum accepted metadata URI length
+pub fn get_max_uri_length(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxUriLength)
+        .unwrap_or(DEFAULT_MAX_URI_LENGTH)
+}
+
+/// Set the maximum accepted metadata URI length
+pub fn set_max_uri_length(env: &Env, len: u32) {
+    env.storage().instance().set(&ConfigKey::MaxUriLength, &len);
+}
+
+// ============================================================
+// Storage Functions - Decimals Floor
+// ============================================================
+
+/// Get the minimum decimals accepted for new tokens (0 if unset)
+pub fn get_min_decimals(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MinDecimals)
+        .unwrap_or(0)
+}
+
+/// Set the minimum decimals accepted for new tokens
+pub fn set_min_decimals(env: &Env, min: u32) {
+    env.storage().instance().set(&ConfigKey::MinDecimals, &min);
+}
+
+// ============================================================
+// Storage Functions - Emergency Shutdown
+// ============================================================
+
+/// Check whether token creation has been permanently disabled
+pub fn is_creation_disabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::CreationDisabled)
+        .unwrap_or(false)
+}
+
+/// Permanently disable token creation
+pub fn set_creation_disabled(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::CreationDisabled, &true);
+}
+
+/// Get the factory-wide supply cap (`None` = unlimited)
+pub fn get_global_supply_cap(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&ConfigKey::GlobalSupplyCap)
+}
+
+/// Set or clear the factory-wide supply cap
+pub fn set_global_supply_cap(env: &Env, cap: Option<i128>) {
+    match cap {
+        Some(cap) => env
+            .storage()
+            .instance()
+            .set(&ConfigKey::GlobalSupplyCap, &cap),
+        None => env.storage().instance().remove(&ConfigKey::GlobalSupplyCap),
+    }
+}
+
+/// Get the supply of all factory tokens combined
+pub fn get_global_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::GlobalTotalSupply)
+        .unwrap_or(0)
+}
+
+pub fn set_global_supply(env: &Env, supply: i128) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::GlobalTotalSupply, &supply);
+}
+
+/// Get `(total burned, burn count)` across every factory token
+pub fn get_global_burn_stats(env: &Env) -> (i128, u32) {
+    let burned = env
+        .storage()
+        .instance()
+        .get(&ConfigKey::GlobalTotalBurned)
+        .unwrap_or(0);
+    let count = env
+        .storage()
+        .instance()
+        .get(&ConfigKey::GlobalBurnCount)
+        .unwrap_or(0);
+    (burned, count)
+}
+
+/// Add `amount` burned over `count` burns to the factory-wide totals
+///
+/// Saturates like `add_total_burned`, so statistics never fail a burn.
+pub fn add_global_burn(env: &Env, amount: i128, count: u32) {
+    let (burned, burns) = get_global_burn_stats(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::GlobalTotalBurned, &burned.saturating_add(amount));
+    env.storage()
+        .instance()
+        .set(&ConfigKey::GlobalBurnCount, &burns.saturating_add(count));
+}
+
+// ============================================================
+// Storage Functions - Event Sequence
+// ============================================================
+
+/// Get the sequence number of the last emitted event (0 if none)
+pub fn get_event_seq(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::EventSeq)
+        .unwrap_or(0_u64)
+}
+
+/// Advance the event sequence and return the number for the next event
+pub fn next_event_seq(env: &Env) -> u64 {
+    let seq = get_event_seq(env).saturating_add(1);
+    env.storage().instance().set(&ConfigKey::EventSeq, &seq);
+    seq
+}
@@ -34,6 +34,16 @@ mod streaming_integration_tests {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            display_decimals: None,
+            created_at_source: crate::types::TimeSource::Timestamp,
+            max_holders: None,
+            min_transfer: None,
+            clawback_mode: crate::types::ClawbackMode::Burn,
+            metadata_version: 0,
+            burnable: true,
+            min_balance: None,
+            events_muted: false,
+            gate_token: None,
         
         };
         storage::set_token_info(&env, 0, &token_info);
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, String};
+
+const SURCHARGE: i128 = 50_000_000;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    fee_token: TokenClient<'static>,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset.address()).mint(&creator, &STARTING_BALANCE);
+
+    Setup {
+        fee_token: TokenClient::new(&env, &fee_asset.address()),
+        env,
+        client,
+        admin,
+        creator,
+    }
+}
+
+fn create(s: &Setup, symbol: &str, supply: i128, fee: i128) -> Result<Address, Error> {
+    let spec = TokenCreationParams {
+        initial_supply: supply,
+        ..token_params(&s.env, symbol)
+    };
+    s.client
+        .try_create_token(&s.creator, &spec, &fee, &TokenKind::Fungible, &None)
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_surcharge_applies_only_above_threshold() {
+    let s = setup();
+    s.client
+        .set_supply_fee_tiers(&s.admin, &vec![&s.env, (1_000_000i128, SURCHARGE)]);
+
+    create(&s, "SMALL", 500_000, BASE_FEE).unwrap();
+    assert_eq!(s.fee_token.balance(&s.creator), STARTING_BALANCE - BASE_FEE);
+
+    assert_eq!(
+        create(&s, "LARGE", 2_000_000, BASE_FEE),
+        Err(Error::InsufficientFee)
+    );
+    create(&s, "LARGE", 2_000_000, BASE_FEE + SURCHARGE).unwrap();
+    assert_eq!(
+        s.fee_token.balance(&s.creator),
+        STARTING_BALANCE - 2 * BASE_FEE - SURCHARGE
+    );
+}
+
+#[test]
+fn test_highest_matching_tier_wins() {
+    let s = setup();
+    s.client.set_supply_fee_tiers(
+        &s.admin,
+        &vec![
+            &s.env,
+            (1_000_000i128, 10_000_000i128),
+            (5_000_000, SURCHARGE),
+        ],
+    );
+
+    create(&s, "MID", 2_000_000, BASE_FEE + 10_000_000).unwrap();
+    create(&s, "HUGE", 10_000_000, BASE_FEE + SURCHARGE).unwrap();
+    assert_eq!(
+        s.fee_token.balance(&s.creator),
+        STARTING_BALANCE - 2 * BASE_FEE - 10_000_000 - SURCHARGE
+    );
+}
+
+#[test]
+fn test_invalid_tiers_rejected() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_set_supply_fee_tiers(
+            &s.admin,
+            &vec![&s.env, (5_000_000i128, 1i128), (1_000_000, 2)]
+        ),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_set_supply_fee_tiers(&s.admin, &vec![&s.env, (1_000_000i128, -1i128)]),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_set_supply_fee_tiers(&s.creator, &vec![&s.env, (1_000_000i128, 1i128)]),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(s.client.get_supply_fee_tiers().is_empty());
+}
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+#[test]
+fn test_human_supply_tracks_burns() {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    // 1234.5678901 units at 7 decimals
+    let spec = TokenCreationParams {
+        initial_supply: 12_345_678_901,
+        ..token_params(&env, "HUM")
+    };
+    let creator = Address::generate(&env);
+    let token = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    assert_eq!(client.get_total_supply_human(&token), (1_234, 5_678_901, 7));
+
+    // Burn 0.6 units: 1233.9678901
+    client.burn(&creator, &0, &6_000_000);
+    assert_eq!(client.get_total_supply_human(&token), (1_233, 9_678_901, 7));
+
+    // Burn down to exactly 1000 units
+    client.burn(&creator, &0, &2_339_678_901);
+    assert_eq!(client.get_total_supply_human(&token), (1_000, 0, 7));
+}
+
+#[test]
+fn test_unknown_token_is_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_get_total_supply_human(&Address::generate(&env)),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_split_units() {
+    assert_eq!(token_creation::split_units(0, 7), Ok((0, 0)));
+    assert_eq!(token_creation::split_units(999, 0), Ok((999, 0)));
+    assert_eq!(
+        token_creation::split_units(123_456_789_012_345_678_901, 18),
+        Ok((123, 456_789_012_345_678_901))
+    );
+    assert_eq!(
+        token_creation::split_units(1, 39),
+        Err(Error::ArithmeticError)
+    );
+}
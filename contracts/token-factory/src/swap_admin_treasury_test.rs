@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, Address, Env, TryFromVal};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv {
+        env,
+        client,
+        admin,
+        treasury,
+        ..
+    } = FactoryEnv::new();
+
+    (env, client, admin, treasury)
+}
+
+#[test]
+fn test_swap_exchanges_roles() {
+    let (_env, client, admin, treasury) = setup();
+
+    client.swap_admin_treasury(&admin);
+
+    let state = client.get_state();
+    assert_eq!(state.admin, treasury);
+    assert_eq!(state.treasury, admin);
+}
+
+#[test]
+fn test_new_admin_can_administer() {
+    let (_env, client, admin, treasury) = setup();
+
+    client.swap_admin_treasury(&admin);
+
+    client.update_fees(&treasury, &Some(80_000_000), &None);
+    assert_eq!(client.get_base_fee(), 80_000_000);
+    assert_eq!(
+        client.try_update_fees(&admin, &Some(90_000_000), &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_swap_admin_treasury(&admin),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_swap_emits_admin_and_treasury_events() {
+    let (env, client, admin, _treasury) = setup();
+
+    client.swap_admin_treasury(&admin);
+
+    let names: std::vec::Vec<Symbol> = env
+        .events()
+        .all()
+        .iter()
+        .map(|(_, topics, _)| Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [symbol_short!("adm_xf_v1"), symbol_short!("trs_upd")]
+    );
+}
+
+#[test]
+fn test_swap_drops_pending_admin_proposal() {
+    let (env, client, admin, _treasury) = setup();
+    let proposed = Address::generate(&env);
+    client.propose_admin(&admin, &proposed);
+
+    client.swap_admin_treasury(&admin);
+
+    assert_eq!(
+        client.try_accept_admin(&proposed),
+        Err(Ok(Error::Unauthorized))
+    );
+}
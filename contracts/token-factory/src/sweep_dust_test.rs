@@ -0,0 +1,183 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+const SUPPLY: i128 = 1_000_000;
+const THRESHOLD: i128 = 100;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    contract_id: Address,
+    creator: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let spec = TokenCreationParams {
+        initial_supply: SUPPLY,
+        ..token_params(&env, "DUST")
+    };
+    let token = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+    client.set_clawback(&token, &creator, &true);
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        creator,
+        token,
+    }
+}
+
+fn seed(s: &Setup, amount: i128) -> Address {
+    let holder = Address::generate(&s.env);
+    s.env.as_contract(&s.contract_id, || {
+        storage::set_balance(&s.env, 0, &holder, amount)
+    });
+    holder
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, 0, holder))
+}
+
+#[test]
+fn test_sweeps_only_sub_threshold_balances() {
+    let s = setup();
+    let dust_a = seed(&s, 1);
+    let dust_b = seed(&s, THRESHOLD - 1);
+    let at_threshold = seed(&s, THRESHOLD);
+    let large = seed(&s, 5_000);
+    let empty = Address::generate(&s.env);
+
+    let holders = vec![
+        &s.env,
+        dust_a.clone(),
+        dust_b.clone(),
+        at_threshold.clone(),
+        large.clone(),
+        empty.clone(),
+    ];
+    let swept = s
+        .client
+        .sweep_dust(&s.token, &s.creator, &THRESHOLD, &holders);
+
+    assert_eq!(swept, THRESHOLD);
+    assert_eq!(balance(&s, &dust_a), 0);
+    assert_eq!(balance(&s, &dust_b), 0);
+    assert_eq!(balance(&s, &at_threshold), THRESHOLD);
+    assert_eq!(balance(&s, &large), 5_000);
+    assert_eq!(balance(&s, &empty), 0);
+
+    let info = s.client.get_token_info_by_address(&s.token);
+    assert_eq!(info.total_supply, SUPPLY - THRESHOLD);
+    assert_eq!(info.total_burned, THRESHOLD);
+    assert_eq!(info.burn_count, 2);
+    assert_eq!(s.client.get_token_info(&0).total_supply, SUPPLY - THRESHOLD);
+}
+
+#[test]
+fn test_nothing_below_threshold_is_noop() {
+    let s = setup();
+    let large = seed(&s, 5_000);
+
+    let swept = s.client.sweep_dust(
+        &s.token,
+        &s.creator,
+        &THRESHOLD,
+        &vec![&s.env, large.clone()],
+    );
+
+    assert_eq!(swept, 0);
+    assert_eq!(balance(&s, &large), 5_000);
+    assert_eq!(
+        s.client.get_token_info_by_address(&s.token).total_supply,
+        SUPPLY
+    );
+}
+
+#[test]
+fn test_requires_clawback_enabled() {
+    let s = setup();
+    let dust = seed(&s, 1);
+    s.client.set_clawback(&s.token, &s.creator, &false);
+
+    let result = s.client.try_sweep_dust(
+        &s.token,
+        &s.creator,
+        &THRESHOLD,
+        &vec![&s.env, dust.clone()],
+    );
+
+    assert_eq!(result, Err(Ok(Error::ClawbackDisabled)));
+    assert_eq!(balance(&s, &dust), 1);
+}
+
+#[test]
+fn test_non_creator_rejected() {
+    let s = setup();
+    let dust = seed(&s, 1);
+
+    let result = s.client.try_sweep_dust(
+        &s.token,
+        &Address::generate(&s.env),
+        &THRESHOLD,
+        &vec![&s.env, dust],
+    );
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_rejects_bad_inputs() {
+    let s = setup();
+    let dust = seed(&s, 1);
+
+    assert_eq!(
+        s.client
+            .try_sweep_dust(&s.token, &s.creator, &THRESHOLD, &Vec::new(&s.env)),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_sweep_dust(&s.token, &s.creator, &0, &vec![&s.env, dust.clone()]),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    let mut holders = Vec::new(&s.env);
+    for _ in 0..101 {
+        holders.push_back(dust.clone());
+    }
+    assert_eq!(
+        s.client
+            .try_sweep_dust(&s.token, &s.creator, &THRESHOLD, &holders),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
+
+#[test]
+fn test_sweep_after_burn_keeps_burn_accounting() {
+    let s = setup();
+    s.client.burn(&s.creator, &0, &400_000);
+    let dust = seed(&s, 5);
+
+    s.client
+        .sweep_dust(&s.token, &s.creator, &THRESHOLD, &vec![&s.env, dust]);
+
+    let info = s.client.get_token_info(&0);
+    assert_eq!(info.total_supply, 599_995);
+    assert_eq!(info.total_burned, 400_005);
+}
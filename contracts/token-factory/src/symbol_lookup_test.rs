@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn params(env: &Env, symbol: &str) -> TokenCreationParams {
+    TokenCreationParams {
+        name: String::from_str(env, "Symbol Token"),
+        ..token_params(env, symbol)
+    }
+}
+
+#[test]
+fn test_lookup_by_symbol_in_unique_mode() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.set_unique_symbols(&admin, &true);
+
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params(&env, "XYZ")], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    let info = client.get_token_by_symbol(&String::from_str(&env, "XYZ"));
+    assert_eq!(info.address, token);
+    assert_eq!(info.creator, creator);
+}
+
+#[test]
+fn test_unknown_symbol_returns_token_not_found() {
+    let (env, client, admin) = setup();
+    client.set_unique_symbols(&admin, &true);
+
+    let result = client.try_get_token_by_symbol(&String::from_str(&env, "NOPE"));
+
+    assert_eq!(result, Err(Ok(Error::TokenNotFound)));
+}
+
+#[test]
+fn test_lookup_refused_when_unique_mode_disabled() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    client.batch_create_tokens(&creator, &vec![&env, params(&env, "XYZ")], &BASE_FEE, &None);
+
+    let result = client.try_get_token_by_symbol(&String::from_str(&env, "XYZ"));
+
+    assert_eq!(result, Err(Ok(Error::UniqueSymbolsDisabled)));
+}
+
+#[test]
+fn test_duplicate_symbol_rejected_in_unique_mode() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.set_unique_symbols(&admin, &true);
+    client.batch_create_tokens(&creator, &vec![&env, params(&env, "XYZ")], &BASE_FEE, &None);
+
+    let spec = client.try_validate_token_spec(
+        &creator,
+        &String::from_str(&env, "Symbol Token"),
+        &String::from_str(&env, "XYZ"),
+        &7,
+        &1_000_000,
+        &None,
+    );
+    assert_eq!(spec, Err(Ok(Error::SymbolTaken)));
+
+    assert_eq!(
+        client.try_begin_create(&creator, &params(&env, "XYZ"), &BASE_FEE, &None),
+        Err(Ok(Error::SymbolTaken))
+    );
+}
+
+#[test]
+fn test_lookup_by_symbol_reflects_burns() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    client.set_unique_symbols(&admin, &true);
+    client.batch_create_tokens(&creator, &vec![&env, params(&env, "XYZ")], &BASE_FEE, &None);
+
+    client.burn(&creator, &0, &400_000);
+
+    let info = client.get_token_by_symbol(&String::from_str(&env, "XYZ"));
+    assert_eq!(info, client.get_token_info(&0));
+    assert_eq!(info.total_supply, 600_000);
+    assert_eq!(info.total_burned, 400_000);
+}
@@ -4,11 +4,41 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use soroban_sdk::testutils::{Address as _, Events, Ledger};
-use soroban_sdk::{Address, Bytes, Env, Symbol, TryFromVal, Val};
+use soroban_sdk::{Address, Bytes, Env, String, Symbol, TryFromVal, Val};
 
 use crate::storage;
 use crate::timelock;
-use crate::types::{ActionType, Error, VoteChoice};
+use crate::types::{ActionType, Error, TokenCreationParams, VoteChoice};
+use crate::{TokenFactory, TokenFactoryClient};
+
+/// Base fee `FactoryEnv` initializes the factory with
+pub const BASE_FEE: i128 = 70_000_000;
+
+/// Metadata fee `FactoryEnv` initializes the factory with
+pub const METADATA_FEE: i128 = 30_000_000;
+
+/// How a test environment treats `require_auth`
+///
+/// Most tests run under `MockAll`, which hides missing signatures. Security
+/// tests switch to `Strict` after setup so every `require_auth` must be
+/// satisfied by an explicit authorization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthMode {
+    /// Every authorization succeeds (`mock_all_auths`)
+    MockAll,
+    /// No authorization is provided; `require_auth` traps
+    Strict,
+}
+
+impl AuthMode {
+    /// Switch `env` to this mode
+    pub fn apply(self, env: &Env) {
+        match self {
+            AuthMode::MockAll => env.mock_all_auths(),
+            AuthMode::Strict => env.set_auths(&[]),
+        }
+    }
+}
 
 pub struct TestEnv {
     pub env: Env,
@@ -48,6 +78,56 @@ impl TestEnv {
     }
 }
 
+/// A factory contract registered and initialized through its client
+///
+/// Runs under `mock_all_auths` with `BASE_FEE` and `METADATA_FEE`.
+pub struct FactoryEnv {
+    pub env: Env,
+    pub contract_id: Address,
+    pub client: TokenFactoryClient<'static>,
+    pub admin: Address,
+    pub treasury: Address,
+}
+
+impl FactoryEnv {
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, TokenFactory);
+        let client = TokenFactoryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        client.initialize(&admin, &treasury, &BASE_FEE, &METADATA_FEE);
+
+        Self {
+            env,
+            contract_id,
+            client,
+            admin,
+            treasury,
+        }
+    }
+}
+
+/// Fungible token parameters: 7 decimals, a supply of 1,000,000 and no
+/// optional settings
+pub fn token_params(env: &Env, symbol: &str) -> TokenCreationParams {
+    TokenCreationParams {
+        name: String::from_str(env, "Test Token"),
+        symbol: String::from_str(env, symbol),
+        decimals: 7,
+        initial_supply: 1_000_000,
+        max_supply: None,
+        metadata_uri: None,
+        display_decimals: None,
+        min_transfer: None,
+        burnable: None,
+        min_balance: None,
+    }
+}
+
 pub struct ActorGenerator<'a> {
     env: &'a Env,
 }
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String};
+
+const DAY: u64 = 86_400;
+const START: u64 = 1_700_000_000;
+
+/// Creates one token at each of the given timestamps
+fn setup(timestamps: &[u64]) -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    for ts in timestamps {
+        env.ledger().set_timestamp(*ts);
+        let params = token_params(&env, "TIME");
+        client.batch_create_tokens(
+            &Address::generate(&env),
+            &vec![&env, params],
+            &BASE_FEE,
+            &None,
+        );
+    }
+
+    (env, client)
+}
+
+#[test]
+fn test_range_returns_tokens_in_window() {
+    let (env, client) = setup(&[START, START + DAY, START + 2 * DAY, START + 10 * DAY]);
+
+    let result = client.list_tokens_by_time_range(&(START + DAY), &(START + 3 * DAY), &100);
+
+    assert_eq!(result, vec![&env, 1, 2]);
+}
+
+#[test]
+fn test_range_includes_start_and_excludes_end() {
+    let (env, client) = setup(&[START, START + 100, START + 200]);
+
+    let result = client.list_tokens_by_time_range(&(START + 100), &(START + 200), &100);
+
+    assert_eq!(result, vec![&env, 1]);
+}
+
+#[test]
+fn test_range_filters_within_shared_bucket() {
+    // All three tokens fall in the same day bucket
+    let (env, client) = setup(&[START, START + 10, START + 20]);
+
+    let result = client.list_tokens_by_time_range(&(START + 5), &(START + 15), &100);
+
+    assert_eq!(result, vec![&env, 1]);
+}
+
+#[test]
+fn test_range_respects_limit() {
+    let (env, client) = setup(&[START, START + 1, START + 2, START + 3]);
+
+    let result = client.list_tokens_by_time_range(&START, &(START + DAY), &2);
+
+    assert_eq!(result, vec![&env, 0, 1]);
+}
+
+#[test]
+fn test_empty_and_inverted_ranges() {
+    let (env, client) = setup(&[START]);
+
+    assert_eq!(
+        client.list_tokens_by_time_range(&START, &START, &100),
+        vec![&env]
+    );
+    assert_eq!(
+        client.try_list_tokens_by_time_range(&(START + 1), &START, &100),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_range_spanning_too_many_days_rejected() {
+    let (_env, client) = setup(&[START]);
+
+    let result = client.try_list_tokens_by_time_range(&START, &(START + 400 * DAY), &100);
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
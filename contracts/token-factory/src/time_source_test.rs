@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+const TIMESTAMP: u64 = 1_700_000_000;
+const SEQUENCE: u32 = 4_242;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+    env.ledger().with_mut(|li| {
+        li.timestamp = TIMESTAMP;
+        li.sequence_number = SEQUENCE;
+    });
+
+    (env, client, admin)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, symbol: &str) -> TokenInfo {
+    let spec = token_params(env, symbol);
+    let address = client.create_token(
+        &Address::generate(env),
+        &spec,
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+    client.get_token_info_by_address(&address)
+}
+
+#[test]
+fn test_defaults_to_timestamp() {
+    let (env, client, _) = setup();
+
+    assert_eq!(client.get_time_source(), TimeSource::Timestamp);
+
+    let info = create(&env, &client, "TIME");
+    assert_eq!(info.created_at, TIMESTAMP);
+    assert_eq!(info.created_at_source, TimeSource::Timestamp);
+}
+
+#[test]
+fn test_sequence_mode_records_ledger_sequence() {
+    let (env, client, admin) = setup();
+
+    client.set_time_source(&admin, &TimeSource::Sequence);
+    assert_eq!(client.get_time_source(), TimeSource::Sequence);
+
+    let info = create(&env, &client, "SEQ");
+    assert_eq!(info.created_at, SEQUENCE as u64);
+    assert_eq!(info.created_at_source, TimeSource::Sequence);
+}
+
+#[test]
+fn test_switching_source_leaves_existing_tokens() {
+    let (env, client, admin) = setup();
+
+    let before = create(&env, &client, "OLD");
+    client.set_time_source(&admin, &TimeSource::Sequence);
+    let after = create(&env, &client, "NEW");
+
+    let before = client.get_token_info_by_address(&before.address);
+    assert_eq!(before.created_at, TIMESTAMP);
+    assert_eq!(before.created_at_source, TimeSource::Timestamp);
+    assert_eq!(after.created_at, SEQUENCE as u64);
+    assert_eq!(after.created_at_source, TimeSource::Sequence);
+}
+
+#[test]
+fn test_non_admin_cannot_set_time_source() {
+    let (env, client, _) = setup();
+
+    let result = client.try_set_time_source(&Address::generate(&env), &TimeSource::Sequence);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_time_source(), TimeSource::Timestamp);
+}
@@ -1,4 +1,5 @@
 use crate::events;
+use crate::fees;
 use crate::payload_validation;
 use crate::storage;
 use crate::types::{
@@ -254,6 +255,7 @@ pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
             if let Some(fee) = pending_change.metadata_fee {
                 storage::set_metadata_fee(env, fee);
             }
+            fees::record_fee_change(env);
 
             let new_base = pending_change
                 .base_fee
@@ -262,6 +264,13 @@ pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
                 .metadata_fee
                 .unwrap_or_else(|| storage::get_metadata_fee(env));
             events::emit_fees_updated(env, new_base, new_metadata);
+            events::emit_fees_applied(
+                env,
+                change_id,
+                &pending_change.scheduled_by,
+                (pending_change.base_fee, pending_change.metadata_fee),
+                (new_base, new_metadata),
+            );
         }
         ChangeType::PauseUpdate => {
             if let Some(paused) = pending_change.paused {
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup(tokens: u32) -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let mut params = Vec::new(&env);
+    for _ in 0..tokens {
+        params.push_back(token_params(&env, "LIST"));
+    }
+    client.batch_create_tokens(&creator, &params, &(BASE_FEE * tokens as i128), &None);
+
+    (env, client, creator)
+}
+
+#[test]
+fn test_addresses_match_token_info_across_pages() {
+    let (_env, client, _creator) = setup(7);
+
+    let mut listed = client.list_token_addresses(&0, &3);
+    assert_eq!(listed.len(), 3);
+    listed.append(&client.list_token_addresses(&3, &3));
+    let last = client.list_token_addresses(&6, &3);
+    assert_eq!(last.len(), 1);
+    listed.append(&last);
+    assert!(client.list_token_addresses(&9, &3).is_empty());
+
+    assert_eq!(listed.len(), 7);
+    for index in 0..7 {
+        assert_eq!(
+            listed.get(index).unwrap(),
+            client.get_token_info(&index).address
+        );
+    }
+}
+
+#[test]
+fn test_bounds_match_export_registry() {
+    let (_env, client, creator) = setup(4);
+    client.archive_token(&client.get_token_info(&1).address, &creator);
+
+    for (start, limit) in [(0, 0), (0, 2), (2, 500), (3, 1), (4, 1), (u32::MAX, 10)] {
+        let addresses = client.list_token_addresses(&start, &limit);
+        let exported = client.export_registry(&start, &limit);
+        assert_eq!(addresses.len(), exported.len());
+        for (address, summary) in addresses.iter().zip(exported.iter()) {
+            assert_eq!(address, summary.address);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Structured per-token metadata
+//!
+//! Creators can attach short key/value attributes (website, twitter, logo,
+//! ...) to their tokens alongside the single metadata URI. Attributes stay
+//! editable until the creator freezes the token's metadata.
+
+use crate::events;
+use crate::storage;
+use crate::types::{Error, OpKind};
+use soroban_sdk::{Address, Env, String};
+
+/// Maximum attribute key length in bytes
+pub const MAX_ATTRIBUTE_KEY_LENGTH: u32 = 32;
+
+/// Maximum attribute value length in bytes
+pub const MAX_ATTRIBUTE_VALUE_LENGTH: u32 = 256;
+
+/// Maximum number of distinct attributes per token
+pub const MAX_TOKEN_ATTRIBUTES: u32 = 16;
+
+/// Set (or overwrite) an attribute on a token
+///
+/// # Returns
+/// * `Ok(())` - Attribute stored
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::MetadataFrozen)` - Token metadata has been frozen
+/// * `Err(Error::InvalidParameters)` - Key or value length out of bounds
+/// * `Err(Error::AttributeLimitExceeded)` - Token already has the maximum
+///   number of attributes
+pub fn set_token_attribute(
+    env: &Env,
+    token_address: &Address,
+    creator: &Address,
+    key: &String,
+    value: &String,
+) -> Result<(), Error> {
+    require_creator(env, token_address, creator)?;
+
+    if key.is_empty() || key.len() > MAX_ATTRIBUTE_KEY_LENGTH {
+        return Err(Error::InvalidParameters);
+    }
+    if value.is_empty() || value.len() > MAX_ATTRIBUTE_VALUE_LENGTH {
+        return Err(Error::InvalidParameters);
+    }
+
+    // Only new keys count towards the cap; overwriting is always allowed
+    if storage::get_token_attribute(env, token_address, key).is_none() {
+        let count = storage::get_token_attribute_count(env, token_address);
+        if count >= MAX_TOKEN_ATTRIBUTES {
+            return Err(Error::AttributeLimitExceeded);
+        }
+        storage::set_token_attribute_count(env, token_address, count + 1);
+    }
+
+    storage::set_token_attribute(env, token_address, key, value);
+    events::emit_token_attribute_set(env, token_address, key, value);
+    bump_metadata_version(env, token_address, creator)?;
+    crate::fees::charge_op_fee(env, creator, OpKind::Metadata)?;
+
+    Ok(())
+}
+
+/// Permanently freeze a token's metadata
+///
+/// # Returns
+/// * `Ok(())` - Metadata frozen
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::MetadataFrozen)` - Metadata is already frozen
+pub fn freeze_metadata(env: &Env, token_address: &Address, creator: &Address) -> Result<(), Error> {
+    require_creator(env, token_address, creator)?;
+
+    storage::set_metadata_frozen(env, token_address);
+    events::emit_metadata_frozen(env, token_address, creator);
+
+    Ok(())
+}
+
+/// Increment a token's metadata version and emit `meta_upd`
+///
+/// Updates both the index-keyed and address-keyed records and returns the
+/// new version.
+pub fn bump_metadata_version(
+    env: &Env,
+    token_address: &Address,
+    updater: &Address,
+) -> Result<u32, Error> {
+    let index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let mut info = storage::get_token_info(env, index).ok_or(Error::TokenNotFound)?;
+
+    info.metadata_version = info.metadata_version.saturating_add(1);
+    storage::set_token_info(env, index, &info);
+    storage::set_token_info_by_address(env, token_address, &info);
+
+    events::emit_metadata_updated(env, token_address, updater, info.metadata_version);
+
+    Ok(info.metadata_version)
+}
+
+fn require_creator(env: &Env, token_address: &Address, creator: &Address) -> Result<(), Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    creator.require_auth();
+
+    let info = storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
+    if info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if storage::is_metadata_frozen(env, token_address) {
+        return Err(Error::MetadataFrozen);
+    }
+    crate::token_creation::ensure_token_unlocked(env, token_address)?;
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use crate::token_attributes::MAX_TOKEN_ATTRIBUTES;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let params = token_params(&env, "ATTR");
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+
+    (env, client, creator, token)
+}
+
+#[test]
+fn test_set_and_get_attributes() {
+    let (env, client, creator, token) = setup();
+    let website = String::from_str(&env, "website");
+    let twitter = String::from_str(&env, "twitter");
+
+    client.set_token_attribute(&token, &creator, &website, &String::from_str(&env, "https://nova.example"));
+    client.set_token_attribute(&token, &creator, &twitter, &String::from_str(&env, "@nova"));
+
+    assert_eq!(
+        client.get_token_attribute(&token, &website),
+        Some(String::from_str(&env, "https://nova.example"))
+    );
+    assert_eq!(
+        client.get_token_attribute(&token, &twitter),
+        Some(String::from_str(&env, "@nova"))
+    );
+    assert_eq!(client.get_token_attribute(&token, &String::from_str(&env, "logo")), None);
+}
+
+#[test]
+fn test_overwrite_attribute() {
+    let (env, client, creator, token) = setup();
+    let key = String::from_str(&env, "website");
+
+    client.set_token_attribute(&token, &creator, &key, &String::from_str(&env, "https://old.example"));
+    client.set_token_attribute(&token, &creator, &key, &String::from_str(&env, "https://new.example"));
+
+    assert_eq!(
+        client.get_token_attribute(&token, &key),
+        Some(String::from_str(&env, "https://new.example"))
+    );
+}
+
+#[test]
+fn test_frozen_metadata_blocks_changes() {
+    let (env, client, creator, token) = setup();
+    let key = String::from_str(&env, "website");
+    client.set_token_attribute(&token, &creator, &key, &String::from_str(&env, "https://nova.example"));
+
+    client.freeze_token_metadata(&token, &creator);
+
+    assert!(client.is_token_metadata_frozen(&token));
+    let result = client.try_set_token_attribute(&token, &creator, &key, &String::from_str(&env, "https://x.example"));
+    assert_eq!(result, Err(Ok(Error::MetadataFrozen)));
+    assert_eq!(
+        client.get_token_attribute(&token, &key),
+        Some(String::from_str(&env, "https://nova.example"))
+    );
+}
+
+#[test]
+fn test_attribute_count_cap() {
+    let (env, client, creator, token) = setup();
+    let value = String::from_str(&env, "v");
+
+    for i in 0..MAX_TOKEN_ATTRIBUTES {
+        let key = String::from_str(&env, &std::format!("key{}", i));
+        client.set_token_attribute(&token, &creator, &key, &value);
+    }
+
+    let result = client.try_set_token_attribute(&token, &creator, &String::from_str(&env, "one_more"), &value);
+    assert_eq!(result, Err(Ok(Error::AttributeLimitExceeded)));
+
+    // Overwriting an existing key is still allowed at the cap
+    client.set_token_attribute(&token, &creator, &String::from_str(&env, "key0"), &String::from_str(&env, "w"));
+}
+
+#[test]
+fn test_attribute_length_bounds() {
+    let (env, client, creator, token) = setup();
+
+    let long_key = String::from_str(&env, &"k".repeat(33));
+    let long_value = String::from_str(&env, &"v".repeat(257));
+
+    assert_eq!(
+        client.try_set_token_attribute(&token, &creator, &long_key, &String::from_str(&env, "v")),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_set_token_attribute(&token, &creator, &String::from_str(&env, "k"), &long_value),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_set_token_attribute(&token, &creator, &String::from_str(&env, ""), &String::from_str(&env, "v")),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_non_creator_cannot_set_attribute() {
+    let (env, client, _creator, token) = setup();
+    let other = Address::generate(&env);
+
+    let result = client.try_set_token_attribute(
+        &token,
+        &other,
+        &String::from_str(&env, "website"),
+        &String::from_str(&env, "https://evil.example"),
+    );
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
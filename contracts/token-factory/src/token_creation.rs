@@ -1,26 +1,38 @@
-use soroban_sdk::{Address, Env, String, Vec};
-use crate::types::{Error, TokenCreationParams, TokenInfo};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
+use crate::types::{
+    ClawbackMode, Error, IndexReservation, TimeSource, TokenCreationParams, TokenInfo, TokenKind,
+};
 use crate::storage;
 
+/// Longest accepted token name
+pub const MAX_NAME_LEN: u32 = 32;
+/// Longest accepted token symbol
+pub const MAX_SYMBOL_LEN: u32 = 12;
+/// Most decimals a token may have
+pub const MAX_DECIMALS: u32 = 18;
+
+/// Ledgers in one creation rate-limit window (about one minute)
+pub const CREATE_WINDOW_LEDGERS: u32 = 12;
+
 /// Validate token creation parameters
-fn validate_token_params(
+pub fn validate_token_params(
     name: &String,
     symbol: &String,
     decimals: u32,
     initial_supply: i128,
 ) -> Result<(), Error> {
     // Validate name length (1-32 characters)
-    if name.len() == 0 || name.len() > 32 {
+    if name.len() == 0 || name.len() > MAX_NAME_LEN {
         return Err(Error::InvalidTokenParams);
     }
 
     // Validate symbol length (1-12 characters)
-    if symbol.len() == 0 || symbol.len() > 12 {
+    if symbol.len() == 0 || symbol.len() > MAX_SYMBOL_LEN {
         return Err(Error::InvalidTokenParams);
     }
 
     // Validate decimals (0-18)
-    if decimals > 18 {
+    if decimals > MAX_DECIMALS {
         return Err(Error::InvalidTokenParams);
     }
 
@@ -32,8 +44,190 @@ fn validate_token_params(
     Ok(())
 }
 
+/// Validate an optional metadata URI
+///
+/// A URI, when given, must be non-empty and no longer than the configured
+/// maximum URI length.
+pub fn validate_metadata_uri(env: &Env, metadata_uri: Option<&String>) -> Result<(), Error> {
+    if let Some(uri) = metadata_uri {
+        if uri.is_empty() || uri.len() > storage::get_max_uri_length(env) {
+            return Err(Error::InvalidParameters);
+        }
+    }
+    Ok(())
+}
+
+/// Validate decimals against the configured floor
+///
+/// The 18-decimal ceiling is checked by `validate_token_params`.
+pub fn validate_min_decimals(env: &Env, decimals: u32) -> Result<(), Error> {
+    if decimals < storage::get_min_decimals(env) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Validate a display precision override
+///
+/// Display decimals only change how wallets render amounts, so they can
+/// hide precision but never add it.
+pub fn validate_display_decimals(
+    display_decimals: Option<u32>,
+    decimals: u32,
+) -> Result<(), Error> {
+    match display_decimals {
+        Some(display) if display > decimals => Err(Error::InvalidTokenParams),
+        _ => Ok(()),
+    }
+}
+
+/// Split a raw amount into whole units and the raw fractional remainder
+///
+/// `raw = whole * 10^decimals + fraction`, with the fraction taking the
+/// sign of `raw` (truncating division).
+///
+/// # Errors
+/// * `ArithmeticError` - `10^decimals` overflows
+pub fn split_units(raw: i128, decimals: u32) -> Result<(i128, i128), Error> {
+    let unit = 10i128.checked_pow(decimals).ok_or(Error::ArithmeticError)?;
+    let whole = raw.checked_div(unit).ok_or(Error::ArithmeticError)?;
+    let fraction = raw.checked_rem(unit).ok_or(Error::ArithmeticError)?;
+    Ok((whole, fraction))
+}
+
+/// Validate a minimum transfer amount
+///
+/// A minimum must be positive; `None` allows transfers of any amount.
+pub fn validate_min_transfer(min_transfer: Option<i128>) -> Result<(), Error> {
+    match min_transfer {
+        Some(min) if min <= 0 => Err(Error::InvalidTokenParams),
+        _ => Ok(()),
+    }
+}
+
+/// Validate a minimum account balance
+///
+/// A minimum must be positive; `None` allows balances of any size.
+pub fn validate_min_balance(min_balance: Option<i128>) -> Result<(), Error> {
+    match min_balance {
+        Some(min) if min <= 0 => Err(Error::InvalidTokenParams),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a resulting balance that is nonzero but below the token minimum
+pub fn ensure_min_balance(info: &TokenInfo, balance: i128) -> Result<(), Error> {
+    match info.min_balance {
+        Some(min) if balance != 0 && balance < min => Err(Error::BelowMinBalance),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a recipient that holds none of the token's gate token
+///
+/// Ungated tokens accept any recipient. The gate balance is read from the
+/// factory registry, so a gate token that is no longer registered admits
+/// nobody.
+pub fn ensure_gate_holder(env: &Env, info: &TokenInfo, recipient: &Address) -> Result<(), Error> {
+    let Some(gate_token) = &info.gate_token else {
+        return Ok(());
+    };
+    let holds_gate = storage::get_token_index(env, gate_token)
+        .is_some_and(|gate_index| storage::get_balance(env, gate_index, recipient) > 0);
+    if !holds_gate {
+        return Err(Error::GateTokenRequired);
+    }
+    Ok(())
+}
+
+/// Validate an initial supply against the configured raw supply ceiling
+///
+/// The ceiling is in raw units, whatever the decimals, and defaults to
+/// half the i128 range so minted supply cannot overflow.
+pub fn validate_max_raw_supply(env: &Env, initial_supply: i128) -> Result<(), Error> {
+    if initial_supply > storage::get_max_raw_supply(env) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Reject any change to a token the admin has locked
+pub fn ensure_token_unlocked(env: &Env, token_address: &Address) -> Result<(), Error> {
+    if storage::is_token_locked(env, token_address) {
+        return Err(Error::TokenLocked);
+    }
+    Ok(())
+}
+
+/// Reject token creation after an emergency shutdown
+pub fn ensure_creation_enabled(env: &Env) -> Result<(), Error> {
+    if storage::is_creation_disabled(env) {
+        return Err(Error::CreationDisabled);
+    }
+    Ok(())
+}
+
+/// Reject creators holding less native XLM than the configured minimum
+///
+/// Disabled (the default) when no minimum is set. The balance is read
+/// from the native asset contract configured with the minimum.
+pub fn ensure_creator_balance(env: &Env, creator: &Address) -> Result<(), Error> {
+    let Some((native_asset, min_balance)) = storage::get_min_creator_balance(env) else {
+        return Ok(());
+    };
+    if soroban_sdk::token::Client::new(env, &native_asset).balance(creator) < min_balance {
+        return Err(Error::InsufficientCreatorBalance);
+    }
+    Ok(())
+}
+
+/// Creations already counted in the current ledger window
+fn creations_this_window(env: &Env) -> (u32, u32) {
+    let window = env.ledger().sequence() / CREATE_WINDOW_LEDGERS;
+    let (last_window, count) = storage::get_create_window(env);
+    if last_window == window {
+        (window, count)
+    } else {
+        (window, 0)
+    }
+}
+
+/// Reject `count` more creations if they would pass the per-window cap
+pub fn ensure_create_rate(env: &Env, count: u32) -> Result<(), Error> {
+    let cap = storage::get_window_create_cap(env);
+    if cap > 0 && creations_this_window(env).1.saturating_add(count) > cap {
+        return Err(Error::CreateRateLimited);
+    }
+    Ok(())
+}
+
+/// Count one creation against the current ledger window
+///
+/// Fails with `CreateRateLimited` when the window is already full.
+pub fn record_window_create(env: &Env) -> Result<(), Error> {
+    ensure_create_rate(env, 1)?;
+    let (window, count) = creations_this_window(env);
+    storage::set_create_window(env, window, count + 1);
+    Ok(())
+}
+
+/// Validate the shape a token kind requires
+///
+/// Non-fungible tokens are single collectibles and must have 0 decimals
+/// and an initial supply of exactly 1.
+pub fn validate_token_kind(
+    kind: TokenKind,
+    decimals: u32,
+    initial_supply: i128,
+) -> Result<(), Error> {
+    if kind == TokenKind::NonFungible && (decimals != 0 || initial_supply != 1) {
+        return Err(Error::InvalidTokenParams);
+    }
+    Ok(())
+}
+
 /// Calculate total fee for token creation
-fn calculate_creation_fee(env: &Env, has_metadata: bool) -> i128 {
+pub fn calculate_creation_fee(env: &Env, has_metadata: bool) -> i128 {
     let base_fee = storage::get_base_fee(env);
     let metadata_fee = if has_metadata {
         storage::get_metadata_fee(env)
@@ -44,48 +238,323 @@ fn calculate_creation_fee(env: &Env, has_metadata: bool) -> i128 {
     base_fee + metadata_fee
 }
 
-/// Create a single token (internal implementation)
-pub fn create_token_internal(
+/// Calculate the creation fee for a token spec, including any supply tier surcharge
+pub fn calculate_token_fee(env: &Env, params: &TokenCreationParams) -> Result<i128, Error> {
+    calculate_creation_fee(env, params.metadata_uri.is_some())
+        .checked_add(crate::fees::supply_surcharge(env, params.initial_supply))
+        .ok_or(Error::ArithmeticError)
+}
+
+/// Split the creation fee of a token spec into `(base, metadata)`
+///
+/// `base` includes any supply tier surcharge; `metadata` is only due when
+/// the spec has a metadata URI.
+fn fee_components(env: &Env, params: &TokenCreationParams) -> Result<(i128, i128), Error> {
+    let base = storage::get_base_fee(env)
+        .checked_add(crate::fees::supply_surcharge(env, params.initial_supply))
+        .ok_or(Error::ArithmeticError)?;
+    Ok((base, storage::get_metadata_fee(env)))
+}
+
+/// Split the creation fee `creator` owes for a token spec into
+/// `(base, metadata)`
+///
+/// Every creation path prices its tokens here, so fee exemptions and
+/// waivers apply to single, batch and escrowed creation alike:
+/// 1. Exempt creators owe nothing and keep their waivers
+/// 2. A `waiver_code` with uses left is redeemed once and waives the fee
+/// 3. Otherwise, if a fee is due, one signed-waiver credit waives it
+pub fn creation_fee_due(
     env: &Env,
     creator: &Address,
     params: &TokenCreationParams,
-    token_index: u32,
-) -> Result<Address, Error> {
-    // Validate parameters
+    waiver_code: Option<&Bytes>,
+) -> Result<(i128, i128), Error> {
+    if crate::fees::is_fee_exempt(env, creator) {
+        return Ok((0, 0));
+    }
+    if waiver_code.is_some_and(|code| crate::fees::redeem_waiver(env, creator, code)) {
+        return Ok((0, 0));
+    }
+
+    let (base, metadata) = fee_components(env, params)?;
+    let due = base > 0 || (params.metadata_uri.is_some() && metadata > 0);
+    if due && crate::fees::take_signed_waiver(env, creator) {
+        return Ok((0, 0));
+    }
+    Ok((base, metadata))
+}
+
+/// Check a token spec against every rule creation enforces on it
+///
+/// The single source of spec validation: `create_token_internal` and
+/// `dry_run_create` both call it, so validation cannot drift from
+/// creation. Returns the symbol as it would be stored.
+pub fn validate_creation_spec(
+    env: &Env,
+    params: &TokenCreationParams,
+    token_kind: TokenKind,
+) -> Result<String, Error> {
     validate_token_params(
         &params.name,
         &params.symbol,
         params.decimals,
         params.initial_supply,
     )?;
+    validate_token_kind(token_kind, params.decimals, params.initial_supply)?;
+    validate_metadata_uri(env, params.metadata_uri.as_ref())?;
+    validate_min_decimals(env, params.decimals)?;
+    validate_display_decimals(params.display_decimals, params.decimals)?;
+    validate_min_transfer(params.min_transfer)?;
+    validate_min_balance(params.min_balance)?;
+    validate_max_raw_supply(env, params.initial_supply)?;
+    crate::mint::check_global_supply(env, params.initial_supply)?;
+
+    let symbol = canonical_symbol(env, &params.symbol);
+    ensure_symbol_not_reserved(env, &symbol)?;
+
+    // Enforce unique symbols when the registry is authoritative
+    if storage::is_unique_symbols_enabled(env) && storage::get_symbol_owner(env, &symbol).is_some()
+    {
+        return Err(Error::SymbolTaken);
+    }
+
+    Ok(symbol)
+}
+
+/// Run every check token creation performs, without changing state
+///
+/// Used by `validate_token_spec` so clients can test a spec before paying
+/// the creation fee. Spec checks go through `validate_creation_spec`, as
+/// in `create_token`. `creator` is checked against the minimum creator
+/// balance, if set.
+pub fn dry_run_create(
+    env: &Env,
+    creator: &Address,
+    params: &TokenCreationParams,
+    token_kind: TokenKind,
+) -> Result<(), Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    ensure_creation_enabled(env)?;
+    ensure_creator_balance(env, creator)?;
+
+    // Creation would need a fresh token index
+    storage::get_token_count(env)
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+
+    validate_creation_spec(env, params, token_kind)?;
+    ensure_create_rate(env, 1)?;
+
+    Ok(())
+}
+
+/// Predict the index and address a creation would get, without creating
+///
+/// Runs `dry_run_create` for `token_kind`, so a spec is rejected with the
+/// same error `create_token` would return. There is no salt to pass: token
+/// addresses are derived by `derive_token_address` from the next free
+/// token index, exactly as the next unreserved creation will.
+pub fn simulate_create_token(
+    env: &Env,
+    creator: &Address,
+    params: &TokenCreationParams,
+    token_kind: TokenKind,
+) -> Result<(u32, Address), Error> {
+    dry_run_create(env, creator, params, token_kind)?;
+
+    let token_index = storage::get_token_count(env);
+    let token_address = derive_token_address(env, token_index);
+    ensure_distinct_token_address(env, &token_address, creator)?;
+
+    Ok((token_index, token_address))
+}
+
+/// ASCII-uppercase a symbol; non-ASCII bytes are left as they are
+///
+/// Symbols longer than `MAX_SYMBOL_LEN` are returned unchanged, since
+/// validation rejects them anyway.
+pub fn normalize_symbol(env: &Env, symbol: &String) -> String {
+    let len = symbol.len() as usize;
+    if len > MAX_SYMBOL_LEN as usize {
+        return symbol.clone();
+    }
+    let mut buf = [0u8; MAX_SYMBOL_LEN as usize];
+    symbol.copy_into_slice(&mut buf[..len]);
+    buf[..len].make_ascii_uppercase();
+    String::from_bytes(env, &buf[..len])
+}
+
+/// The symbol as it would be stored: uppercased when normalization is on
+pub fn canonical_symbol(env: &Env, symbol: &String) -> String {
+    if storage::is_normalize_symbols_enabled(env) {
+        normalize_symbol(env, symbol)
+    } else {
+        symbol.clone()
+    }
+}
+
+/// Reject a symbol on the admin-maintained reserved list
+pub fn ensure_symbol_not_reserved(env: &Env, symbol: &String) -> Result<(), Error> {
+    if storage::is_symbol_reserved(env, &canonical_symbol(env, symbol)) {
+        return Err(Error::SymbolReserved);
+    }
+    Ok(())
+}
+
+/// Collect every problem with a token spec instead of stopping at the first
+///
+/// Checks the same rules as creation, but reports each with its own code
+/// so a form can flag every bad field at once:
+/// - `InvalidName` - name empty or over 32 characters
+/// - `InvalidSymbol` - symbol empty or over 12 characters
+/// - `InvalidDecimals` - decimals over 18, below the configured minimum,
+///   or under the display decimals
+/// - `InvalidSupply` - initial supply not positive or above the raw
+///   supply ceiling
+/// - `InvalidParameters` - metadata URI empty or too long
+/// - `SymbolTaken` - symbol registered while unique symbols are enforced
+/// - `SymbolReserved` - symbol on the reserved blocklist
+///
+/// Contract state (pause, shutdown) is not a spec problem and is left to
+/// `dry_run_create`. Creation itself still fails fast on the first error.
+pub fn collect_spec_violations(env: &Env, params: &TokenCreationParams) -> Vec<Error> {
+    let mut violations = Vec::new(env);
+
+    if params.name.is_empty() || params.name.len() > MAX_NAME_LEN {
+        violations.push_back(Error::InvalidName);
+    }
+    if params.symbol.is_empty() || params.symbol.len() > MAX_SYMBOL_LEN {
+        violations.push_back(Error::InvalidSymbol);
+    }
+    if params.decimals > MAX_DECIMALS
+        || validate_min_decimals(env, params.decimals).is_err()
+        || validate_display_decimals(params.display_decimals, params.decimals).is_err()
+    {
+        violations.push_back(Error::InvalidDecimals);
+    }
+    if params.initial_supply <= 0 || validate_max_raw_supply(env, params.initial_supply).is_err() {
+        violations.push_back(Error::InvalidSupply);
+    }
+    if validate_min_transfer(params.min_transfer).is_err() {
+        violations.push_back(Error::InvalidAmount);
+    }
+    if validate_min_balance(params.min_balance).is_err() {
+        violations.push_back(Error::InvalidAmount);
+    }
+    if let Err(err) = validate_metadata_uri(env, params.metadata_uri.as_ref()) {
+        violations.push_back(err);
+    }
+    if storage::is_unique_symbols_enabled(env)
+        && storage::get_symbol_owner(env, &canonical_symbol(env, &params.symbol)).is_some()
+    {
+        violations.push_back(Error::SymbolTaken);
+    }
+    if ensure_symbol_not_reserved(env, &params.symbol).is_err() {
+        violations.push_back(Error::SymbolReserved);
+    }
+
+    violations
+}
+
+/// Current ledger time as read by `source`
+pub fn creation_time(env: &Env, source: TimeSource) -> u64 {
+    match source {
+        TimeSource::Timestamp => env.ledger().timestamp(),
+        TimeSource::Sequence => env.ledger().sequence() as u64,
+    }
+}
+
+/// Derive the deterministic contract address for a token index
+///
+/// Uses the address the factory would deploy to with a salt of
+/// `sha256(token_index)`, so every token gets a distinct address.
+pub fn derive_token_address(env: &Env, token_index: u32) -> Address {
+    let salt: BytesN<32> = env
+        .crypto()
+        .sha256(&Bytes::from_array(env, &token_index.to_be_bytes()))
+        .into();
+    env.deployer().with_current_contract(salt).deployed_address()
+}
 
-    // Generate token address (placeholder - in production this would deploy actual token contract)
-    // For now, we create a deterministic address based on token index
-    let token_address = env.current_contract_address();
+/// Reject a token address that collides with a privileged address
+///
+/// A token sharing its address with the admin, the treasury or its creator
+/// would mix token records with account state. Derived addresses make this
+/// practically impossible, so hitting it means something is badly wrong.
+pub fn ensure_distinct_token_address(
+    env: &Env,
+    token_address: &Address,
+    creator: &Address,
+) -> Result<(), Error> {
+    if *token_address == storage::get_admin(env)
+        || *token_address == storage::get_treasury(env)
+        || token_address == creator
+    {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Create a single token (internal implementation)
+pub fn create_token_internal(
+    env: &Env,
+    creator: &Address,
+    params: &TokenCreationParams,
+    token_index: u32,
+    token_kind: TokenKind,
+) -> Result<Address, Error> {
+    // Validate parameters
+    let symbol = validate_creation_spec(env, params, token_kind)?;
+    record_window_create(env)?;
+
+    let token_address = derive_token_address(env, token_index);
+    ensure_distinct_token_address(env, &token_address, creator)?;
 
     // Create token info
+    let time_source = storage::get_time_source(env);
     let token_info = TokenInfo {
         address: token_address.clone(),
         creator: creator.clone(),
         name: params.name.clone(),
-        symbol: params.symbol.clone(),
+        symbol: symbol.clone(),
         decimals: params.decimals,
         total_supply: params.initial_supply,
         initial_supply: params.initial_supply,
         max_supply: None,
         metadata_uri: params.metadata_uri.clone(),
-        created_at: env.ledger().timestamp(),
+        created_at: creation_time(env, time_source),
         total_burned: 0,
         burn_count: 0,
         is_paused: false,
         clawback_enabled: false,
         freeze_enabled: false,
+        wasm_hash: storage::get_token_wasm_hash(env),
+        token_kind,
+        display_decimals: params.display_decimals,
+        created_at_source: time_source,
+        max_holders: None,
+        min_transfer: params.min_transfer,
+        clawback_mode: ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: params.burnable.unwrap_or(true),
+        min_balance: params.min_balance,
+        events_muted: false,
+        gate_token: None,
     };
 
+    crate::mint::issue_global_supply(env, params.initial_supply)?;
+
     // Store token info
     storage::set_token_info(env, token_index, &token_info);
     storage::set_token_info_by_address(env, &token_address, &token_info);
 
+    if storage::is_unique_symbols_enabled(env) {
+        storage::set_symbol_owner(env, &symbol, &token_address);
+    }
+
     // Set initial balance for creator
     storage::set_balance(env, token_index, creator, params.initial_supply);
 
@@ -95,10 +564,13 @@ pub fn create_token_internal(
         &token_address,
         creator,
         &params.name,
-        &params.symbol,
+        &symbol,
         params.decimals,
         params.initial_supply,
     );
+    if storage::is_webhook_topic_enabled(env) {
+        crate::events::emit_token_ping(env, &token_address, token_index);
+    }
 
     Ok(token_address)
 }
@@ -107,46 +579,158 @@ pub fn create_token_internal(
 pub fn create_token(
     env: &Env,
     creator: Address,
-    name: String,
-    symbol: String,
-    decimals: u32,
-    initial_supply: i128,
-    metadata_uri: Option<String>,
+    params: TokenCreationParams,
+    fee_payment: i128,
+    token_kind: TokenKind,
+    waiver_code: Option<Bytes>,
+) -> Result<Address, Error> {
+    create_paid_token(
+        env,
+        creator,
+        params,
+        fee_payment,
+        token_kind,
+        waiver_code,
+        None,
+    )
+}
+
+/// Ledgers an index reservation stays valid (about one day)
+pub const INDEX_RESERVATION_LEDGERS: u32 = 17_280;
+
+/// Reserve the next token index for `creator`
+///
+/// The index is allocated immediately, so tokens created meanwhile take
+/// later indices. An index whose reservation lapses is never reused and
+/// stays empty.
+pub fn reserve_index(env: &Env, creator: &Address) -> Result<u32, Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    ensure_creation_enabled(env)?;
+
+    creator.require_auth();
+
+    let index = storage::increment_token_count(env)? - 1;
+    let expires_at = env
+        .ledger()
+        .sequence()
+        .saturating_add(INDEX_RESERVATION_LEDGERS);
+    storage::set_index_reservation(
+        env,
+        index,
+        &IndexReservation {
+            creator: creator.clone(),
+            expires_at,
+        },
+    );
+
+    crate::events::emit_index_reserved(env, index, creator, expires_at);
+
+    Ok(index)
+}
+
+/// Create a token into an index reserved with `reserve_index`
+///
+/// # Returns
+/// * `Ok(Address)` - The created token address
+/// * `Err(Error::IndexNotReserved)` - No live reservation on the index
+/// * `Err(Error::Unauthorized)` - The index is reserved for someone else
+/// * `Err(Error::ReservationExpired)` - The reservation lapsed
+/// * Any error `create_token` returns
+pub fn create_token_at(
+    env: &Env,
+    creator: Address,
+    reserved_index: u32,
+    params: TokenCreationParams,
+    fee_payment: i128,
+) -> Result<Address, Error> {
+    // `create_paid_token` requires the creator's authorization
+    let reservation =
+        storage::get_index_reservation(env, reserved_index).ok_or(Error::IndexNotReserved)?;
+    if reservation.creator != creator {
+        return Err(Error::Unauthorized);
+    }
+    if env.ledger().sequence() >= reservation.expires_at {
+        return Err(Error::ReservationExpired);
+    }
+    storage::remove_index_reservation(env, reserved_index);
+
+    create_paid_token(
+        env,
+        creator,
+        params,
+        fee_payment,
+        TokenKind::Fungible,
+        None,
+        Some(reserved_index),
+    )
+}
+
+/// Create a token after checking and taking its fee
+///
+/// Uses `reserved_index` when given, otherwise the next free index.
+fn create_paid_token(
+    env: &Env,
+    creator: Address,
+    params: TokenCreationParams,
     fee_payment: i128,
+    token_kind: TokenKind,
+    waiver_code: Option<Bytes>,
+    reserved_index: Option<u32>,
 ) -> Result<Address, Error> {
     // Check if paused
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
+    ensure_creation_enabled(env)?;
 
     // Require creator authorization
     creator.require_auth();
+    ensure_creator_balance(env, &creator)?;
 
-    // Calculate and verify fee
-    let required_fee = calculate_creation_fee(env, metadata_uri.is_some());
-    if fee_payment < required_fee {
-        return Err(Error::InsufficientFee);
-    }
+    validate_creation_spec(env, &params, token_kind)?;
 
-    // Get next token index
-    let token_index = storage::increment_token_count(env)? - 1;
+    // Calculate, verify and take the fee; exemptions and waivers make
+    // creation free
+    let with_metadata = params.metadata_uri.is_some();
+    let (base, metadata) = creation_fee_due(env, &creator, &params, waiver_code.as_ref())?;
+    crate::fees::validate_and_collect(env, &creator, base, metadata, with_metadata, fee_payment)?;
 
-    // Create token parameters
-    let params = TokenCreationParams {
-        name,
-        symbol,
-        decimals,
-        initial_supply,
-        max_supply: None,
-        metadata_uri,
+    // Get next token index unless one was reserved
+    let token_index = match reserved_index {
+        Some(index) => index,
+        None => storage::increment_token_count(env)? - 1,
     };
 
     // Create token
-    let token_address = create_token_internal(env, &creator, &params, token_index)?;
+    create_token_internal(env, &creator, &params, token_index, token_kind)
+}
+
+/// Create a token and renounce every creator power over it
+///
+/// Minting is finalized, metadata is frozen and clawback stays disabled,
+/// all before the transaction ends, so the token is never held by an
+/// active creator.
+pub fn create_token_renounced(
+    env: &Env,
+    creator: Address,
+    params: TokenCreationParams,
+    fee_payment: i128,
+) -> Result<Address, Error> {
+    let token_address = create_token(
+        env,
+        creator.clone(),
+        params,
+        fee_payment,
+        TokenKind::Fungible,
+        None,
+    )?;
 
-    // Transfer fee to treasury (placeholder - in production would use actual token transfer)
-    // let treasury = storage::get_treasury(env);
-    // token::transfer(env, &creator, &treasury, fee_payment);
+    storage::set_minting_finalized(env, &token_address);
+    storage::set_metadata_frozen(env, &token_address);
+    storage::set_token_renounced(env, &token_address);
+    crate::events::emit_creator_renounced(env, &token_address, &creator);
 
     Ok(token_address)
 }
@@ -170,12 +754,15 @@ pub fn create_token(
 /// * `creator` - Address creating the tokens (must authorize)
 /// * `tokens` - Vector of token creation parameters
 /// * `total_fee_payment` - Total fee payment for all tokens
+/// * `waiver_code` - Optional promo code, redeemed once per token while it
+///   has uses left; each use waives that token's fee
 /// 
 /// # Returns
 /// Vector of created token addresses
 /// 
 /// # Errors
 /// * `ContractPaused` - Contract is paused
+/// * `CreationDisabled` - Creation was shut down
 /// * `InsufficientFee` - Total fee payment is insufficient
 /// * `InvalidTokenParams` - Any token has invalid parameters
 /// * `BatchCreationFailed` - Batch creation failed (atomic rollback)
@@ -184,14 +771,17 @@ pub fn batch_create_tokens(
     creator: Address,
     tokens: Vec<TokenCreationParams>,
     total_fee_payment: i128,
+    waiver_code: Option<Bytes>,
 ) -> Result<Vec<Address>, Error> {
     // Check if paused
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
+    ensure_creation_enabled(env)?;
 
     // Require creator authorization
     creator.require_auth();
+    ensure_creator_balance(env, &creator)?;
 
     // Validate batch is not empty
     if tokens.is_empty() {
@@ -199,27 +789,34 @@ pub fn batch_create_tokens(
     }
 
     // Phase 1: Validate all tokens before any state changes (atomic semantics)
-    let mut total_required_fee = 0i128;
+    ensure_create_rate(env, tokens.len())?;
+    let mut total_base_fee = 0i128;
+    let mut total_metadata_fee = 0i128;
     for token in tokens.iter() {
         // Validate each token's parameters
-        validate_token_params(
-            &token.name,
-            &token.symbol,
-            token.decimals,
-            token.initial_supply,
-        )?;
+        validate_creation_spec(env, &token, TokenKind::Fungible)?;
 
         // Calculate fee for this token
-        let token_fee = calculate_creation_fee(env, token.metadata_uri.is_some());
-        total_required_fee = total_required_fee
-            .checked_add(token_fee)
+        let (base, metadata) = creation_fee_due(env, &creator, &token, waiver_code.as_ref())?;
+        total_base_fee = total_base_fee
+            .checked_add(base)
             .ok_or(Error::InvalidTokenParams)?;
+        if token.metadata_uri.is_some() {
+            total_metadata_fee = total_metadata_fee
+                .checked_add(metadata)
+                .ok_or(Error::InvalidTokenParams)?;
+        }
     }
 
-    // Verify total fee payment
-    if total_fee_payment < total_required_fee {
-        return Err(Error::InsufficientFee);
-    }
+    // Verify and take the total fee
+    crate::fees::validate_and_collect(
+        env,
+        &creator,
+        total_base_fee,
+        total_metadata_fee,
+        true,
+        total_fee_payment,
+    )?;
 
     // Phase 2: Create all tokens (all validations passed)
     let mut created_addresses = Vec::new(env);
@@ -229,7 +826,8 @@ pub fn batch_create_tokens(
         let token_index = starting_token_count + (i as u32);
         
         // Create token
-        let token_address = create_token_internal(env, &creator, &token, token_index)
+        let token_address =
+            create_token_internal(env, &creator, &token, token_index, TokenKind::Fungible)
             .map_err(|_| Error::BatchCreationFailed)?;
         
         created_addresses.push_back(token_address);
@@ -242,37 +840,165 @@ pub fn batch_create_tokens(
     // Emit batch creation event
     crate::events::emit_batch_tokens_created(env, &creator, tokens.len() as u32);
 
-    // Transfer total fee to treasury (placeholder)
-    // let treasury = storage::get_treasury(env);
-    // token::transfer(env, &creator, &treasury, total_fee_payment);
-
     Ok(created_addresses)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::{symbol_short, testutils::{Address as _, Events}, Env, Val};
+/// Archive a token (creator only)
+///
+/// Archiving only hides the token from `list_tokens` and the default
+/// token count. Its index is never reused, so indices stay stable.
+///
+/// # Returns
+/// * `Ok(())` - Token archived
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::TokenArchived)` - Token was already archived
+pub fn archive_token(env: &Env, token_address: &Address, creator: &Address) -> Result<(), Error> {
+    creator.require_auth();
 
-    fn setup_test_env() -> (Env, Address, Address) {
-        let env = Env::default();
-        env.mock_all_auths();
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
 
-        let admin = Address::generate(&env);
-        let treasury = Address::generate(&env);
+    let info = storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
+    if info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    let index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
 
-        // Register contract and initialize storage
-        let contract_id = env.register_contract(None, crate::TokenFactory);
-        env.as_contract(&contract_id, || {
-            storage::set_admin(&env, &admin);
-            storage::set_treasury(&env, &treasury);
-            storage::set_base_fee(&env, 100);
-            storage::set_metadata_fee(&env, 50);
-        });
+    if storage::is_token_archived(env, index) {
+        return Err(Error::TokenArchived);
+    }
+
+    storage::set_token_archived(env, index);
+    crate::events::emit_token_archived(env, token_address, creator, index);
+
+    Ok(())
+}
+
+/// Close a fully drained token, freeing its per-token storage (admin only)
+///
+/// # Returns
+/// * `Ok(())` - Token closed and archived
+/// * `Err(Error::Unauthorized)` - Caller is not the admin
+/// * `Err(Error::TokenNotFound)` - Token address not registered (or already closed)
+/// * `Err(Error::SupplyNotZero)` - Token still has circulating supply
+pub fn close_token(env: &Env, admin: &Address, token_address: &Address) -> Result<(), Error> {
+    admin.require_auth();
+
+    if *admin != storage::get_admin(env) {
+        return Err(Error::Unauthorized);
+    }
+
+    let index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let info = storage::get_token_info(env, index).ok_or(Error::TokenNotFound)?;
+    if info.total_supply != 0 {
+        return Err(Error::SupplyNotZero);
+    }
+
+    storage::remove_token_records(env, index, token_address);
+    if !storage::is_token_archived(env, index) {
+        storage::set_token_archived(env, index);
+    }
+    crate::events::emit_token_closed(env, token_address, admin, index);
+
+    Ok(())
+}
+
+/// Mute or unmute routine events of a token (creator only)
+///
+/// A muted token emits no transfer or holder burn events; admin and
+/// security events (clawbacks, pauses, this toggle itself) still fire.
+///
+/// # Returns
+/// * `Ok(())` - Muting updated
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::TokenRenounced)` - Creator renounced control of the token
+pub fn set_token_events_muted(
+    env: &Env,
+    token_address: &Address,
+    creator: &Address,
+    muted: bool,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let mut info = storage::get_token_info(env, index).ok_or(Error::TokenNotFound)?;
+    if info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    if storage::is_token_renounced(env, token_address) {
+        return Err(Error::TokenRenounced);
+    }
+
+    info.events_muted = muted;
+    storage::set_token_info(env, index, &info);
+    storage::set_token_info_by_address(env, token_address, &info);
+    crate::events::emit_token_events_muted(env, token_address, creator, muted);
+
+    Ok(())
+}
+
+/// Gate or ungate who may receive a token (creator only)
+///
+/// While gated, transfers and mints of the token only go to holders of a
+/// nonzero balance of `gate_token`. `None` removes the gate.
+///
+/// # Returns
+/// * `Ok(())` - Gate updated
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token or gate token not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::TokenRenounced)` - Creator renounced control of the token
+/// * `Err(Error::InvalidParameters)` - The token would gate itself
+pub fn set_token_gate(
+    env: &Env,
+    token_address: &Address,
+    creator: &Address,
+    gate_token: Option<Address>,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
 
-        (env, admin, treasury)
+    let index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let mut info = storage::get_token_info(env, index).ok_or(Error::TokenNotFound)?;
+    if info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    if storage::is_token_renounced(env, token_address) {
+        return Err(Error::TokenRenounced);
+    }
+    if let Some(gate) = &gate_token {
+        if gate == token_address {
+            return Err(Error::InvalidParameters);
+        }
+        storage::get_token_index(env, gate).ok_or(Error::TokenNotFound)?;
     }
 
+    info.gate_token = gate_token.clone();
+    storage::set_token_info(env, index, &info);
+    storage::set_token_info_by_address(env, token_address, &info);
+    crate::events::emit_token_gate_updated(env, token_address, creator, &gate_token);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+    use soroban_sdk::{symbol_short, testutils::{Address as _, Events}, Env, Symbol, TryFromVal};
+
     #[test]
     fn test_validate_token_params_success() {
         let env = Env::default();
@@ -357,87 +1083,65 @@ mod tests {
         assert_eq!(fee, 150);
     }
 
-    /* TODO: Fix batch tests - need proper contract client setup
     #[test]
     fn test_batch_create_emits_exact_sequence_in_input_order() {
-        let (env, admin, _treasury) = setup_test_env();
-        let before = env.events().all().len();
-
-        let token_a = TokenCreationParams {
-            name: String::from_str(&env, "Alpha"),
-            symbol: String::from_str(&env, "ALP"),
-            decimals: 7,
-            initial_supply: 1_000_000,
-            max_supply: None,
-            metadata_uri: None,
-        };
-        let token_b = TokenCreationParams {
-            name: String::from_str(&env, "Beta"),
-            symbol: String::from_str(&env, "BET"),
-            decimals: 7,
-            initial_supply: 2_000_000,
-            max_supply: None,
-            metadata_uri: None,
-        };
+        let f = FactoryEnv::new();
+        let creator = Address::generate(&f.env);
+        let batch = soroban_sdk::vec![
+            &f.env,
+            token_params(&f.env, "ALP"),
+            TokenCreationParams {
+                initial_supply: 2_000_000,
+                ..token_params(&f.env, "BET")
+            },
+        ];
 
-        let batch = soroban_sdk::vec![&env, token_a, token_b];
-        let fee = env.as_contract(&env.current_contract_address(), || {
-            2 * calculate_creation_fee(&env, false)
-        });
-        let created = env.as_contract(&env.current_contract_address(), || {
-            batch_create_tokens(&env, admin, batch, fee)
-        }).unwrap();
+        let created = f
+            .client
+            .batch_create_tokens(&creator, &batch, &(2 * BASE_FEE), &None);
         assert_eq!(created.len(), 2);
 
-        let all = env.events().all();
-        let delta = all.slice(before as u32..);
-        assert_eq!(delta.len(), 3, "expected 2 create events + 1 batch summary");
-
-        // Verify events were emitted (simplified check without Val conversion issues)
-        assert!(delta.len() >= 3);
+        // Fee events may interleave; the creation events keep their order
+        let mut created_events = soroban_sdk::Vec::new(&f.env);
+        for (_, topics, _) in f.env.events().all().iter() {
+            let name = Symbol::try_from_val(&f.env, &topics.get(0).unwrap()).unwrap();
+            if name == symbol_short!("tok_crt") {
+                let token = Address::try_from_val(&f.env, &topics.get(1).unwrap()).unwrap();
+                created_events.push_back((name, Some(token)));
+            } else if name == symbol_short!("bch_tkn") {
+                created_events.push_back((name, None));
+            }
+        }
+        assert_eq!(
+            created_events,
+            soroban_sdk::vec![
+                &f.env,
+                (symbol_short!("tok_crt"), created.get(0)),
+                (symbol_short!("tok_crt"), created.get(1)),
+                (symbol_short!("bch_tkn"), None),
+            ]
+        );
     }
-    */
 
-    /* TODO: Fix batch rollback test - need proper contract client setup
     #[test]
     fn test_batch_create_rollback_emits_no_partial_success_events() {
-        let (env, admin, _treasury) = setup_test_env();
-        let before = env.events().all().len();
-        let token_count_before = env.as_contract(&env.current_contract_address(), || {
-            storage::get_token_count(&env)
-        });
-
-        let valid = TokenCreationParams {
-            name: String::from_str(&env, "Valid"),
-            symbol: String::from_str(&env, "VLD"),
-            decimals: 7,
-            initial_supply: 1_000_000,
-            max_supply: None,
-            metadata_uri: None,
-        };
+        let f = FactoryEnv::new();
+        let creator = Address::generate(&f.env);
         let invalid = TokenCreationParams {
-            name: String::from_str(&env, ""), // invalid -> forces rollback path
-            symbol: String::from_str(&env, "BAD"),
-            decimals: 7,
-            initial_supply: 1_000_000,
-            max_supply: None,
-            metadata_uri: None,
+            name: String::from_str(&f.env, ""),
+            ..token_params(&f.env, "BAD")
         };
+        let batch = soroban_sdk::vec![&f.env, token_params(&f.env, "VLD"), invalid];
 
-        let batch = soroban_sdk::vec![&env, valid, invalid];
-        let fee = env.as_contract(&env.current_contract_address(), || {
-            2 * calculate_creation_fee(&env, false)
-        });
-        let err = env.as_contract(&env.current_contract_address(), || {
-            batch_create_tokens(&env, admin, batch, fee)
-        }).unwrap_err();
-        assert_eq!(err, Error::InvalidTokenParams);
+        let result = f
+            .client
+            .try_batch_create_tokens(&creator, &batch, &(2 * BASE_FEE), &None);
 
-        let token_count_after = env.as_contract(&env.current_contract_address(), || {
-            storage::get_token_count(&env)
-        });
-        assert_eq!(token_count_after, token_count_before);
-        assert_eq!(env.events().all().len(), before, "no partial success event leakage allowed");
+        assert_eq!(result, Err(Ok(Error::InvalidTokenParams)));
+        assert_eq!(f.client.get_token_count(&true), 0);
+        assert!(
+            f.env.events().all().is_empty(),
+            "no partial success event leakage allowed"
+        );
     }
-    */
 }
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    (env, contract_id, client)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, creator: &Address) -> Address {
+    let params = token_params(env, "CRTR");
+    client
+        .batch_create_tokens(creator, &vec![env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap()
+}
+
+#[test]
+fn test_get_token_creator_matches_token_info() {
+    let (env, _contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let token = create(&env, &client, &creator);
+
+    assert_eq!(client.get_token_creator(&token), creator);
+    assert_eq!(
+        client.get_token_creator(&token),
+        client.get_token_info_by_address(&token).creator
+    );
+}
+
+#[test]
+fn test_is_token_creator() {
+    let (env, _contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let token = create(&env, &client, &creator);
+
+    assert!(client.is_token_creator(&token, &creator));
+    assert!(!client.is_token_creator(&token, &Address::generate(&env)));
+}
+
+#[test]
+fn test_unknown_token() {
+    let (env, _contract_id, client) = setup();
+    let token = Address::generate(&env);
+
+    assert_eq!(
+        client.try_get_token_creator(&token),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert!(!client.is_token_creator(&token, &Address::generate(&env)));
+}
+
+#[test]
+fn test_falls_back_to_token_record() {
+    let (env, contract_id, client) = setup();
+    let creator = Address::generate(&env);
+    let token = create(&env, &client, &creator);
+
+    // Tokens registered before the creator entry existed only have the record
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .remove(&types::TokenKey::TokenCreator(token.clone()))
+    });
+
+    assert_eq!(client.get_token_creator(&token), creator);
+    assert!(client.is_token_creator(&token, &creator));
+}
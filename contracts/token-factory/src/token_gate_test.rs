@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    token: Address,
+    gate: Address,
+}
+
+fn params(env: &Env, symbol: &str) -> TokenCreationParams {
+    TokenCreationParams {
+        name: String::from_str(env, symbol),
+        ..token_params(env, symbol)
+    }
+}
+
+/// A token (index 0) gated on a membership token (index 1)
+fn setup() -> Setup {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    let tokens = client.batch_create_tokens(
+        &creator,
+        &vec![&env, params(&env, "MAIN"), params(&env, "PASS")],
+        &(BASE_FEE * 2),
+        &None,
+    );
+    let token = tokens.get(0).unwrap();
+    let gate = tokens.get(1).unwrap();
+    client.set_token_gate(&token, &creator, &Some(gate.clone()));
+
+    Setup {
+        env,
+        client,
+        creator,
+        token,
+        gate,
+    }
+}
+
+#[test]
+fn test_transfer_requires_gate_token() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let memo = Bytes::new(&s.env);
+
+    assert_eq!(
+        s.client
+            .try_transfer_with_memo(&s.token, &s.creator, &recipient, &100, &memo),
+        Err(Ok(Error::GateTokenRequired))
+    );
+
+    // Granting the membership token opens the gate
+    s.client
+        .transfer_with_memo(&s.gate, &s.creator, &recipient, &1, &memo);
+    s.client
+        .transfer_with_memo(&s.token, &s.creator, &recipient, &100, &memo);
+    assert_eq!(s.client.get_token_info(&0).gate_token, Some(s.gate));
+}
+
+#[test]
+fn test_mint_and_batch_transfer_require_gate_token() {
+    let s = setup();
+    let member = Address::generate(&s.env);
+    let outsider = Address::generate(&s.env);
+    let memo = Bytes::new(&s.env);
+    s.client
+        .transfer_with_memo(&s.gate, &s.creator, &member, &1, &memo);
+
+    assert_eq!(
+        s.client.try_mint(&s.creator, &0, &outsider, &100),
+        Err(Ok(Error::GateTokenRequired))
+    );
+    assert_eq!(
+        s.client.try_batch_transfer(
+            &s.token,
+            &s.creator,
+            &vec![
+                &s.env,
+                (member.clone(), 10_i128),
+                (outsider.clone(), 10_i128)
+            ],
+        ),
+        Err(Ok(Error::GateTokenRequired))
+    );
+
+    s.client.mint(&s.creator, &0, &member, &100);
+}
+
+#[test]
+fn test_removing_gate_admits_everyone() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    s.client.set_token_gate(&s.token, &s.creator, &None);
+    s.client
+        .transfer_with_memo(&s.token, &s.creator, &recipient, &100, &Bytes::new(&s.env));
+}
+
+#[test]
+fn test_set_gate_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_set_token_gate(&s.token, &Address::generate(&s.env), &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_set_token_gate(&s.token, &s.creator, &Some(s.token.clone())),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_set_token_gate(&s.token, &s.creator, &Some(Address::generate(&s.env))),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_setting_gate_keeps_burn_accounting() {
+    let s = setup();
+    s.client.burn(&s.creator, &0, &400_000);
+
+    s.client.set_token_gate(&s.token, &s.creator, &None);
+
+    let info = s.client.get_token_info(&0);
+    assert_eq!(info.gate_token, None);
+    assert_eq!(info.total_supply, 600_000);
+    assert_eq!(info.total_burned, 400_000);
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, symbol: &str) -> Address {
+    let creator = Address::generate(env);
+    let params = token_params(env, symbol);
+    client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None)
+}
+
+#[test]
+fn test_results_align_with_requested_addresses() {
+    let (env, client) = setup();
+    let first = create(&env, &client, "ONE");
+    let second = create(&env, &client, "TWO");
+    let unknown_a = Address::generate(&env);
+    let unknown_b = Address::generate(&env);
+
+    let infos = client.get_token_infos_by_address(&vec![
+        &env,
+        unknown_a,
+        second.clone(),
+        unknown_b,
+        first.clone(),
+    ]);
+
+    assert_eq!(infos.len(), 4);
+    assert_eq!(infos.get(0).unwrap(), None);
+    assert_eq!(
+        infos.get(1).unwrap().unwrap().symbol,
+        String::from_str(&env, "TWO")
+    );
+    assert_eq!(infos.get(2).unwrap(), None);
+    let info = infos.get(3).unwrap().unwrap();
+    assert_eq!(info.address, first);
+    assert_eq!(info.symbol, String::from_str(&env, "ONE"));
+}
+
+#[test]
+fn test_empty_request_returns_empty() {
+    let (env, client) = setup();
+    assert_eq!(client.get_token_infos_by_address(&Vec::new(&env)).len(), 0);
+}
+
+#[test]
+fn test_oversized_batch_rejected() {
+    let (env, client) = setup();
+    let mut addresses = Vec::new(&env);
+    for _ in 0..51 {
+        addresses.push_back(Address::generate(&env));
+    }
+
+    assert_eq!(
+        client.try_get_token_infos_by_address(&addresses),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
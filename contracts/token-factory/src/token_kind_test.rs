@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+fn spec(env: &Env, decimals: u32, initial_supply: i128) -> TokenCreationParams {
+    TokenCreationParams {
+        decimals,
+        initial_supply,
+        ..token_params(env, "KIND")
+    }
+}
+
+#[test]
+fn test_create_fungible_token() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    let token = client.create_token(
+        &creator,
+        &spec(&env, 7, 1_000_000),
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    );
+
+    assert_eq!(client.get_token_kind(&token), TokenKind::Fungible);
+    assert_eq!(
+        client.get_token_info_by_address(&token).total_supply,
+        1_000_000
+    );
+}
+
+#[test]
+fn test_create_non_fungible_token() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    let token = client.create_token(
+        &creator,
+        &spec(&env, 0, 1),
+        &BASE_FEE,
+        &TokenKind::NonFungible,
+        &None,
+    );
+
+    assert_eq!(client.get_token_kind(&token), TokenKind::NonFungible);
+    let info = client.get_token_info_by_address(&token);
+    assert_eq!(info.decimals, 0);
+    assert_eq!(info.total_supply, 1);
+}
+
+#[test]
+fn test_non_fungible_rejects_decimals() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    let result = client.try_create_token(
+        &creator,
+        &spec(&env, 7, 1),
+        &BASE_FEE,
+        &TokenKind::NonFungible,
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidTokenParams)));
+    assert_eq!(client.try_get_token_info(&0), Err(Ok(Error::TokenNotFound)));
+}
+
+#[test]
+fn test_non_fungible_rejects_supply_above_one() {
+    let (env, client) = setup();
+    let creator = Address::generate(&env);
+
+    let result = client.try_create_token(
+        &creator,
+        &spec(&env, 0, 2),
+        &BASE_FEE,
+        &TokenKind::NonFungible,
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidTokenParams)));
+}
+
+#[test]
+fn test_get_token_kind_unknown_token() {
+    let (env, client) = setup();
+
+    let result = client.try_get_token_kind(&Address::generate(&env));
+
+    assert_eq!(result, Err(Ok(Error::TokenNotFound)));
+}
@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    (env, client, contract_id, creator)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, creator: &Address, symbol: &str) -> Address {
+    let spec = token_params(env, symbol);
+    client.create_token(creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None)
+}
+
+#[test]
+fn test_valid_index_returns_info() {
+    let (env, client, _, creator) = setup();
+    let token = create(&env, &client, &creator, "LIVE");
+
+    let info = client.get_token_info_detailed(&0);
+    assert_eq!(info.address, token);
+    assert_eq!(info, client.get_token_info(&0));
+}
+
+#[test]
+fn test_out_of_range_index() {
+    let (env, client, _, creator) = setup();
+    create(&env, &client, &creator, "LIVE");
+
+    assert_eq!(
+        client.try_get_token_info_detailed(&1),
+        Err(Ok(Error::TokenIndexOutOfRange))
+    );
+    assert_eq!(
+        client.try_get_token_info_detailed(&u32::MAX),
+        Err(Ok(Error::TokenIndexOutOfRange))
+    );
+}
+
+#[test]
+fn test_archived_token() {
+    let (env, client, _, creator) = setup();
+    let token = create(&env, &client, &creator, "GONE");
+    client.archive_token(&token, &creator);
+
+    assert_eq!(
+        client.try_get_token_info_detailed(&0),
+        Err(Ok(Error::TokenArchived))
+    );
+    // The plain lookup still serves archived tokens
+    assert_eq!(client.get_token_info(&0).address, token);
+}
+
+#[test]
+fn test_reserved_index_not_yet_created() {
+    let (env, client, contract_id, _) = setup();
+    env.as_contract(&contract_id, || {
+        storage::increment_token_count(&env).unwrap();
+    });
+
+    assert_eq!(
+        client.try_get_token_info_detailed(&0),
+        Err(Ok(Error::TokenNotYetCreated))
+    );
+}
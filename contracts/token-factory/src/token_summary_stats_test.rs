@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Bytes, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+#[test]
+fn test_summary_matches_individual_getters() {
+    let (env, client) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        max_supply: Some(2_000_000),
+        ..token_params(&env, "SUMM")
+    };
+    let token = client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+    let index = client.get_next_token_index() - 1;
+
+    let holder = Address::generate(&env);
+    client.transfer_with_memo(&token, &creator, &holder, &10_000, &Bytes::new(&env));
+    client.burn(&holder, &index, &2_500);
+    client.burn(&creator, &index, &500);
+    env.ledger().with_mut(|l| l.timestamp = 1_600);
+
+    let summary = client.get_token_summary_stats(&index);
+    let info = client.get_token_info(&index);
+    let stats = client.get_token_stats(&index);
+
+    assert_eq!(summary.address, token);
+    assert_eq!(summary.address, info.address);
+    assert_eq!(summary.creator, client.get_token_creator(&token));
+    assert_eq!(summary.current_supply, stats.current_supply);
+    assert_eq!(summary.current_supply, 997_000);
+    assert_eq!(summary.max_supply, info.max_supply);
+    assert_eq!(summary.total_burned, stats.total_burned);
+    assert_eq!(summary.total_burned, 3_000);
+    assert_eq!(summary.burn_count, client.get_burn_count(&index));
+    assert_eq!(summary.burn_count, 2);
+    assert_eq!(summary.holder_count, client.get_holder_count(&index));
+    assert_eq!(summary.holder_count, 2);
+    assert_eq!(summary.created_at, info.created_at);
+    assert_eq!(summary.created_at_source, info.created_at_source);
+    assert_eq!(summary.age, 600);
+}
+
+#[test]
+fn test_unknown_token_rejected() {
+    let (_env, client) = setup();
+    assert_eq!(
+        client.try_get_token_summary_stats(&0),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
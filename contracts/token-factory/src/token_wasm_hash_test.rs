@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, admin, client)
+}
+
+fn create_token(env: &Env, client: &TokenFactoryClient, creator: &Address) -> Address {
+    let params = token_params(env, "PROV");
+    client
+        .batch_create_tokens(creator, &vec![env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap()
+}
+
+#[test]
+fn test_token_records_factory_wasm_hash() {
+    let (env, admin, client) = setup();
+    let creator = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+
+    client.set_token_wasm_hash(&admin, &hash);
+    let token = create_token(&env, &client, &creator);
+
+    assert_eq!(client.get_token_wasm_hash(&token), hash);
+}
+
+#[test]
+fn test_existing_tokens_keep_recorded_hash() {
+    let (env, admin, client) = setup();
+    let creator = Address::generate(&env);
+    let first_hash = BytesN::from_array(&env, &[1; 32]);
+    let second_hash = BytesN::from_array(&env, &[2; 32]);
+
+    client.set_token_wasm_hash(&admin, &first_hash);
+    let first = create_token(&env, &client, &creator);
+
+    client.set_token_wasm_hash(&admin, &second_hash);
+    let second = create_token(&env, &client, &creator);
+
+    assert_ne!(first, second);
+    assert_eq!(client.get_token_wasm_hash(&first), first_hash);
+    assert_eq!(client.get_token_wasm_hash(&second), second_hash);
+}
+
+#[test]
+fn test_set_token_wasm_hash_rejects_non_admin() {
+    let (env, _admin, client) = setup();
+    let attacker = Address::generate(&env);
+
+    let result = client.try_set_token_wasm_hash(&attacker, &BytesN::from_array(&env, &[9; 32]));
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_get_token_wasm_hash_unknown_token() {
+    let (env, _admin, client) = setup();
+
+    let result = client.try_get_token_wasm_hash(&Address::generate(&env));
+
+    assert_eq!(result, Err(Ok(Error::TokenNotFound)));
+}
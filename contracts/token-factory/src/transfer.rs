@@ -0,0 +1,233 @@
+//! Transfers of factory-managed balances
+//!
+//! A memo can be attached to a transfer for payment reconciliation. The
+//! memo is only published in the transfer event; it is never stored.
+//!
+//! `batch_transfer` pays many recipients from one sender. Every entry is
+//! checked before any balance moves, so a batch either completes in full
+//! or changes nothing. A batch pays the transfer operation fee once.
+
+use crate::events;
+use crate::storage;
+use crate::types::{Error, OpKind};
+use soroban_sdk::{Address, Bytes, Env, Vec};
+
+/// Maximum memo length in bytes
+pub const MAX_MEMO_LEN: u32 = 64;
+
+/// Maximum number of recipients in one `batch_transfer`
+pub const MAX_BATCH_TRANSFER: u32 = 100;
+
+/// Move `amount` from `from` to `to`, publishing `memo` with the event
+///
+/// # Returns
+/// * `Ok(())` - Transfer completed
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::InvalidParameters)` - Memo is too long, `from == to`, or
+///   either side is the factory contract itself
+/// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::BelowMinTransfer)` - Amount is below the token's minimum
+/// * `Err(Error::AddressFrozen)` - Sender or recipient is frozen
+/// * `Err(Error::GateTokenRequired)` - Recipient holds none of the gate token
+/// * `Err(Error::InsufficientBalance)` - Sender balance is less than amount
+/// * `Err(Error::BelowMinBalance)` - Sender or recipient would be left with
+///   a nonzero balance below the token minimum
+/// * `Err(Error::HolderLimitReached)` - Recipient would exceed the holder cap
+pub fn transfer_with_memo(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: &Bytes,
+) -> Result<(), Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    from.require_auth();
+
+    if memo.len() > MAX_MEMO_LEN || from == to {
+        return Err(Error::InvalidParameters);
+    }
+    crate::burn::validate_address(env, from)?;
+    crate::burn::validate_address(env, to)?;
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let token_index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    crate::token_creation::ensure_token_unlocked(env, token_address)?;
+    if info.min_transfer.is_some_and(|min| amount < min) {
+        return Err(Error::BelowMinTransfer);
+    }
+    if storage::is_address_frozen(env, token_address, from)
+        || storage::is_address_frozen(env, token_address, to)
+    {
+        return Err(Error::AddressFrozen);
+    }
+    crate::token_creation::ensure_gate_holder(env, &info, to)?;
+
+    let from_balance = storage::get_balance(env, token_index, from);
+    if from_balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    // Moving a whole balance to a new holder keeps the holder count
+    let to_balance = storage::get_balance(env, token_index, to);
+    if to_balance == 0 && from_balance > amount {
+        crate::mint::ensure_holder_capacity(env, &info, token_index, 1)?;
+    }
+    let to_balance = to_balance
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    crate::token_creation::ensure_min_balance(&info, from_balance - amount)?;
+    crate::token_creation::ensure_min_balance(&info, to_balance)?;
+
+    storage::set_balance(env, token_index, from, from_balance - amount);
+    storage::set_balance(env, token_index, to, to_balance);
+    crate::fees::charge_op_fee(env, from, OpKind::Transfer)?;
+
+    if !info.events_muted {
+        events::emit_transfer_with_memo(env, token_address, from, to, amount, memo);
+    }
+    Ok(())
+}
+
+/// Pay every `(recipient, amount)` in `transfers` from `from`
+///
+/// # Returns
+/// * `Ok(i128)` - Total amount transferred
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::BatchTooLarge)` - More than `MAX_BATCH_TRANSFER` entries
+/// * `Err(Error::InvalidParameters)` - Empty batch, a recipient is `from`,
+///   or either side is the factory contract itself
+/// * `Err(Error::InvalidAmount)` - An amount is zero or negative
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::BelowMinTransfer)` - An amount is below the token's minimum
+/// * `Err(Error::AddressFrozen)` - Sender or a recipient is frozen
+/// * `Err(Error::GateTokenRequired)` - A recipient holds none of the gate token
+/// * `Err(Error::InsufficientBalance)` - Sender balance is less than the total
+/// * `Err(Error::BelowMinBalance)` - Sender or a recipient would be left with
+///   a nonzero balance below the token minimum
+/// * `Err(Error::HolderLimitReached)` - New recipients would exceed the holder cap
+/// * `Err(Error::ArithmeticError)` - The total overflows
+pub fn batch_transfer(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    transfers: &Vec<(Address, i128)>,
+) -> Result<i128, Error> {
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    from.require_auth();
+
+    if transfers.len() > MAX_BATCH_TRANSFER {
+        return Err(Error::BatchTooLarge);
+    }
+    if transfers.is_empty() {
+        return Err(Error::InvalidParameters);
+    }
+    crate::burn::validate_address(env, from)?;
+
+    let token_index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    crate::token_creation::ensure_token_unlocked(env, token_address)?;
+    if storage::is_address_frozen(env, token_address, from) {
+        return Err(Error::AddressFrozen);
+    }
+
+    // Validate every entry before moving anything
+    let mut total: i128 = 0;
+    let mut new_holders: Vec<Address> = Vec::new(env);
+    for (to, amount) in transfers.iter() {
+        if to == *from {
+            return Err(Error::InvalidParameters);
+        }
+        crate::burn::validate_address(env, &to)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if info.min_transfer.is_some_and(|min| amount < min) {
+            return Err(Error::BelowMinTransfer);
+        }
+        if storage::is_address_frozen(env, token_address, &to) {
+            return Err(Error::AddressFrozen);
+        }
+        crate::token_creation::ensure_gate_holder(env, &info, &to)?;
+        total = total.checked_add(amount).ok_or(Error::ArithmeticError)?;
+        if storage::get_balance(env, token_index, &to) == 0 && !new_holders.contains(&to) {
+            new_holders.push_back(to);
+        }
+    }
+
+    let from_balance = storage::get_balance(env, token_index, from);
+    if from_balance < total {
+        return Err(Error::InsufficientBalance);
+    }
+    if info.min_balance.is_some() {
+        crate::token_creation::ensure_min_balance(&info, from_balance - total)?;
+        ensure_recipient_min_balances(env, &info, token_index, transfers)?;
+    }
+
+    // A sender paying out its whole balance frees its holder slot
+    let freed = u32::from(from_balance == total);
+    crate::mint::ensure_holder_capacity(
+        env,
+        &info,
+        token_index,
+        new_holders.len().saturating_sub(freed),
+    )?;
+
+    storage::set_balance(env, token_index, from, from_balance - total);
+    for (to, amount) in transfers.iter() {
+        let balance = storage::get_balance(env, token_index, &to)
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_balance(env, token_index, &to, balance);
+    }
+    crate::fees::charge_op_fee(env, from, OpKind::Transfer)?;
+
+    if !info.events_muted {
+        events::emit_batch_transfer(env, token_address, from, transfers.len(), total);
+    }
+    Ok(total)
+}
+
+/// Check every recipient's final balance against the token minimum
+///
+/// A recipient listed more than once is checked once, against the sum of
+/// all its entries.
+fn ensure_recipient_min_balances(
+    env: &Env,
+    info: &crate::types::TokenInfo,
+    token_index: u32,
+    transfers: &Vec<(Address, i128)>,
+) -> Result<(), Error> {
+    let mut checked: Vec<Address> = Vec::new(env);
+    for (to, _) in transfers.iter() {
+        if checked.contains(&to) {
+            continue;
+        }
+        let mut balance = storage::get_balance(env, token_index, &to);
+        for (other, amount) in transfers.iter() {
+            if other == to {
+                balance = balance.checked_add(amount).ok_or(Error::ArithmeticError)?;
+            }
+        }
+        crate::token_creation::ensure_min_balance(info, balance)?;
+        checked.push_back(to);
+    }
+    Ok(())
+}
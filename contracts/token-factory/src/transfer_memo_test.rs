@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, Bytes, Env, String, TryFromVal, Val};
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    holder: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+
+    let params = token_params(&env, "MEMO");
+    let token = client
+        .batch_create_tokens(
+            &Address::generate(&env),
+            &vec![&env, params],
+            &BASE_FEE,
+            &None,
+        )
+        .get(0)
+        .unwrap();
+    let holder = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &holder, 1_000)
+    });
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        holder,
+        token,
+    }
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, 0, holder))
+}
+
+#[test]
+fn test_transfer_with_memo_moves_balance_and_emits_memo() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let memo = Bytes::from_slice(&s.env, b"invoice-2024-0042");
+
+    s.client
+        .transfer_with_memo(&s.token, &s.holder, &recipient, &400, &memo);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(balance(&s, &s.holder), 600);
+    assert_eq!(balance(&s, &recipient), 400);
+
+    let name = Symbol::try_from_val(&s.env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(name, symbol_short!("xfer_memo"));
+    assert_eq!(
+        Address::try_from_val(&s.env, &topics.get(2).unwrap()).unwrap(),
+        s.holder
+    );
+    assert_eq!(
+        Address::try_from_val(&s.env, &topics.get(3).unwrap()).unwrap(),
+        recipient
+    );
+
+    let payload = soroban_sdk::Vec::<Val>::try_from_val(&s.env, &data).unwrap();
+    assert_eq!(
+        i128::try_from_val(&s.env, &payload.get(1).unwrap()).unwrap(),
+        400
+    );
+    assert_eq!(
+        Bytes::try_from_val(&s.env, &payload.get(2).unwrap()).unwrap(),
+        memo
+    );
+}
+
+#[test]
+fn test_memo_length_limit() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+
+    let max_memo = Bytes::from_slice(&s.env, &[b'x'; 64]);
+    s.client
+        .transfer_with_memo(&s.token, &s.holder, &recipient, &1, &max_memo);
+
+    let long_memo = Bytes::from_slice(&s.env, &[b'x'; 65]);
+    assert_eq!(
+        s.client
+            .try_transfer_with_memo(&s.token, &s.holder, &recipient, &1, &long_memo),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(balance(&s, &recipient), 1);
+}
+
+#[test]
+fn test_transfer_with_memo_rejects_bad_transfers() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let memo = Bytes::new(&s.env);
+
+    assert_eq!(
+        s.client
+            .try_transfer_with_memo(&s.token, &s.holder, &recipient, &1_001, &memo),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        s.client
+            .try_transfer_with_memo(&s.token, &s.holder, &recipient, &0, &memo),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_transfer_with_memo(&s.token, &s.holder, &s.holder, &1, &memo),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.try_transfer_with_memo(
+            &Address::generate(&s.env),
+            &s.holder,
+            &recipient,
+            &1,
+            &memo
+        ),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
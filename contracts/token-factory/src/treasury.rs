@@ -150,6 +150,8 @@ pub fn record_withdrawal(env: &Env, amount: i128) -> Result<(), Error> {
 ///
 /// Transfers accumulated fees to a recipient address.
 /// Enforces withdrawal policy limits and allowlist.
+/// When a fee asset is configured, the amount is paid out of the
+/// collected fee balance.
 ///
 /// # Arguments
 /// * `env` - The contract environment
@@ -163,6 +165,7 @@ pub fn record_withdrawal(env: &Env, amount: i128) -> Result<(), Error> {
 /// * `Err(Error::WithdrawalCapExceeded)` - Exceeds daily cap
 /// * `Err(Error::RecipientNotAllowed)` - Recipient not allowed
 /// * `Err(Error::InvalidAmount)` - Invalid amount
+/// * `Err(Error::InsufficientBalance)` - Exceeds the collected fee balance
 pub fn withdraw_fees(
     env: &Env,
     admin: &Address,
@@ -180,6 +183,9 @@ pub fn withdraw_fees(
     // Validate withdrawal
     validate_withdrawal(env, recipient, amount)?;
 
+    // Pay out of the collected fee balance
+    crate::fees::withdraw(env, recipient, amount)?;
+
     // Record withdrawal
     record_withdrawal(env, amount)?;
 
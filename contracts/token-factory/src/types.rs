@@ -23,6 +23,56 @@ pub struct FactoryState {
     pub paused: bool,
 }
 
+/// Every admin-tunable factory parameter, read in one call
+///
+/// # Fields
+/// * `base_fee` - Base fee for token deployment (in stroops)
+/// * `metadata_fee` - Additional fee for metadata inclusion (in stroops)
+/// * `fee_burn_bps` - Share of each fee burned, in basis points
+/// * `rounding_mode` - Rounding applied to the burned fee share
+/// * `admin_fee_exempt` - Whether the admin deploys without paying fees
+/// * `max_uri_length` - Longest accepted metadata URI
+/// * `min_decimals` - Fewest decimals a new token may have
+/// * `max_raw_supply` - Largest raw initial supply a new token may have
+/// * `global_supply_cap` - Cap on supply across all tokens, if any
+/// * `window_create_cap` - Creations allowed per ledger window (0 = no cap)
+/// * `min_creator_balance` - Native XLM a creator must hold (0 = no minimum)
+/// * `action_quorum` - Approvals a queued admin action needs
+/// * `clawback_cooldown` - Seconds between clawbacks from one holder
+/// * `admin_burn_during_pause` - Whether `admin_burn` works while paused
+/// * `clawback_non_burnable` - Whether `admin_burn` works on non-burnable tokens
+/// * `unique_symbols` - Whether token symbols must be unique
+/// * `normalize_symbols` - Whether symbols are uppercased at creation
+/// * `allowlist_enabled` - Whether treasury withdrawals use the allowlist
+/// * `paused` - Whether the contract is paused
+/// * `pause_expiry` - Ledger at which the current pause ends, if set
+/// * `creation_disabled` - Whether creation was permanently shut down
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminConfig {
+    pub base_fee: i128,
+    pub metadata_fee: i128,
+    pub fee_burn_bps: u32,
+    pub rounding_mode: RoundingMode,
+    pub admin_fee_exempt: bool,
+    pub max_uri_length: u32,
+    pub min_decimals: u32,
+    pub max_raw_supply: i128,
+    pub global_supply_cap: Option<i128>,
+    pub window_create_cap: u32,
+    pub min_creator_balance: i128,
+    pub action_quorum: u32,
+    pub clawback_cooldown: u64,
+    pub admin_burn_during_pause: bool,
+    pub clawback_non_burnable: bool,
+    pub unique_symbols: bool,
+    pub normalize_symbols: bool,
+    pub allowlist_enabled: bool,
+    pub paused: bool,
+    pub pause_expiry: Option<u32>,
+    pub creation_disabled: bool,
+}
+
 /// Contract metadata for factory identification
 ///
 /// Contains descriptive information about the token factory contract.
@@ -63,6 +113,8 @@ pub struct ContractMetadata {
 /// * `metadata_uri` - Optional IPFS URI for additional metadata
 /// * `created_at` - Unix timestamp of token creation
 /// * `clawback_enabled` - Whether admin can burn from any address
+/// * `wasm_hash` - Token wasm hash the factory used when the token was created
+/// * `token_kind` - Whether the token is fungible or a non-fungible collectible
 ///
 /// # Examples
 /// ```
@@ -87,6 +139,28 @@ pub struct TokenInfo {
     pub is_paused: bool,
     pub clawback_enabled: bool,
     pub freeze_enabled: bool,
+    pub wasm_hash: BytesN<32>,
+    pub token_kind: TokenKind,
+    /// Decimals wallets should display, when different from `decimals`
+    pub display_decimals: Option<u32>,
+    /// Whether `created_at` is a ledger timestamp or a ledger sequence
+    pub created_at_source: TimeSource,
+    /// Most addresses that may hold a nonzero balance, if capped
+    pub max_holders: Option<u32>,
+    /// Smallest amount a single transfer may move, if limited
+    pub min_transfer: Option<i128>,
+    /// Destination of tokens taken by `admin_burn`
+    pub clawback_mode: ClawbackMode,
+    /// Number of metadata changes (URI or attributes) since creation
+    pub metadata_version: u32,
+    /// Whether holders may burn their balances
+    pub burnable: bool,
+    /// Smallest nonzero balance an account may be left with, if limited
+    pub min_balance: Option<i128>,
+    /// Whether routine transfer and burn events are suppressed
+    pub events_muted: bool,
+    /// Factory token a recipient must hold to receive this one, if gated
+    pub gate_token: Option<Address>,
 }
 
 #[contracttype]
@@ -127,6 +201,14 @@ pub struct TokenCreationParams {
     pub initial_supply: i128,
     pub max_supply: Option<i128>,
     pub metadata_uri: Option<String>,
+    /// Display-only precision; must not exceed `decimals`
+    pub display_decimals: Option<u32>,
+    /// Smallest amount a single transfer may move; must be positive
+    pub min_transfer: Option<i128>,
+    /// Whether holders may burn; `None` means burnable
+    pub burnable: Option<bool>,
+    /// Smallest nonzero balance an account may keep; must be positive
+    pub min_balance: Option<i128>,
 }
 
 /// Timelock configuration
@@ -224,6 +306,165 @@ pub struct Vault {
     pub created_at: u64,
 }
 
+/// Running totals of collected fees, split by destination.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FeeTotals {
+    pub to_treasury: i128,
+    pub burned: i128,
+}
+
+/// Rounding applied when taking a basis-point share of an amount.
+///
+/// Only the computed share is rounded; the remainder is derived by
+/// subtraction, so both parts always add up to the original amount.
+/// `Floor` is the default.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round down
+    #[default]
+    Floor,
+    /// Round up
+    Ceil,
+    /// Round to nearest, halves up
+    Nearest,
+}
+
+/// Kind of token created by the factory.
+///
+/// `NonFungible` tokens are single collectibles: they always have
+/// 0 decimals and a supply of exactly 1.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenKind {
+    Fungible,
+    NonFungible,
+}
+
+/// Clock used to stamp `TokenInfo::created_at`.
+///
+/// `Timestamp` records the ledger close time in seconds; `Sequence`
+/// records the ledger sequence number, which orders creations strictly.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeSource {
+    Timestamp,
+    Sequence,
+}
+
+/// Ongoing token operation that can carry an operation fee
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OpKind {
+    /// `mint`, paid by the creator
+    Mint,
+    /// `transfer_with_memo` and `batch_transfer`, paid by the sender
+    Transfer,
+    /// `set_token_metadata` and `set_token_attribute`, paid by the creator
+    Metadata,
+}
+
+/// Where tokens taken by `admin_burn` end up.
+///
+/// `Burn` destroys them and reduces supply; `Redirect` moves them to a
+/// recovery address and leaves supply unchanged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClawbackMode {
+    Burn,
+    Redirect(Address),
+}
+
+/// Account roles the admin can grant or revoke.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Creates tokens through `create_token` without paying the creation fee
+    FeeExempt,
+    /// Proposes and approves queued admin actions
+    Approver,
+}
+
+/// Admin action that runs once enough approvers sign off on it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// New `(base_fee, metadata_fee)`; `None` keeps the current value
+    UpdateFees(Option<i128>, Option<i128>),
+    SetPaused(bool),
+    SetTreasury(Address),
+}
+
+/// Admin action waiting in the approval queue
+///
+/// # Fields
+/// * `id` - Unique identifier for this request
+/// * `proposer` - Approver who proposed the action
+/// * `action` - Action to run once the quorum is reached
+/// * `approvals` - Approvers who signed off, proposer included
+/// * `executed` - Whether the action has run
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionRequest {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: AdminAction,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+}
+
+/// Lifecycle state for a token creation fee escrow.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscrowStatus {
+    Pending,
+    Finalized,
+    Cancelled,
+}
+
+/// Deployment fee held by the factory until the creator finalizes or
+/// cancels the token creation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreateEscrow {
+    pub id: u64,
+    pub creator: Address,
+    pub params: TokenCreationParams,
+    pub fee: i128,
+    pub status: EscrowStatus,
+    pub created_at: u64,
+}
+
+/// Amount and timing of a new vesting schedule.
+///
+/// `cliff` and `duration` are seconds after `start`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingTerms {
+    pub total: i128,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+}
+
+/// Tokens locked for a beneficiary and released linearly.
+///
+/// Nothing can be claimed before `start + cliff`; after that the vested
+/// amount grows linearly from `start` and reaches `total` at
+/// `start + duration`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub token_address: Address,
+    pub beneficiary: Address,
+    pub total: i128,
+    pub claimed: i128,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+}
+
 /// Compact read-only snapshot of a token's current state.
 /// Returned by get_token_stats().
 #[contracttype]
@@ -237,6 +478,56 @@ pub struct TokenStats {
     pub freeze_enabled: bool,
 }
 
+/// Supply and activity overview for a token detail page.
+/// Returned by get_token_summary_stats().
+///
+/// `age` is measured in the unit of `created_at_source`: seconds for
+/// timestamps, ledgers for sequence numbers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenSummaryStats {
+    pub address: Address,
+    pub creator: Address,
+    pub current_supply: i128,
+    pub max_supply: Option<i128>,
+    pub total_burned: i128,
+    pub burn_count: u32,
+    pub holder_count: u32,
+    pub created_at: u64,
+    pub created_at_source: TimeSource,
+    pub age: u64,
+}
+
+/// A single read bundled into `multicall_reads`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadRequest {
+    /// Same as `get_state()`
+    State,
+    /// Same as `get_token_info(index)`
+    TokenInfo(u32),
+    /// Same as `get_burn_count(index)`
+    BurnCount(u32),
+    /// Same as `get_token_stats(index)`
+    SupplyInfo(u32),
+}
+
+/// Result of one `ReadRequest`, at the same position as its request
+///
+/// Contract types cannot box their fields, so the size difference between
+/// variants is accepted.
+#[allow(clippy::large_enum_variant)]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadResult {
+    State(FactoryState),
+    TokenInfo(TokenInfo),
+    BurnCount(u32),
+    SupplyInfo(TokenStats),
+    /// The read failed, e.g. with `Error::TokenNotFound`
+    Failed(Error),
+}
+
 /// Batch fee update structure for Phase 2 optimization
 ///
 /// Allows updating both fees in a single operation, providing
@@ -307,6 +598,105 @@ pub enum DataKey {
     CampaignByCreator(Address, u32),
     CreatorCampaignCount(Address),
     ActiveCampaigns,
+    TokenWasmHash,
+    FeeToken,
+    CreateEscrow(u64),
+    CreateEscrowCount,
+    FeeBurnBps,
+    FeeTotals,
+    UniqueSymbols,
+    InstanceId,
+}
+
+/// Storage keys for per-token records keyed by token address
+///
+/// Kept separate from `DataKey` because a `#[contracttype]` enum is limited
+/// to 50 variants. Keys are encoded by variant name only, so variant names
+/// must stay unique across both enums.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenKey {
+    TokenIndex(Address),
+    SacWrapper(Address),
+    SacWrappedToken(Address),
+    TokenAttribute(Address, String),
+    TokenAttributeCount(Address),
+    MetadataFrozen(Address),
+    Allowance(Address, Address, Address),
+    SymbolRegistry(String),
+    MintingFinalized(Address),
+    Archived(u32),
+    TimeBucket(u64),
+    TokenCreator(Address),
+    Vesting(Address, Address),
+    LastClawback(Address, Address),
+    Renounced(Address),
+    HolderCount(u32),
+    Locked(Address),
+    ReservedSymbol(String),
+    ReservedIndex(u32),
+    TokenAttributeKeys(Address),
+    HolderListLen(u32),
+    HolderAt(u32, u32),
+    HolderPos(u32, Address),
+    OwnerSpenders(Address, Address),
+}
+
+/// Storage keys for factory-wide values added after `DataKey` filled up
+///
+/// See `TokenKey` for why these live outside `DataKey`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigKey {
+    MaxUriLength,
+    EventSeq,
+    MinDecimals,
+    ArchivedCount,
+    RoundingMode,
+    CreationDisabled,
+    PauseExpiry,
+    GlobalSupplyCap,
+    GlobalTotalSupply,
+    ClawbackCooldown,
+    TimeSource,
+    VersionCount,
+    VersionHistory(u32),
+    AdminFeeExempt,
+    PreviousWasm,
+    GlobalTotalBurned,
+    GlobalBurnCount,
+    WebhookTopicEnabled,
+    SupplyFeeTiers,
+    WindowCreateCap,
+    CreateWindow,
+    NormalizeSymbols,
+    MaxRawSupply,
+    AdminBurnDuringPause,
+    ClawbackNonBurnable,
+    Guardian,
+    MinCreatorBalance,
+    Quorum,
+    ActionCount,
+    ActionRequest(u64),
+    Approver(Address),
+}
+
+/// Storage keys for fee pools (keyed by fee asset) and fee waivers
+///
+/// See `TokenKey` for why these live outside `DataKey`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeKey {
+    FeeBalance(Address),
+    EscrowedFees(Address),
+    Waiver(BytesN<32>),
+    FeeExempt(Address),
+    WaiverSigner,
+    UsedWaiverNonce(u64),
+    SignedWaiverCredit(Address),
+    FeeHistory(u32),
+    FeeHistoryCount,
+    OpFee(OpKind),
 }
 
 #[contracttype]
@@ -368,6 +758,40 @@ impl Error {
     pub const CampaignNotFound: Self = Self(51);
     pub const InvalidBudget: Self = Self(52);
     pub const InsufficientBudget: Self = Self(53);
+    pub const EscrowNotFound: Self = Self(54);
+    pub const MetadataFrozen: Self = Self(55);
+    pub const AttributeLimitExceeded: Self = Self(56);
+    pub const InsufficientAllowance: Self = Self(57);
+    pub const SymbolTaken: Self = Self(58);
+    pub const UniqueSymbolsDisabled: Self = Self(59);
+    pub const BatchEntryFailed: Self = Self(60);
+    pub const MintingFinalized: Self = Self(61);
+    pub const TokenArchived: Self = Self(62);
+    pub const CreationDisabled: Self = Self(63);
+    pub const GlobalCapExceeded: Self = Self(64);
+    pub const ClawbackRateLimited: Self = Self(65);
+    pub const InvalidName: Self = Self(66);
+    pub const InvalidSymbol: Self = Self(67);
+    pub const InvalidDecimals: Self = Self(68);
+    pub const InvalidSupply: Self = Self(69);
+    pub const TokenIndexOutOfRange: Self = Self(70);
+    pub const TokenNotYetCreated: Self = Self(71);
+    pub const TokenRenounced: Self = Self(72);
+    pub const HolderLimitReached: Self = Self(73);
+    pub const NoPreviousVersion: Self = Self(74);
+    pub const BelowMinTransfer: Self = Self(75);
+    pub const TokenLocked: Self = Self(76);
+    pub const SymbolReserved: Self = Self(77);
+    pub const CreateRateLimited: Self = Self(78);
+    pub const IndexNotReserved: Self = Self(79);
+    pub const ReservationExpired: Self = Self(80);
+    pub const WaiverExpired: Self = Self(81);
+    pub const WaiverNonceUsed: Self = Self(82);
+    pub const BurnDisabled: Self = Self(83);
+    pub const BelowMinBalance: Self = Self(84);
+    pub const SupplyNotZero: Self = Self(85);
+    pub const InsufficientCreatorBalance: Self = Self(86);
+    pub const GateTokenRequired: Self = Self(87);
 }
 
 impl From<Error> for soroban_sdk::Error {
@@ -530,6 +954,76 @@ pub struct PaginatedTokens {
     pub cursor: PaginationCursor,
 }
 
+/// Compact registry entry for off-chain backups
+///
+/// Carries only the fields needed to identify a token and its supply, so
+/// an export page is much smaller than the same page of `TokenInfo`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenSummary {
+    pub address: Address,
+    pub creator: Address,
+    pub symbol: String,
+    pub total_supply: i128,
+    pub decimals: u32,
+}
+
+/// A token index held for a creator until `expires_at` (ledger sequence)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexReservation {
+    pub creator: Address,
+    pub expires_at: u32,
+}
+
+/// A single-use fee waiver signed off-chain by the waiver signer
+///
+/// The signature covers `waiver_message`: the factory address, the
+/// creator, `nonce` and `expires_at` (ledger timestamp, exclusive).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedWaiver {
+    pub nonce: u64,
+    pub expires_at: u64,
+    pub signature: BytesN<64>,
+}
+
+/// One entry in the factory's upgrade lineage
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionRecord {
+    pub version: String,
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Summary of the factory's health for monitoring
+///
+/// # Fields
+/// * `initialized` - Whether `initialize` has run
+/// * `paused` - Whether the contract is paused
+/// * `token_count` - Number of tokens ever created
+/// * `invariants_ok` - Whether the admin, treasury and fee invariants hold
+/// * `version` - Label of the most recently installed version, if any
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthStatus {
+    pub initialized: bool,
+    pub paused: bool,
+    pub token_count: u32,
+    pub invariants_ok: bool,
+    pub version: Option<String>,
+}
+
+/// Fees in effect after one fee change
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecord {
+    pub base_fee: i128,
+    pub metadata_fee: i128,
+    pub timestamp: u64,
+}
+
 /// Paginated vault result
 ///
 /// Contains a page of vaults and an optional cursor for fetching the next page.
@@ -0,0 +1,124 @@
+//! Factory upgrades and their lineage
+//!
+//! Every `upgrade` appends a `VersionRecord` before swapping the factory
+//! wasm, so auditors can read back each version, its wasm hash and when it
+//! was installed. Records are append-only.
+//!
+//! The version an upgrade replaces is kept so `rollback_upgrade` can put
+//! it back. Only one step is kept: a rollback cannot be rolled back, and
+//! the first recorded upgrade has nothing to roll back to because the
+//! factory cannot read the hash of the wasm it was deployed with.
+
+use crate::events;
+use crate::storage;
+use crate::types::{Error, VersionRecord};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+/// Maximum number of records returned by one `get_version_history` call
+const MAX_HISTORY_PAGE: u32 = 100;
+
+/// Replace the factory wasm and record the upgrade (admin only)
+///
+/// # Returns
+/// * `Ok(())` - Upgrade recorded and wasm replaced
+/// * `Err(Error::Unauthorized)` - Caller is not the current admin
+/// * `Err(Error::InvalidParameters)` - Version label is empty
+pub fn upgrade(
+    env: &Env,
+    admin: &Address,
+    new_wasm_hash: BytesN<32>,
+    version: String,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    record_version(env, &version, &new_wasm_hash)?;
+    events::emit_upgraded(env, admin, &new_wasm_hash, &version);
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+    Ok(())
+}
+
+/// Restore the wasm and version the last upgrade replaced (admin only)
+///
+/// # Returns
+/// * `Ok(())` - Previous version restored
+/// * `Err(Error::Unauthorized)` - Caller is not the current admin
+/// * `Err(Error::NoPreviousVersion)` - Nothing to roll back to
+pub fn rollback_upgrade(env: &Env, admin: &Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let restored = record_rollback(env)?;
+    events::emit_rolled_back(env, admin, &restored.wasm_hash, &restored.version);
+
+    env.deployer()
+        .update_current_contract_wasm(restored.wasm_hash);
+    Ok(())
+}
+
+/// Append `version` and `wasm_hash` to the lineage at the current time
+///
+/// The version being replaced is kept for `rollback_upgrade`.
+pub fn record_version(env: &Env, version: &String, wasm_hash: &BytesN<32>) -> Result<(), Error> {
+    if version.is_empty() {
+        return Err(Error::InvalidParameters);
+    }
+
+    if let Some(current) = get_current_version(env) {
+        storage::set_previous_version(env, &current);
+    }
+
+    storage::push_version_record(
+        env,
+        &VersionRecord {
+            version: version.clone(),
+            wasm_hash: wasm_hash.clone(),
+            timestamp: env.ledger().timestamp(),
+        },
+    )
+}
+
+/// Append the kept previous version to the lineage and forget it
+pub fn record_rollback(env: &Env) -> Result<VersionRecord, Error> {
+    let previous = storage::get_previous_version(env).ok_or(Error::NoPreviousVersion)?;
+    storage::clear_previous_version(env);
+
+    let restored = VersionRecord {
+        timestamp: env.ledger().timestamp(),
+        ..previous
+    };
+    storage::push_version_record(env, &restored)?;
+    Ok(restored)
+}
+
+/// The most recently installed version, if any upgrade was recorded
+pub fn get_current_version(env: &Env) -> Option<VersionRecord> {
+    match storage::get_version_count(env) {
+        0 => None,
+        count => storage::get_version_record(env, count - 1),
+    }
+}
+
+/// Up to `limit` upgrade records starting at `start`, oldest first
+pub fn get_version_history(env: &Env, start: u32, limit: u32) -> Vec<VersionRecord> {
+    let end = start
+        .saturating_add(limit.clamp(1, MAX_HISTORY_PAGE))
+        .min(storage::get_version_count(env));
+
+    let mut records = Vec::new(env);
+    for index in start..end {
+        if let Some(record) = storage::get_version_record(env, index) {
+            records.push_back(record);
+        }
+    }
+    records
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+
+    let current_admin = storage::get_admin(env);
+    if *admin != current_admin {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    (env, client, contract_id, admin)
+}
+
+/// Record an upgrade the way `upgrade` does, without swapping the wasm
+fn mock_upgrade(env: &Env, contract_id: &Address, at: u64, version: &str, hash: &BytesN<32>) {
+    env.ledger().with_mut(|li| li.timestamp = at);
+    env.as_contract(contract_id, || {
+        upgrade::record_version(env, &String::from_str(env, version), hash).unwrap()
+    });
+}
+
+/// Record a rollback the way `rollback_upgrade` does, without swapping the wasm
+fn mock_rollback(env: &Env, contract_id: &Address, at: u64) -> Result<types::VersionRecord, Error> {
+    env.ledger().with_mut(|li| li.timestamp = at);
+    env.as_contract(contract_id, || upgrade::record_rollback(env))
+}
+
+#[test]
+fn test_rollback_restores_previous_version() {
+    let (env, client, contract_id, _) = setup();
+    let v1 = BytesN::from_array(&env, &[1; 32]);
+    let v2 = BytesN::from_array(&env, &[2; 32]);
+
+    let creator = Address::generate(&env);
+    let spec = token_params(&env, "SURV");
+    let token = client.create_token(&creator, &spec, &BASE_FEE, &TokenKind::Fungible, &None);
+
+    mock_upgrade(&env, &contract_id, 1_000, "1.0.0", &v1);
+    mock_upgrade(&env, &contract_id, 2_000, "2.0.0", &v2);
+    assert_eq!(
+        client.get_current_version().unwrap().version,
+        String::from_str(&env, "2.0.0")
+    );
+
+    let restored = mock_rollback(&env, &contract_id, 3_000).unwrap();
+    assert_eq!(restored.wasm_hash, v1);
+
+    let current = client.get_current_version().unwrap();
+    assert_eq!(current.version, String::from_str(&env, "1.0.0"));
+    assert_eq!(current.wasm_hash, v1);
+    assert_eq!(current.timestamp, 3_000);
+
+    // The lineage keeps the bad upgrade and appends the rollback
+    assert_eq!(client.get_version_history(&0, &10).len(), 3);
+
+    // Registry state is untouched
+    assert_eq!(client.get_token_info(&0).address, token);
+    assert_eq!(client.get_token_count(&true), 1);
+}
+
+#[test]
+fn test_rollback_only_undoes_one_step() {
+    let (env, _, contract_id, _) = setup();
+    mock_upgrade(
+        &env,
+        &contract_id,
+        1_000,
+        "1.0.0",
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    mock_upgrade(
+        &env,
+        &contract_id,
+        2_000,
+        "2.0.0",
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+
+    mock_rollback(&env, &contract_id, 3_000).unwrap();
+
+    assert_eq!(
+        mock_rollback(&env, &contract_id, 4_000),
+        Err(Error::NoPreviousVersion)
+    );
+}
+
+#[test]
+fn test_first_upgrade_cannot_be_rolled_back() {
+    let (env, client, contract_id, admin) = setup();
+
+    assert_eq!(
+        client.try_rollback_upgrade(&admin),
+        Err(Ok(Error::NoPreviousVersion))
+    );
+
+    mock_upgrade(
+        &env,
+        &contract_id,
+        1_000,
+        "1.0.0",
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(
+        client.try_rollback_upgrade(&admin),
+        Err(Ok(Error::NoPreviousVersion))
+    );
+}
+
+#[test]
+fn test_non_admin_cannot_roll_back() {
+    let (env, client, contract_id, _) = setup();
+    mock_upgrade(
+        &env,
+        &contract_id,
+        1_000,
+        "1.0.0",
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    mock_upgrade(
+        &env,
+        &contract_id,
+        2_000,
+        "2.0.0",
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+
+    assert_eq!(
+        client.try_rollback_upgrade(&Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.get_current_version().unwrap().version,
+        String::from_str(&env, "2.0.0")
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+fn valid_spec(env: &Env) -> TokenCreationParams {
+    token_params(env, "FORM")
+}
+
+#[test]
+fn test_reports_every_problem() {
+    let (env, client) = setup();
+    let spec = TokenCreationParams {
+        symbol: String::from_str(&env, ""),
+        decimals: 19,
+        initial_supply: 0,
+        ..valid_spec(&env)
+    };
+
+    assert_eq!(
+        client.validate_token_spec_full(&spec),
+        vec![
+            &env,
+            Error::InvalidSymbol,
+            Error::InvalidDecimals,
+            Error::InvalidSupply
+        ]
+    );
+}
+
+#[test]
+fn test_over_length_name_and_uri() {
+    let (env, client) = setup();
+    let spec = TokenCreationParams {
+        name: String::from_str(&env, "A name that is far too long for a token"),
+        metadata_uri: Some(String::from_str(&env, "")),
+        ..valid_spec(&env)
+    };
+
+    assert_eq!(
+        client.validate_token_spec_full(&spec),
+        vec![&env, Error::InvalidName, Error::InvalidParameters]
+    );
+}
+
+#[test]
+fn test_valid_spec_has_no_violations() {
+    let (env, client) = setup();
+
+    assert!(client
+        .validate_token_spec_full(&valid_spec(&env))
+        .is_empty());
+}
+
+#[test]
+fn test_create_token_still_fails_fast() {
+    let (env, client) = setup();
+    let spec = TokenCreationParams {
+        symbol: String::from_str(&env, ""),
+        decimals: 19,
+        initial_supply: 0,
+        ..valid_spec(&env)
+    };
+
+    assert_eq!(
+        client.try_create_token(
+            &Address::generate(&env),
+            &spec,
+            &BASE_FEE,
+            &TokenKind::Fungible,
+            &None,
+        ),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+}
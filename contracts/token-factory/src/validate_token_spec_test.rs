@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, Address, TokenFactoryClient<'static>, Address, TokenClient<'static>) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let fee_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_fee_token(&admin, &fee_asset.address());
+
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &fee_asset.address()).mint(&creator, &1_000_000_000);
+
+    let fee_token = TokenClient::new(&env, &fee_asset.address());
+    (env, contract_id, client, creator, fee_token)
+}
+
+fn token_count(env: &Env, contract_id: &Address) -> u32 {
+    env.as_contract(contract_id, || storage::get_token_count(env))
+}
+
+#[test]
+fn test_valid_spec_is_accepted_without_side_effects() {
+    let (env, contract_id, client, creator, fee_token) = setup();
+
+    client.validate_token_spec(
+        &creator,
+        &String::from_str(&env, "Dry Run Token"),
+        &String::from_str(&env, "DRY"),
+        &7,
+        &1_000_000,
+        &Some(String::from_str(&env, "ipfs://QmDryRun")),
+    );
+
+    assert_eq!(token_count(&env, &contract_id), 0);
+    assert_eq!(fee_token.balance(&creator), 1_000_000_000);
+}
+
+#[test]
+fn test_over_length_symbol_is_rejected() {
+    let (env, contract_id, client, creator, fee_token) = setup();
+
+    let result = client.try_validate_token_spec(
+        &creator,
+        &String::from_str(&env, "Dry Run Token"),
+        &String::from_str(&env, "THIRTEENCHARS"),
+        &7,
+        &1_000_000,
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidTokenParams)));
+    assert_eq!(token_count(&env, &contract_id), 0);
+    assert_eq!(fee_token.balance(&creator), 1_000_000_000);
+}
+
+#[test]
+fn test_invalid_decimals_and_supply_are_rejected() {
+    let (env, _contract_id, client, creator, _fee_token) = setup();
+    let name = String::from_str(&env, "Dry Run Token");
+    let symbol = String::from_str(&env, "DRY");
+
+    assert_eq!(
+        client.try_validate_token_spec(&creator, &name, &symbol, &19, &1_000_000, &None),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+    assert_eq!(
+        client.try_validate_token_spec(&creator, &name, &symbol, &7, &0, &None),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+}
+
+#[test]
+fn test_paused_factory_is_reported() {
+    let (env, _contract_id, client, creator, _fee_token) = setup();
+    let admin = client.get_state().admin;
+    client.pause(&admin);
+
+    let result = client.try_validate_token_spec(
+        &creator,
+        &String::from_str(&env, "Dry Run Token"),
+        &String::from_str(&env, "DRY"),
+        &7,
+        &1_000_000,
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+}
@@ -21,7 +21,7 @@
 use soroban_sdk::Env;
 
 use crate::storage;
-use crate::types::Error;
+use crate::types::{Error, HealthStatus};
 
 /// Validates that the admin address is set and valid.
 ///
@@ -35,12 +35,15 @@ use crate::types::Error;
 /// 2. If not set, return `Error::MissingAdmin`
 /// 3. Verify address is valid (non-zero, proper format)
 /// 4. If invalid, return `Error::InvalidAdmin`
-/// 5. Return `Ok(())` if valid
+/// 5. Reject the factory itself or a token it deployed, since neither can
+///    act as admin and the factory would be locked out
+/// 6. Return `Ok(())` if valid
 ///
 /// # Errors
 ///
 /// * `Error::MissingAdmin` - Admin address not set in storage (initialization incomplete)
 /// * `Error::InvalidAdmin` - Admin address is invalid (address format or value issue)
+/// * `Error::InvalidParameters` - Admin is the factory or one of its tokens
 ///
 /// # Examples
 ///
@@ -61,7 +64,14 @@ pub fn validate_admin(env: &Env) -> Result<(), Error> {
     // In Soroban, if the address exists in storage, it's already validated by the SDK
     // The get_admin() call will panic if the address is corrupted, which is appropriate
     // for storage corruption scenarios
-    let _admin = storage::get_admin(env);
+    let admin = storage::get_admin(env);
+
+    // Contract-controlled addresses can never authorize admin calls
+    if admin == env.current_contract_address()
+        || storage::get_token_info_by_address(env, &admin).is_some()
+    {
+        return Err(Error::InvalidParameters);
+    }
 
     Ok(())
 }
@@ -276,3 +286,23 @@ pub fn validate_state(env: &Env) -> Result<(), Error> {
 
     Ok(())
 }
+/// Summarize contract health without changing state
+///
+/// Only the cheap invariants (admin, treasury, fees) are checked; the
+/// token count check iterates every token and is left to `validate_state`.
+pub fn health_check(env: &Env) -> HealthStatus {
+    let initialized = storage::has_admin(env);
+    let invariants_ok = initialized
+        && validate_admin(env).is_ok()
+        && validate_treasury(env).is_ok()
+        && validate_fees(env).is_ok();
+
+    HealthStatus {
+        initialized,
+        paused: storage::is_pause_active(env),
+        token_count: storage::get_token_count(env),
+        invariants_ok,
+        version: crate::upgrade::get_current_version(env).map(|record| record.version),
+    }
+}
+
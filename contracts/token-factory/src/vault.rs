@@ -39,7 +39,8 @@ pub fn fund_vault(env: &Env, vault_id: u64, funder: &Address, amount: i128) -> R
 }
 
 fn emit_vault_funded(env: &Env, vault_id: u64, funder: &Address, amount: i128) {
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("vlt_fd_v1"), vault_id),
         (funder.clone(), amount),
     );
@@ -132,7 +133,8 @@ pub fn claim_vault(
 }
 
 fn emit_vault_claimed(env: &Env, vault_id: u64, owner: &Address, amount: i128) {
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("vlt_cl_v1"), vault_id),
         (owner.clone(), amount),
     );
@@ -39,6 +39,18 @@ fn setup() -> (Env, Address, Address, Address, Address, Address) {
         is_paused: false,
         clawback_enabled: false,
         freeze_enabled: false,
+        wasm_hash: soroban_sdk::BytesN::from_array(&env, &[0; 32]),
+        token_kind: crate::types::TokenKind::Fungible,
+        display_decimals: None,
+        created_at_source: crate::types::TimeSource::Timestamp,
+        max_holders: None,
+        min_transfer: None,
+        clawback_mode: crate::types::ClawbackMode::Burn,
+        metadata_version: 0,
+        burnable: true,
+        min_balance: None,
+        events_muted: false,
+        gate_token: None,
     };
 
     env.as_contract(&contract_id, || {
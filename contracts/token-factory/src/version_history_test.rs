@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::FactoryEnv;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    (env, client, contract_id, admin)
+}
+
+/// Record an upgrade the way `upgrade` does, without swapping the wasm
+fn mock_upgrade(env: &Env, contract_id: &Address, at: u64, version: &str, hash: &BytesN<32>) {
+    env.ledger().with_mut(|li| li.timestamp = at);
+    env.as_contract(contract_id, || {
+        upgrade::record_version(env, &String::from_str(env, version), hash).unwrap()
+    });
+}
+
+#[test]
+fn test_history_records_upgrades_in_order() {
+    let (env, client, contract_id, _) = setup();
+    let first = BytesN::from_array(&env, &[1; 32]);
+    let second = BytesN::from_array(&env, &[2; 32]);
+
+    mock_upgrade(&env, &contract_id, 1_000, "1.1.0", &first);
+    mock_upgrade(&env, &contract_id, 2_000, "1.2.0", &second);
+
+    let history = client.get_version_history(&0, &10);
+    assert_eq!(history.len(), 2);
+
+    let v1 = history.get(0).unwrap();
+    assert_eq!(v1.version, String::from_str(&env, "1.1.0"));
+    assert_eq!(v1.wasm_hash, first);
+    assert_eq!(v1.timestamp, 1_000);
+
+    let v2 = history.get(1).unwrap();
+    assert_eq!(v2.version, String::from_str(&env, "1.2.0"));
+    assert_eq!(v2.wasm_hash, second);
+    assert_eq!(v2.timestamp, 2_000);
+}
+
+#[test]
+fn test_history_is_paginated() {
+    let (env, client, contract_id, _) = setup();
+    for i in 0..3u8 {
+        let hash = BytesN::from_array(&env, &[i; 32]);
+        mock_upgrade(&env, &contract_id, i as u64, "v", &hash);
+    }
+
+    let page = client.get_version_history(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page.get(0).unwrap().wasm_hash,
+        BytesN::from_array(&env, &[1; 32])
+    );
+    assert_eq!(client.get_version_history(&3, &10).len(), 0);
+}
+
+#[test]
+fn test_empty_history() {
+    let (_, client, _, _) = setup();
+
+    assert_eq!(client.get_version_history(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_non_admin_cannot_upgrade() {
+    let (env, client, _, _) = setup();
+
+    let result = client.try_upgrade(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[7; 32]),
+        &String::from_str(&env, "2.0.0"),
+    );
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_version_history(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_empty_version_rejected() {
+    let (env, client, _, admin) = setup();
+
+    let result = client.try_upgrade(
+        &admin,
+        &BytesN::from_array(&env, &[7; 32]),
+        &String::from_str(&env, ""),
+    );
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_failed_wasm_swap_records_nothing() {
+    let (env, client, _, admin) = setup();
+
+    // The hash was never uploaded, so the swap fails and the record rolls back
+    let result = client.try_upgrade(
+        &admin,
+        &BytesN::from_array(&env, &[7; 32]),
+        &String::from_str(&env, "2.0.0"),
+    );
+
+    assert!(result.is_err());
+    assert_eq!(client.get_version_history(&0, &10).len(), 0);
+}
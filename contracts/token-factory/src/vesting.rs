@@ -1,4 +1,7 @@
-use soroban_sdk::contracterror;
+use soroban_sdk::{contracterror, Address, Env};
+
+use crate::storage;
+use crate::types::{Error, VestingSchedule, VestingTerms};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -65,6 +68,163 @@ pub fn vested_amount(
     Ok(result_u128 as i128)
 }
 
+/// Amount of a schedule vested at `now`, ignoring what was claimed
+pub fn schedule_vested(schedule: &VestingSchedule, now: u64) -> Result<i128, Error> {
+    if now < schedule.start.saturating_add(schedule.cliff) {
+        return Ok(0);
+    }
+    vested_amount(
+        schedule.total,
+        schedule.start,
+        schedule.start.saturating_add(schedule.duration),
+        now,
+    )
+    .map_err(|_| Error::ArithmeticError)
+}
+
+/// Lock `terms.total` of the creator's balance in a vesting schedule
+/// (creator only)
+///
+/// # Returns
+/// * `Ok(())` - Schedule created
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - Token address not registered
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InvalidAmount)` - `total` is not positive
+/// * `Err(Error::InvalidParameters)` - Zero duration, cliff past the end,
+///   or the beneficiary already has a schedule for this token
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::AddressFrozen)` - Creator or beneficiary is frozen
+/// * `Err(Error::InsufficientBalance)` - Creator holds less than `total`
+/// * `Err(Error::BelowMinBalance)` - Creator would keep a nonzero balance
+///   below the token's minimum
+pub fn create_vesting(
+    env: &Env,
+    token_address: &Address,
+    creator: &Address,
+    beneficiary: &Address,
+    terms: &VestingTerms,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let token_index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    crate::token_creation::ensure_token_unlocked(env, token_address)?;
+
+    if terms.total <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if terms.duration == 0 || terms.cliff > terms.duration {
+        return Err(Error::InvalidParameters);
+    }
+    if storage::get_vesting(env, token_address, beneficiary).is_some() {
+        return Err(Error::InvalidParameters);
+    }
+
+    if storage::is_address_frozen(env, token_address, creator)
+        || storage::is_address_frozen(env, token_address, beneficiary)
+    {
+        return Err(Error::AddressFrozen);
+    }
+
+    let balance = storage::get_balance(env, token_index, creator);
+    if balance < terms.total {
+        return Err(Error::InsufficientBalance);
+    }
+    crate::token_creation::ensure_min_balance(&info, balance - terms.total)?;
+    storage::set_balance(env, token_index, creator, balance - terms.total);
+
+    storage::set_vesting(
+        env,
+        &VestingSchedule {
+            token_address: token_address.clone(),
+            beneficiary: beneficiary.clone(),
+            total: terms.total,
+            claimed: 0,
+            start: terms.start,
+            cliff: terms.cliff,
+            duration: terms.duration,
+        },
+    );
+    crate::events::emit_vesting_created(env, token_address, beneficiary, terms);
+
+    Ok(())
+}
+
+/// Credit everything vested but not yet claimed to the beneficiary
+///
+/// # Returns
+/// * `Ok(amount)` - Amount released by this claim (0 if nothing is due)
+/// * `Err(Error::ContractPaused)` - Contract is paused
+/// * `Err(Error::TokenNotFound)` - No schedule for this token and beneficiary
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::AddressFrozen)` - Beneficiary is frozen
+/// * `Err(Error::GateTokenRequired)` - Beneficiary lacks the gate token
+/// * `Err(Error::HolderLimitReached)` - A new holder would exceed the cap
+/// * `Err(Error::BelowMinBalance)` - The credited balance is below the
+///   token's minimum
+pub fn claim_vested(env: &Env, token_address: &Address, beneficiary: &Address) -> Result<i128, Error> {
+    beneficiary.require_auth();
+
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut schedule =
+        storage::get_vesting(env, token_address, beneficiary).ok_or(Error::TokenNotFound)?;
+    let token_index = storage::get_token_index(env, token_address).ok_or(Error::TokenNotFound)?;
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    crate::token_creation::ensure_token_unlocked(env, token_address)?;
+
+    let vested = schedule_vested(&schedule, env.ledger().timestamp())?;
+    let releasable = vested
+        .checked_sub(schedule.claimed)
+        .ok_or(Error::ArithmeticError)?;
+    if releasable <= 0 {
+        return Ok(0);
+    }
+
+    if storage::is_address_frozen(env, token_address, beneficiary) {
+        return Err(Error::AddressFrozen);
+    }
+    crate::token_creation::ensure_gate_holder(env, &info, beneficiary)?;
+
+    let balance = storage::get_balance(env, token_index, beneficiary);
+    if balance == 0 {
+        crate::mint::ensure_holder_capacity(env, &info, token_index, 1)?;
+    }
+    let balance = balance
+        .checked_add(releasable)
+        .ok_or(Error::ArithmeticError)?;
+    crate::token_creation::ensure_min_balance(&info, balance)?;
+    storage::set_balance(env, token_index, beneficiary, balance);
+
+    schedule.claimed = vested;
+    storage::set_vesting(env, &schedule);
+    crate::events::emit_vesting_claimed(env, token_address, beneficiary, releasable, vested);
+
+    Ok(releasable)
+}
+
+/// Amount vested so far for a beneficiary, claimed or not (0 if none)
+pub fn get_vested_amount(env: &Env, token_address: &Address, beneficiary: &Address) -> i128 {
+    storage::get_vesting(env, token_address, beneficiary)
+        .and_then(|schedule| schedule_vested(&schedule, env.ledger().timestamp()).ok())
+        .unwrap_or(0)
+}
 
 #[cfg(test)]
 mod tests {
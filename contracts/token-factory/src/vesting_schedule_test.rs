@@ -0,0 +1,276 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String};
+use types::VestingTerms;
+
+const START: u64 = 1_000_000;
+const CLIFF: u64 = 100;
+const DURATION: u64 = 1_000;
+const TOTAL: i128 = 10_000;
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    beneficiary: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        ..
+    } = FactoryEnv::new();
+    env.ledger().set_timestamp(START);
+
+    let creator = Address::generate(&env);
+    let params = token_params(&env, "VEST");
+    let token = client
+        .batch_create_tokens(&creator, &vec![&env, params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+    env.as_contract(&contract_id, || {
+        storage::set_balance(&env, 0, &creator, TOTAL)
+    });
+    let beneficiary = Address::generate(&env);
+
+    Setup {
+        env,
+        contract_id,
+        client,
+        creator,
+        beneficiary,
+        token,
+    }
+}
+
+fn terms() -> VestingTerms {
+    VestingTerms {
+        total: TOTAL,
+        start: START,
+        cliff: CLIFF,
+        duration: DURATION,
+    }
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, 0, holder))
+}
+
+fn with_schedule() -> Setup {
+    let s = setup();
+    s.client
+        .create_vesting(&s.token, &s.creator, &s.beneficiary, &terms());
+    s
+}
+
+#[test]
+fn test_create_vesting_locks_creator_balance() {
+    let s = with_schedule();
+
+    assert_eq!(balance(&s, &s.creator), 0);
+    let schedule = s.client.get_vesting(&s.token, &s.beneficiary).unwrap();
+    assert_eq!(schedule.total, TOTAL);
+    assert_eq!(schedule.claimed, 0);
+}
+
+#[test]
+fn test_nothing_claimable_before_cliff() {
+    let s = with_schedule();
+    s.env.ledger().set_timestamp(START + CLIFF - 1);
+
+    assert_eq!(s.client.get_vested_amount(&s.token, &s.beneficiary), 0);
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), 0);
+    assert_eq!(balance(&s, &s.beneficiary), 0);
+}
+
+#[test]
+fn test_mid_schedule_is_proportional() {
+    let s = with_schedule();
+    s.env.ledger().set_timestamp(START + DURATION / 4);
+
+    assert_eq!(
+        s.client.get_vested_amount(&s.token, &s.beneficiary),
+        TOTAL / 4
+    );
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), TOTAL / 4);
+    assert_eq!(balance(&s, &s.beneficiary), TOTAL / 4);
+}
+
+#[test]
+fn test_fully_vested_after_duration() {
+    let s = with_schedule();
+    s.env.ledger().set_timestamp(START + DURATION + 50);
+
+    assert_eq!(s.client.get_vested_amount(&s.token, &s.beneficiary), TOTAL);
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), TOTAL);
+    assert_eq!(balance(&s, &s.beneficiary), TOTAL);
+}
+
+#[test]
+fn test_double_claim_does_not_over_release() {
+    let s = with_schedule();
+    s.env.ledger().set_timestamp(START + DURATION / 2);
+
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), TOTAL / 2);
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), 0);
+
+    s.env.ledger().set_timestamp(START + DURATION);
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), TOTAL / 2);
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), 0);
+
+    assert_eq!(balance(&s, &s.beneficiary), TOTAL);
+    assert_eq!(
+        s.client
+            .get_vesting(&s.token, &s.beneficiary)
+            .unwrap()
+            .claimed,
+        TOTAL
+    );
+}
+
+#[test]
+fn test_create_vesting_validation() {
+    let s = setup();
+    let create = |terms: VestingTerms| {
+        s.client
+            .try_create_vesting(&s.token, &s.creator, &s.beneficiary, &terms)
+    };
+
+    assert_eq!(
+        create(VestingTerms {
+            total: 0,
+            ..terms()
+        }),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        create(VestingTerms {
+            duration: 0,
+            cliff: 0,
+            ..terms()
+        }),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        create(VestingTerms {
+            cliff: DURATION + 1,
+            ..terms()
+        }),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        create(VestingTerms {
+            total: TOTAL + 1,
+            ..terms()
+        }),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_create_vesting_requires_creator() {
+    let s = setup();
+
+    let result = s.client.try_create_vesting(
+        &s.token,
+        &Address::generate(&s.env),
+        &s.beneficiary,
+        &terms(),
+    );
+
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_one_schedule_per_beneficiary() {
+    let s = with_schedule();
+
+    let result = s
+        .client
+        .try_create_vesting(&s.token, &s.creator, &s.beneficiary, &terms());
+
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_claim_without_schedule() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_claim_vested(&s.token, &s.beneficiary),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(s.client.get_vested_amount(&s.token, &s.beneficiary), 0);
+}
+
+fn set_min_balance(s: &Setup, min_balance: i128) {
+    s.env.as_contract(&s.contract_id, || {
+        let mut info = storage::get_token_info(&s.env, 0).unwrap();
+        info.min_balance = Some(min_balance);
+        storage::set_token_info(&s.env, 0, &info);
+    });
+}
+
+#[test]
+fn test_create_vesting_keeps_creator_above_min_balance() {
+    let s = setup();
+    set_min_balance(&s, 100);
+
+    let result = s.client.try_create_vesting(
+        &s.token,
+        &s.creator,
+        &s.beneficiary,
+        &VestingTerms {
+            total: TOTAL - 10,
+            ..terms()
+        },
+    );
+
+    assert_eq!(result, Err(Ok(Error::BelowMinBalance)));
+    assert_eq!(balance(&s, &s.creator), TOTAL);
+}
+
+#[test]
+fn test_claim_respects_min_balance() {
+    let s = with_schedule();
+    set_min_balance(&s, TOTAL / 2);
+
+    s.env.ledger().set_timestamp(START + DURATION / 4);
+    assert_eq!(
+        s.client.try_claim_vested(&s.token, &s.beneficiary),
+        Err(Ok(Error::BelowMinBalance))
+    );
+
+    s.env.ledger().set_timestamp(START + DURATION / 2);
+    assert_eq!(s.client.claim_vested(&s.token, &s.beneficiary), TOTAL / 2);
+}
+
+#[test]
+fn test_claim_requires_gate_token() {
+    let s = with_schedule();
+    let gate_params = TokenCreationParams {
+        initial_supply: 1_000,
+        ..token_params(&s.env, "GATE")
+    };
+    let gate = s
+        .client
+        .batch_create_tokens(&s.creator, &vec![&s.env, gate_params], &BASE_FEE, &None)
+        .get(0)
+        .unwrap();
+    s.client.set_token_gate(&s.token, &s.creator, &Some(gate));
+
+    s.env.ledger().set_timestamp(START + DURATION);
+    assert_eq!(
+        s.client.try_claim_vested(&s.token, &s.beneficiary),
+        Err(Ok(Error::GateTokenRequired))
+    );
+    assert_eq!(balance(&s, &s.beneficiary), 0);
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, TryFromVal, Val};
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let FactoryEnv {
+        env, client, admin, ..
+    } = FactoryEnv::new();
+
+    (env, client, admin)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, symbol: &str) -> Address {
+    let spec = token_params(env, symbol);
+    client.create_token(
+        &Address::generate(env),
+        &spec,
+        &BASE_FEE,
+        &TokenKind::Fungible,
+        &None,
+    )
+}
+
+/// Names and payloads of the events from the last invocation
+fn events(env: &Env) -> std::vec::Vec<(Symbol, Vec<Val>)> {
+    env.events()
+        .all()
+        .iter()
+        .filter_map(|(_, topics, data)| {
+            let name = Symbol::try_from_val(env, &topics.get(0)?).ok()?;
+            Some((name, Vec::<Val>::try_from_val(env, &data).ok()?))
+        })
+        .collect()
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let (env, client, _) = setup();
+    assert!(!client.is_webhook_topic_enabled());
+
+    create(&env, &client, "QUIET");
+
+    let names: std::vec::Vec<Symbol> = events(&env).into_iter().map(|(n, _)| n).collect();
+    assert!(names.contains(&symbol_short!("tok_crt")));
+    assert!(!names.contains(&symbol_short!("tok_ping")));
+}
+
+#[test]
+fn test_enabled_emits_ping_after_rich_event() {
+    let (env, client, admin) = setup();
+    client.set_webhook_topic(&admin, &true);
+    create(&env, &client, "FIRST");
+
+    let token = create(&env, &client, "PING");
+
+    let events = events(&env);
+    let rich = events
+        .iter()
+        .position(|(n, _)| *n == symbol_short!("tok_crt"))
+        .unwrap();
+    let ping = events
+        .iter()
+        .position(|(n, _)| *n == symbol_short!("tok_ping"))
+        .unwrap();
+    assert!(rich < ping);
+
+    let payload = &events[ping].1;
+    assert_eq!(
+        Address::try_from_val(&env, &payload.get(1).unwrap()).unwrap(),
+        token
+    );
+    assert_eq!(
+        u32::try_from_val(&env, &payload.get(2).unwrap()).unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_disabling_stops_ping() {
+    let (env, client, admin) = setup();
+    client.set_webhook_topic(&admin, &true);
+    client.set_webhook_topic(&admin, &false);
+
+    create(&env, &client, "QUIET");
+
+    let names: std::vec::Vec<Symbol> = events(&env).into_iter().map(|(n, _)| n).collect();
+    assert!(names.contains(&symbol_short!("tok_crt")));
+    assert!(!names.contains(&symbol_short!("tok_ping")));
+}
+
+#[test]
+fn test_non_admin_cannot_toggle() {
+    let (env, client, _) = setup();
+
+    assert_eq!(
+        client.try_set_webhook_topic(&Address::generate(&env), &true),
+        Err(Ok(Error::Unauthorized))
+    );
+}
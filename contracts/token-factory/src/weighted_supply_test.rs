@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, TokenFactoryClient<'static>) {
+    let FactoryEnv { env, client, .. } = FactoryEnv::new();
+
+    (env, client)
+}
+
+fn create(env: &Env, client: &TokenFactoryClient, symbol: &str, decimals: u32, supply: i128) {
+    let creator = Address::generate(env);
+    let params = TokenCreationParams {
+        decimals,
+        initial_supply: supply,
+        ..token_params(env, symbol)
+    };
+    client.create_token(&creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+}
+
+#[test]
+fn test_supplies_normalized_to_seven_decimals() {
+    let (env, client) = setup();
+    assert_eq!(client.get_weighted_supply(), 0);
+
+    // 5 whole tokens at 2 decimals -> 5 * 10^7
+    create(&env, &client, "TWO", 2, 500);
+    // 3 whole tokens at 7 decimals -> unchanged
+    create(&env, &client, "SEVEN", 7, 30_000_000);
+    // 2.5 tokens at 18 decimals -> scaled down by 10^11
+    create(&env, &client, "EIGHTN", 18, 2_500_000_000_000_000_000);
+    // 0 decimals: 4 whole tokens
+    create(&env, &client, "ZERO", 0, 4);
+
+    let expected = 500 * 100_000 + 30_000_000 + 25_000_000 + 4 * 10_000_000;
+    assert_eq!(client.get_weighted_supply(), expected);
+}
+
+#[test]
+fn test_high_decimal_dust_truncated() {
+    let (env, client) = setup();
+
+    // Below one 7-decimal unit: 10^11 - 1 units at 18 decimals
+    create(&env, &client, "DUST", 18, 99_999_999_999);
+    assert_eq!(client.get_weighted_supply(), 0);
+
+    create(&env, &client, "MORE", 18, 100_000_000_000);
+    assert_eq!(client.get_weighted_supply(), 1);
+}
+
+#[test]
+fn test_overflow_reported() {
+    let (env, client) = setup();
+    create(&env, &client, "HUGE", 0, i128::MAX / 2);
+
+    assert_eq!(
+        client.try_get_weighted_supply(),
+        Err(Ok(Error::ArithmeticError))
+    );
+}
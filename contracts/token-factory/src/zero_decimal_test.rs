@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_helpers::{token_params, FactoryEnv, BASE_FEE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env, String};
+
+struct Setup {
+    env: Env,
+    contract_id: Address,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+}
+
+fn setup() -> Setup {
+    let FactoryEnv {
+        env,
+        contract_id,
+        client,
+        admin,
+        ..
+    } = FactoryEnv::new();
+
+    let creator = Address::generate(&env);
+    Setup {
+        env,
+        contract_id,
+        client,
+        admin,
+        creator,
+    }
+}
+
+fn create_whole_unit_token(s: &Setup, supply: i128) -> (Address, u32) {
+    let params = TokenCreationParams {
+        decimals: 0,
+        initial_supply: supply,
+        max_supply: Some(supply + 2),
+        ..token_params(&s.env, "CLCT")
+    };
+    let token = s
+        .client
+        .create_token(&s.creator, &params, &BASE_FEE, &TokenKind::Fungible, &None);
+    (token, s.client.get_next_token_index() - 1)
+}
+
+fn balance(s: &Setup, index: u32, who: &Address) -> i128 {
+    s.env
+        .as_contract(&s.contract_id, || storage::get_balance(&s.env, index, who))
+}
+
+#[test]
+fn test_whole_unit_operations_are_exact() {
+    let s = setup();
+    let (token, index) = create_whole_unit_token(&s, 10);
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+
+    s.client.mint(&s.creator, &index, &alice, &1);
+    s.client
+        .transfer_with_memo(&token, &s.creator, &alice, &3, &Bytes::new(&s.env));
+    s.client
+        .batch_transfer(&token, &alice, &vec![&s.env, (bob.clone(), 1)]);
+    s.client.burn(&alice, &index, &1);
+
+    assert_eq!(balance(&s, index, &s.creator), 7);
+    assert_eq!(balance(&s, index, &alice), 2);
+    assert_eq!(balance(&s, index, &bob), 1);
+
+    let info = s.client.get_token_info(&index);
+    assert_eq!(info.total_supply, 10);
+    assert_eq!(info.total_burned, 1);
+}
+
+#[test]
+fn test_fee_burn_bps_never_touches_token_amounts() {
+    let s = setup();
+    // The only basis-point feature acts on creation fees, not on supply
+    s.client.set_fee_burn_bps(&s.admin, &3_333);
+    s.client
+        .set_rounding_mode(&s.admin, &types::RoundingMode::Ceil);
+
+    let (_token, index) = create_whole_unit_token(&s, 3);
+
+    assert_eq!(s.client.get_token_info(&index).total_supply, 3);
+    assert_eq!(balance(&s, index, &s.creator), 3);
+}
+
+#[test]
+fn test_weighted_supply_scales_whole_units_exactly() {
+    let s = setup();
+    create_whole_unit_token(&s, 5);
+
+    assert_eq!(s.client.get_weighted_supply(), 5 * 10_000_000);
+}